@@ -11,6 +11,7 @@ use crossbeam::sync::MsQueue;
 use std::sync::mpsc::channel;
 
 use self::mesos::{
+    CommandBuilder,
     ProtobufCallbackRouter,
     run_protobuf_scheduler,
     Scheduler,
@@ -32,10 +33,7 @@ static MAX_NUM_TASKS: u32 = 2;
 struct TestScheduler<'a> {
     eventbus: &'a MsQueue<EventBusState>,
 
-    max_id: u64,
-
-    num_tasks: u32,
-    running_tasks: u32
+    max_id: u64
 }
 
 impl<'a> TestScheduler<'a> {
@@ -44,10 +42,7 @@ impl<'a> TestScheduler<'a> {
         TestScheduler {
             eventbus: eventbus,
 
-            max_id: 0,
-
-            num_tasks: 0,
-            running_tasks: 0
+            max_id: 0
         }
     }
 
@@ -81,11 +76,10 @@ impl<'a> Scheduler for TestScheduler<'a> {
                       inverse_offers: Vec<&self::mesos::proto::InverseOffer>) {
         println!("received inverse offers");
 
-        // this never lets go willingly
-        let offer_ids = inverse_offers.iter()
-                                      .map(|o| o.get_id().clone())
-                                      .collect();
-        client.decline(offer_ids, None);
+        // move our tasks elsewhere, then let the agent go
+        for inverse_offer in inverse_offers {
+            client.drain(inverse_offer).unwrap();
+        }
     }
 
     #[inline]
@@ -112,17 +106,28 @@ impl<'a> Scheduler for TestScheduler<'a> {
                                             acc + cpu_res.get_value()
                                         });
 
-        // or use this if you don't require special filtering
+        // or use this if you don't require special filtering; note that
+        // it counts reserved and revocable memory as well
         let mut offer_mem = util::get_scalar_resource_sum("mem", offers);
 
+        // launched tasks are tracked by the client's registry, which
+        // keeps them until they reach a terminal state
+        let mut num_tasks = client.registry
+                                  .lock()
+                                  .unwrap()
+                                  .non_terminal()
+                                  .len() as u32;
+
         let mut tasks = vec![];
-        while self.num_tasks < MAX_NUM_TASKS {
+        while num_tasks < MAX_NUM_TASKS {
             let name = &*format!("sleepy-{}", self.get_id());
 
             let task_id = util::task_id(name);
 
-            let mut command = self::mesos::proto::CommandInfo::new();
-            command.set_value("env > /tmp/yolo; sleep 10".to_string());
+            let command = CommandBuilder::shell("env > /tmp/yolo; sleep 10")
+                              .env("TASK_NAME", name)
+                              .build()
+                              .unwrap();
 
             let mem = util::scalar("mem", "*", 128f64);
             let cpus = util::scalar("cpus", "*", 1f64);
@@ -136,7 +141,7 @@ impl<'a> Scheduler for TestScheduler<'a> {
                                             resources);
             tasks.push(task_info);
 
-            self.num_tasks += 1;
+            num_tasks += 1;
         }
 
         client.launch(offer_ids, tasks, None);
@@ -153,19 +158,14 @@ impl<'a> Scheduler for TestScheduler<'a> {
                  status.get_state(),
                  status.get_task_id().get_value());
 
-        let state = status.get_state();
-
-        match state {
-            TaskState::TASK_FINISHED => {
-                self.num_tasks -= 1;
-                self.running_tasks -= 1;
-            },
-            TaskState::TASK_RUNNING => {
-                self.running_tasks += 1;
-            },
-            _ => {
-                println!("Not implemented: {:?}", state);
-            }
+        let mut registry = client.registry.lock().unwrap();
+
+        println!("{} tasks running", registry.running().len());
+
+        // the registry has already seen this update, so finished tasks
+        // can be dropped from it
+        if status.get_state() == TaskState::TASK_FINISHED {
+            registry.remove(status.get_task_id());
         }
     }
 
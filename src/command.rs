@@ -0,0 +1,303 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use protobuf::RepeatedField;
+
+use proto::mesos::{CommandInfo, CommandInfo_URI, Environment,
+                   Environment_Variable};
+
+/// What `Debug` output shows instead of a secret's value.
+pub const REDACTED: &'static str = "<redacted>";
+
+/// Why a command would be rejected or could not run.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CommandError {
+    /// A shell command has nothing to run, or an exec command no
+    /// program.
+    MissingValue,
+    /// Arguments are ignored by shell commands.
+    ArgumentsWithShell,
+    /// An environment variable has no name or one containing `=`.
+    InvalidVariableName(String),
+    /// The variable is set more than once.
+    DuplicateVariable(String),
+    /// A fetcher URI is empty.
+    EmptyUri,
+    /// The fetched file would be saved outside the sandbox.
+    InvalidFilename(String),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CommandError::MissingValue => write!(f, "command has no value"),
+            CommandError::ArgumentsWithShell => {
+                write!(f, "shell commands take no arguments")
+            }
+            CommandError::InvalidVariableName(ref name) => {
+                write!(f, "invalid environment variable name {:?}", name)
+            }
+            CommandError::DuplicateVariable(ref name) => {
+                write!(f, "environment variable {} is set twice", name)
+            }
+            CommandError::EmptyUri => write!(f, "fetcher URI is empty"),
+            CommandError::InvalidFilename(ref filename) => {
+                write!(f,
+                       "fetcher filename {:?} must not be a path",
+                       filename)
+            }
+        }
+    }
+}
+
+impl Error for CommandError {
+    fn description(&self) -> &str {
+        "invalid command"
+    }
+}
+
+/// A value that must not end up in logs.  Its `Debug` output is
+/// `REDACTED`.
+#[derive(Clone, PartialEq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: &str) -> Secret {
+        Secret(value.to_string())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", REDACTED)
+    }
+}
+
+/// A file for the Mesos fetcher to download into the task's sandbox
+/// before the command starts.
+#[derive(Clone, Debug)]
+pub struct FetchUri {
+    uri: CommandInfo_URI,
+}
+
+impl FetchUri {
+    pub fn new(value: &str) -> FetchUri {
+        let mut uri = CommandInfo_URI::new();
+        uri.set_value(value.to_string());
+        FetchUri { uri: uri }
+    }
+
+    /// Makes the fetched file executable.
+    pub fn executable(mut self) -> FetchUri {
+        self.uri.set_executable(true);
+        self
+    }
+
+    /// Whether archives are unpacked, which they are by default.
+    pub fn extract(mut self, extract: bool) -> FetchUri {
+        self.uri.set_extract(extract);
+        self
+    }
+
+    /// Lets the agent serve the file from its fetcher cache.  Only use
+    /// this for URIs whose content never changes.
+    pub fn cache(mut self) -> FetchUri {
+        self.uri.set_cache(true);
+        self
+    }
+
+    /// Saves the file under `filename` in the sandbox.
+    pub fn filename(mut self, filename: &str) -> FetchUri {
+        self.uri.set_filename(filename.to_string());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Plain(String),
+    Secret(Secret),
+}
+
+/// Builds a `CommandInfo`, either run through `/bin/sh -c` or executed
+/// directly with an argument vector.
+///
+/// Environment variables added with `secret` are hidden from the
+/// builder's `Debug` output; use `redact` before logging the
+/// `CommandInfo` that is built.
+#[derive(Clone, Debug)]
+pub struct CommandBuilder {
+    shell: bool,
+    value: String,
+    arguments: Vec<String>,
+    environment: Vec<(String, Value)>,
+    uris: Vec<FetchUri>,
+    user: Option<String>,
+}
+
+impl CommandBuilder {
+    /// Runs `command` through the shell.
+    pub fn shell(command: &str) -> CommandBuilder {
+        CommandBuilder {
+            shell: true,
+            value: command.to_string(),
+            arguments: vec![],
+            environment: vec![],
+            uris: vec![],
+            user: None,
+        }
+    }
+
+    /// Executes `program` directly, with `program` itself as the first
+    /// argument as is the convention.
+    pub fn exec(program: &str) -> CommandBuilder {
+        CommandBuilder {
+            shell: false,
+            arguments: vec![program.to_string()],
+            ..CommandBuilder::shell(program)
+        }
+    }
+
+    /// Adds an argument.  Only exec commands take arguments.
+    pub fn arg(mut self, arg: &str) -> CommandBuilder {
+        self.arguments.push(arg.to_string());
+        self
+    }
+
+    pub fn args(mut self, args: &[&str]) -> CommandBuilder {
+        self.arguments.extend(args.iter().map(|a| a.to_string()));
+        self
+    }
+
+    pub fn env(mut self, name: &str, value: &str) -> CommandBuilder {
+        self.environment
+            .push((name.to_string(), Value::Plain(value.to_string())));
+        self
+    }
+
+    /// Adds every variable of `vars`, e.g. a `HashMap<String, String>`,
+    /// ordered by name.
+    pub fn envs<'a, I>(mut self, vars: I) -> CommandBuilder
+        where I: IntoIterator<Item = (&'a String, &'a String)>
+    {
+        let mut vars: Vec<(&String, &String)> = vars.into_iter().collect();
+        vars.sort();
+        for (name, value) in vars {
+            self = self.env(name, value);
+        }
+        self
+    }
+
+    /// Adds a variable whose value is kept out of `Debug` output.
+    pub fn secret(mut self, name: &str, value: Secret) -> CommandBuilder {
+        self.environment.push((name.to_string(), Value::Secret(value)));
+        self
+    }
+
+    pub fn uri(self, value: &str) -> CommandBuilder {
+        self.fetch(FetchUri::new(value))
+    }
+
+    pub fn fetch(mut self, uri: FetchUri) -> CommandBuilder {
+        self.uris.push(uri);
+        self
+    }
+
+    /// Runs the command as `user` rather than the framework's user.
+    pub fn user(mut self, user: &str) -> CommandBuilder {
+        self.user = Some(user.to_string());
+        self
+    }
+
+    /// The names of the variables added with `secret`, for `redact`.
+    pub fn secret_names(&self) -> Vec<&str> {
+        self.environment
+            .iter()
+            .filter_map(|&(ref name, ref value)| {
+                match *value {
+                    Value::Secret(_) => Some(&name[..]),
+                    Value::Plain(_) => None,
+                }
+            })
+            .collect()
+    }
+
+    pub fn build(self) -> Result<CommandInfo, CommandError> {
+        let mut command = CommandInfo::new();
+        command.set_shell(self.shell);
+        command.set_value(self.value);
+        command.set_arguments(RepeatedField::from_vec(self.arguments));
+
+        let mut environment = Environment::new();
+        for (name, value) in self.environment {
+            let mut variable = Environment_Variable::new();
+            variable.set_name(name);
+            variable.set_value(match value {
+                Value::Plain(value) => value,
+                Value::Secret(secret) => secret.0,
+            });
+            environment.mut_variables().push(variable);
+        }
+        if !environment.get_variables().is_empty() {
+            command.set_environment(environment);
+        }
+
+        let uris = self.uris.into_iter().map(|u| u.uri).collect();
+        command.set_uris(RepeatedField::from_vec(uris));
+        if let Some(user) = self.user {
+            command.set_user(user);
+        }
+        validate(&command)?;
+        Ok(command)
+    }
+}
+
+/// Checks what the agent needs to run `command`.
+pub fn validate(command: &CommandInfo) -> Result<(), CommandError> {
+    if command.get_value().is_empty() {
+        return Err(CommandError::MissingValue);
+    }
+    if command.get_shell() && !command.get_arguments().is_empty() {
+        return Err(CommandError::ArgumentsWithShell);
+    }
+    let mut names = HashSet::new();
+    for variable in command.get_environment().get_variables() {
+        let name = variable.get_name();
+        if name.is_empty() || name.contains('=') {
+            return Err(CommandError::InvalidVariableName(name.to_string()));
+        }
+        if !names.insert(name) {
+            return Err(CommandError::DuplicateVariable(name.to_string()));
+        }
+    }
+    for uri in command.get_uris() {
+        if uri.get_value().is_empty() {
+            return Err(CommandError::EmptyUri);
+        }
+        let filename = uri.get_filename();
+        if filename.contains('/') || filename == "." || filename == ".." {
+            return Err(CommandError::InvalidFilename(filename.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// A copy of `command` fit for logging, with the values of the `secrets`
+/// environment variables replaced by `REDACTED`.
+pub fn redact(command: &CommandInfo, secrets: &[&str]) -> CommandInfo {
+    let mut command = command.clone();
+    if !command.has_environment() {
+        return command;
+    }
+    for variable in command.mut_environment().mut_variables().iter_mut() {
+        if secrets.contains(&variable.get_name()) {
+            variable.set_value(REDACTED.to_string());
+        }
+    }
+    command
+}
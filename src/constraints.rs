@@ -0,0 +1,357 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use protobuf::RepeatedField;
+use regex::Regex;
+
+use proto::mesos::{Offer, TaskID};
+use util;
+
+/// The field that names the agent's hostname rather than an attribute.
+pub const HOSTNAME: &'static str = "hostname";
+
+/// How the values of a field must be distributed across a group's tasks.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operator {
+    /// Every task runs under a different value.
+    Unique,
+    /// Every task runs under the given value or, without one, under the
+    /// same value as the group's other tasks.
+    Cluster(Option<String>),
+    /// Tasks are spread evenly across the values, optionally over at
+    /// least the given number of distinct values.
+    GroupBy(Option<usize>),
+    /// The value must match the pattern.
+    Like(Pattern),
+    /// The value must not match the pattern.
+    Unlike(Pattern),
+    /// At most the given number of tasks run under each value.
+    MaxPer(usize),
+}
+
+/// A regular expression that has to match a value in full, as in
+/// Marathon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+    source: String,
+    regex: Regex,
+}
+
+impl Pattern {
+    pub fn new(source: &str) -> Result<Pattern, ConstraintError> {
+        match Regex::new(&format!("^(?:{})$", source)) {
+            Ok(regex) => {
+                Ok(Pattern {
+                    source: source.to_string(),
+                    regex: regex,
+                })
+            }
+            Err(e) => {
+                Err(ConstraintError::InvalidRegex(source.to_string(),
+                                                  e.to_string()))
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        self.regex.is_match(value)
+    }
+}
+
+/// Why a constraint expression could not be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstraintError {
+    /// The expression has no field name.
+    MissingField,
+    /// The expression has no operator.
+    MissingOperator(String),
+    UnknownOperator(String),
+    /// The operator needs a value that was not given.
+    MissingValue(String),
+    /// The operator takes no value, but one was given.
+    UnexpectedValue(String, String),
+    /// The operator's value must be a positive number.
+    InvalidNumber(String, String),
+    /// The operator's value is not a valid regular expression.
+    InvalidRegex(String, String),
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConstraintError::MissingField => {
+                write!(f, "constraint has no field")
+            }
+            ConstraintError::MissingOperator(ref field) => {
+                write!(f, "constraint on {} has no operator", field)
+            }
+            ConstraintError::UnknownOperator(ref op) => {
+                write!(f,
+                       "unknown constraint operator {}, expected one of \
+                        UNIQUE, CLUSTER, GROUP_BY, LIKE, UNLIKE, MAX_PER",
+                       op)
+            }
+            ConstraintError::MissingValue(ref op) => {
+                write!(f, "{} needs a value", op)
+            }
+            ConstraintError::UnexpectedValue(ref op, ref value) => {
+                write!(f, "{} takes no value, got {:?}", op, value)
+            }
+            ConstraintError::InvalidNumber(ref op, ref value) => {
+                write!(f, "{} needs a positive number, got {:?}", op, value)
+            }
+            ConstraintError::InvalidRegex(ref value, ref reason) => {
+                write!(f, "invalid regular expression {:?}: {}", value, reason)
+            }
+        }
+    }
+}
+
+impl Error for ConstraintError {
+    fn description(&self) -> &str {
+        "invalid constraint"
+    }
+}
+
+/// A Marathon-style placement constraint on the agent's hostname or an
+/// attribute, written `field:OPERATOR[:value]`, e.g. `hostname:UNIQUE`,
+/// `rack:GROUP_BY:3` or `zone:LIKE:us-east-.*`.
+///
+/// Attribute values are compared as rendered by
+/// `util::get_attribute_value`.  An agent lacking the attribute only
+/// satisfies UNLIKE.  Operators that depend on where a group's other
+/// tasks run are evaluated against their values for the same field, as
+/// recorded by `Placements`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    pub field: String,
+    pub operator: Operator,
+}
+
+impl Constraint {
+    pub fn new(field: &str, operator: Operator) -> Constraint {
+        Constraint {
+            field: field.to_string(),
+            operator: operator,
+        }
+    }
+
+    /// Parses a constraint from its parts, as in Marathon's JSON form
+    /// `["rack", "GROUP_BY", "3"]`.
+    pub fn from_parts(parts: &[&str]) -> Result<Constraint, ConstraintError> {
+        let field = match parts.get(0) {
+            Some(field) if !field.is_empty() => field.to_string(),
+            _ => return Err(ConstraintError::MissingField),
+        };
+        let op = match parts.get(1) {
+            Some(op) if !op.is_empty() => op.to_uppercase(),
+            _ => return Err(ConstraintError::MissingOperator(field)),
+        };
+        let value = parts.get(2).map(|v| v.to_string());
+        if parts.len() > 3 {
+            let extra = parts[3..].join(":");
+            return Err(ConstraintError::UnexpectedValue(op, extra));
+        }
+
+        let operator = match &op[..] {
+            "UNIQUE" => {
+                if let Some(value) = value {
+                    return Err(ConstraintError::UnexpectedValue(op, value));
+                }
+                Operator::Unique
+            }
+            "CLUSTER" => Operator::Cluster(value),
+            "GROUP_BY" => {
+                match value {
+                    Some(value) => {
+                        Operator::GroupBy(Some(number(&op, value)?))
+                    }
+                    None => Operator::GroupBy(None),
+                }
+            }
+            "LIKE" => Operator::Like(pattern(&op, value)?),
+            "UNLIKE" => Operator::Unlike(pattern(&op, value)?),
+            "MAX_PER" => {
+                match value {
+                    Some(value) => Operator::MaxPer(number(&op, value)?),
+                    None => return Err(ConstraintError::MissingValue(op)),
+                }
+            }
+            _ => return Err(ConstraintError::UnknownOperator(op)),
+        };
+        Ok(Constraint::new(&field, operator))
+    }
+
+    /// Returns true if a task of a group whose other tasks run under
+    /// `placed` values of this constraint's field may run on `offer`'s
+    /// agent.
+    pub fn matches(&self, offer: &Offer, placed: &[String]) -> bool {
+        let value = match field_value(offer, &self.field) {
+            Some(value) => value,
+            None => {
+                return match self.operator {
+                    Operator::Unlike(_) => true,
+                    _ => false,
+                }
+            }
+        };
+        let count = placed.iter().filter(|v| **v == value).count();
+
+        match self.operator {
+            Operator::Unique => count == 0,
+            Operator::Cluster(Some(ref wanted)) => value == *wanted,
+            Operator::Cluster(None) => {
+                placed.first().map_or(true, |first| *first == value)
+            }
+            Operator::GroupBy(groups) => {
+                let mut counts: HashMap<&str, usize> = HashMap::new();
+                for v in placed {
+                    *counts.entry(&v[..]).or_insert(0) += 1;
+                }
+                // values no task runs under yet count as empty groups
+                let least = if counts.len() < groups.unwrap_or(0) {
+                    0
+                } else {
+                    counts.values().cloned().min().unwrap_or(0)
+                };
+                count <= least
+            }
+            Operator::Like(ref pattern) => pattern.matches(&value),
+            Operator::Unlike(ref pattern) => !pattern.matches(&value),
+            Operator::MaxPer(max) => count < max,
+        }
+    }
+}
+
+impl FromStr for Constraint {
+    type Err = ConstraintError;
+
+    fn from_str(s: &str) -> Result<Constraint, ConstraintError> {
+        let parts: Vec<&str> = s.splitn(3, ':').collect();
+        Constraint::from_parts(&parts)
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.operator {
+            Operator::Unique => write!(f, "{}:UNIQUE", self.field),
+            Operator::Cluster(Some(ref value)) => {
+                write!(f, "{}:CLUSTER:{}", self.field, value)
+            }
+            Operator::Cluster(None) => write!(f, "{}:CLUSTER", self.field),
+            Operator::GroupBy(Some(groups)) => {
+                write!(f, "{}:GROUP_BY:{}", self.field, groups)
+            }
+            Operator::GroupBy(None) => write!(f, "{}:GROUP_BY", self.field),
+            Operator::Like(ref pattern) => {
+                write!(f, "{}:LIKE:{}", self.field, pattern.as_str())
+            }
+            Operator::Unlike(ref pattern) => {
+                write!(f, "{}:UNLIKE:{}", self.field, pattern.as_str())
+            }
+            Operator::MaxPer(max) => {
+                write!(f, "{}:MAX_PER:{}", self.field, max)
+            }
+        }
+    }
+}
+
+fn number(op: &str, value: String) -> Result<usize, ConstraintError> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(ConstraintError::InvalidNumber(op.to_string(), value)),
+    }
+}
+
+fn pattern(op: &str,
+           value: Option<String>)
+           -> Result<Pattern, ConstraintError> {
+    match value {
+        Some(value) => Pattern::new(&value),
+        None => Err(ConstraintError::MissingValue(op.to_string())),
+    }
+}
+
+/// The value of `field` for `offer`'s agent: its hostname for
+/// `HOSTNAME`, otherwise the attribute of that name.
+pub fn field_value(offer: &Offer, field: &str) -> Option<String> {
+    if field == HOSTNAME {
+        return Some(offer.get_hostname().to_string());
+    }
+    util::get_attribute_value(offer, field)
+}
+
+// What constraints need to know about the agent a task was placed on.
+#[derive(Clone, Debug)]
+struct Placement {
+    group: String,
+    agent: Offer,
+}
+
+/// Remembers the agents tasks run on, by group, so constraints can be
+/// evaluated against a group's running tasks.  `TaskQueue` records the
+/// tasks it places, grouping them by `TaskSpec::name`, and forgets them
+/// once they terminate.
+#[derive(Clone, Debug, Default)]
+pub struct Placements {
+    tasks: HashMap<String, Placement>,
+}
+
+impl Placements {
+    pub fn new() -> Placements {
+        Placements { tasks: HashMap::new() }
+    }
+
+    /// Records that `task_id` of `group` runs on `offer`'s agent.
+    pub fn record(&mut self, group: &str, task_id: &TaskID, offer: &Offer) {
+        let mut agent = Offer::new();
+        agent.set_hostname(offer.get_hostname().to_string());
+        agent.set_attributes(RepeatedField::from_vec(offer.get_attributes()
+                                                          .to_vec()));
+        self.tasks.insert(task_id.get_value().to_string(),
+                          Placement {
+                              group: group.to_string(),
+                              agent: agent,
+                          });
+    }
+
+    pub fn forget(&mut self, task_id: &TaskID) {
+        self.tasks.remove(task_id.get_value());
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// The values of `field` for the agents `group`'s tasks run on.
+    pub fn values(&self, group: &str, field: &str) -> Vec<String> {
+        self.tasks
+            .values()
+            .filter(|p| p.group == group)
+            .filter_map(|p| field_value(&p.agent, field))
+            .collect()
+    }
+
+    /// Returns true if a task of `group` may run on `offer`'s agent
+    /// under all of `constraints`.
+    pub fn allows(&self,
+                  group: &str,
+                  constraints: &[Constraint],
+                  offer: &Offer)
+                  -> bool {
+        constraints.iter().all(|c| {
+            c.matches(offer, &self.values(group, &c.field))
+        })
+    }
+}
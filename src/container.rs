@@ -0,0 +1,447 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use protobuf::RepeatedField;
+
+use proto::mesos::{ContainerInfo, ContainerInfo_DockerInfo,
+                   ContainerInfo_DockerInfo_Network,
+                   ContainerInfo_DockerInfo_PortMapping,
+                   ContainerInfo_MesosInfo, ContainerInfo_Type, Credential,
+                   Image, Image_Appc, Image_Docker, Image_Type, NetworkInfo,
+                   Parameter, Volume, Volume_Mode};
+use ports::PortAllocation;
+
+/// Why a container description would be rejected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContainerError {
+    MissingImage,
+    /// Port mappings only apply to BRIDGE and USER networks.
+    PortMappingsWithout(DockerNetwork),
+    /// Two port mappings use the same host port.
+    DuplicateHostPort(u32),
+    /// A USER network needs the name of the network to join.
+    MissingNetworkName,
+    /// Containers on the host network share the host's hostname.
+    HostnameOnHostNetwork,
+    /// A volume has no container path.
+    MissingContainerPath,
+    /// Both the `docker` and the `mesos` sub-messages are set.
+    DockerAndMesos,
+    /// The sub-message for the container's type is missing.
+    MissingInfoFor(ContainerInfo_Type),
+    /// Registry credentials only apply to Docker images.
+    CredentialWithoutDockerImage,
+}
+
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ContainerError::MissingImage => write!(f, "container has no image"),
+            ContainerError::PortMappingsWithout(ref network) => {
+                write!(f,
+                       "port mappings need a BRIDGE or USER network, not {}",
+                       network)
+            }
+            ContainerError::DuplicateHostPort(port) => {
+                write!(f, "host port {} is mapped more than once", port)
+            }
+            ContainerError::MissingNetworkName => {
+                write!(f, "USER networks need a network name")
+            }
+            ContainerError::HostnameOnHostNetwork => {
+                write!(f, "containers on the HOST network share its hostname")
+            }
+            ContainerError::MissingContainerPath => {
+                write!(f, "volume has no container path")
+            }
+            ContainerError::DockerAndMesos => {
+                write!(f, "container cannot set both docker and mesos")
+            }
+            ContainerError::MissingInfoFor(container_type) => {
+                write!(f, "{:?} container has no {:?} info",
+                       container_type,
+                       container_type)
+            }
+            ContainerError::CredentialWithoutDockerImage => {
+                write!(f, "registry credentials need a Docker image")
+            }
+        }
+    }
+}
+
+impl Error for ContainerError {
+    fn description(&self) -> &str {
+        "invalid container"
+    }
+}
+
+/// The network a Docker container joins.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DockerNetwork {
+    /// The host's network stack; the task uses the offered ports as is.
+    Host,
+    /// Docker's bridge, with host ports mapped into the container.
+    Bridge,
+    /// No networking at all.
+    None,
+    /// A named user-defined (e.g. CNI) network.
+    User(String),
+}
+
+impl fmt::Display for DockerNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DockerNetwork::Host => write!(f, "HOST"),
+            DockerNetwork::Bridge => write!(f, "BRIDGE"),
+            DockerNetwork::None => write!(f, "NONE"),
+            DockerNetwork::User(ref name) => write!(f, "USER ({})", name),
+        }
+    }
+}
+
+fn volume(host_path: &str, container_path: &str, mode: Volume_Mode) -> Volume {
+    let mut volume = Volume::new();
+    volume.set_container_path(container_path.to_string());
+    if !host_path.is_empty() {
+        volume.set_host_path(host_path.to_string());
+    }
+    volume.set_mode(mode);
+    volume
+}
+
+/// Builds the `ContainerInfo` for a task run by the Docker
+/// containerizer.
+#[derive(Clone, Debug)]
+pub struct DockerBuilder {
+    image: String,
+    network: DockerNetwork,
+    port_mappings: Vec<ContainerInfo_DockerInfo_PortMapping>,
+    privileged: bool,
+    parameters: Vec<(String, String)>,
+    force_pull_image: bool,
+    volume_driver: Option<String>,
+    volumes: Vec<Volume>,
+    hostname: Option<String>,
+}
+
+impl DockerBuilder {
+    /// A container from `image`, on the host network like Mesos'
+    /// default.
+    pub fn new(image: &str) -> DockerBuilder {
+        DockerBuilder {
+            image: image.to_string(),
+            network: DockerNetwork::Host,
+            port_mappings: vec![],
+            privileged: false,
+            parameters: vec![],
+            force_pull_image: false,
+            volume_driver: None,
+            volumes: vec![],
+            hostname: None,
+        }
+    }
+
+    pub fn network(mut self, network: DockerNetwork) -> DockerBuilder {
+        self.network = network;
+        self
+    }
+
+    pub fn port_mapping(mut self,
+                        host_port: u32,
+                        container_port: u32,
+                        protocol: &str)
+                        -> DockerBuilder {
+        let mut mapping = ContainerInfo_DockerInfo_PortMapping::new();
+        mapping.set_host_port(host_port);
+        mapping.set_container_port(container_port);
+        mapping.set_protocol(protocol.to_string());
+        self.port_mappings.push(mapping);
+        self
+    }
+
+    /// Maps the ports allocated from the offer onto `container_ports`;
+    /// see `PortAllocation::port_mappings`.
+    pub fn ports(mut self,
+                 ports: &PortAllocation,
+                 container_ports: &[u32],
+                 protocol: &str)
+                 -> DockerBuilder {
+        self.port_mappings
+            .extend(ports.port_mappings(container_ports, protocol));
+        self
+    }
+
+    pub fn privileged(mut self) -> DockerBuilder {
+        self.privileged = true;
+        self
+    }
+
+    /// Passes `--key=value` to `docker run`.
+    pub fn parameter(mut self, key: &str, value: &str) -> DockerBuilder {
+        self.parameters.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn force_pull_image(mut self) -> DockerBuilder {
+        self.force_pull_image = true;
+        self
+    }
+
+    pub fn volume_driver(mut self, driver: &str) -> DockerBuilder {
+        self.volume_driver = Some(driver.to_string());
+        self
+    }
+
+    /// Mounts `host_path` (or, with a volume driver, the named volume) at
+    /// `container_path`.
+    pub fn volume(mut self,
+                  host_path: &str,
+                  container_path: &str,
+                  mode: Volume_Mode)
+                  -> DockerBuilder {
+        self.volumes.push(volume(host_path, container_path, mode));
+        self
+    }
+
+    pub fn hostname(mut self, hostname: &str) -> DockerBuilder {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<ContainerInfo, ContainerError> {
+        if self.image.is_empty() {
+            return Err(ContainerError::MissingImage);
+        }
+        let network = match self.network {
+            DockerNetwork::Host => ContainerInfo_DockerInfo_Network::HOST,
+            DockerNetwork::Bridge => ContainerInfo_DockerInfo_Network::BRIDGE,
+            DockerNetwork::None => ContainerInfo_DockerInfo_Network::NONE,
+            DockerNetwork::User(ref name) if name.is_empty() => {
+                return Err(ContainerError::MissingNetworkName)
+            }
+            DockerNetwork::User(_) => ContainerInfo_DockerInfo_Network::USER,
+        };
+        let mapped = !self.port_mappings.is_empty();
+        match self.network {
+            DockerNetwork::Host | DockerNetwork::None if mapped => {
+                return Err(ContainerError::PortMappingsWithout(self.network));
+            }
+            _ => {}
+        }
+        if self.network == DockerNetwork::Host && self.hostname.is_some() {
+            return Err(ContainerError::HostnameOnHostNetwork);
+        }
+        let mut host_ports = HashSet::new();
+        for mapping in self.port_mappings.iter() {
+            let host_port = mapping.get_host_port();
+            if !host_ports.insert(host_port) {
+                return Err(ContainerError::DuplicateHostPort(host_port));
+            }
+        }
+
+        let mut docker = ContainerInfo_DockerInfo::new();
+        docker.set_image(self.image);
+        docker.set_network(network);
+        docker.set_port_mappings(RepeatedField::from_vec(self.port_mappings));
+        docker.set_privileged(self.privileged);
+        for (key, value) in self.parameters {
+            let mut parameter = Parameter::new();
+            parameter.set_key(key);
+            parameter.set_value(value);
+            docker.mut_parameters().push(parameter);
+        }
+        if self.force_pull_image {
+            docker.set_force_pull_image(true);
+        }
+        if let Some(driver) = self.volume_driver {
+            docker.set_volume_driver(driver);
+        }
+
+        let mut container = ContainerInfo::new();
+        container.set_field_type(ContainerInfo_Type::DOCKER);
+        container.set_docker(docker);
+        container.set_volumes(RepeatedField::from_vec(self.volumes));
+        if let Some(hostname) = self.hostname {
+            container.set_hostname(hostname);
+        }
+        if let DockerNetwork::User(name) = self.network {
+            let mut network_info = NetworkInfo::new();
+            network_info.set_name(name);
+            container.mut_network_infos().push(network_info);
+        }
+        validate(&container)?;
+        Ok(container)
+    }
+}
+
+/// Checks that `container` carries exactly the sub-message its type
+/// calls for.
+pub fn validate(container: &ContainerInfo) -> Result<(), ContainerError> {
+    if container.has_docker() && container.has_mesos() {
+        return Err(ContainerError::DockerAndMesos);
+    }
+    let container_type = container.get_field_type();
+    let has_info = match container_type {
+        ContainerInfo_Type::DOCKER => container.has_docker(),
+        // a MESOS container without an image runs on the host filesystem
+        ContainerInfo_Type::MESOS => !container.has_docker(),
+    };
+    if !has_info {
+        return Err(ContainerError::MissingInfoFor(container_type));
+    }
+    if container.get_volumes()
+                .iter()
+                .any(|v| v.get_container_path().is_empty()) {
+        return Err(ContainerError::MissingContainerPath);
+    }
+    Ok(())
+}
+
+/// A Docker image for the unified containerizer, pulled from a private
+/// registry with `credential` if given.
+pub fn docker_image(name: &str, credential: Option<Credential>) -> Image {
+    let mut docker = Image_Docker::new();
+    docker.set_name(name.to_string());
+    if let Some(credential) = credential {
+        docker.set_credential(credential);
+    }
+
+    let mut image = Image::new();
+    image.set_field_type(Image_Type::DOCKER);
+    image.set_docker(docker);
+    image
+}
+
+/// An Appc image, optionally pinned to the image id `id`.
+pub fn appc_image(name: &str, id: Option<&str>) -> Image {
+    let mut appc = Image_Appc::new();
+    appc.set_name(name.to_string());
+    if let Some(id) = id {
+        appc.set_id(id.to_string());
+    }
+
+    let mut image = Image::new();
+    image.set_field_type(Image_Type::APPC);
+    image.set_appc(appc);
+    image
+}
+
+pub fn credential(principal: &str, secret: &str) -> Credential {
+    let mut credential = Credential::new();
+    credential.set_principal(principal.to_string());
+    credential.set_secret(secret.to_string());
+    credential
+}
+
+/// Builds the `ContainerInfo` for a task run by the Mesos (unified)
+/// containerizer, which provisions Docker and Appc images itself rather
+/// than through a Docker daemon.
+#[derive(Clone, Debug, Default)]
+pub struct MesosBuilder {
+    image: Option<Image>,
+    credential: Option<Credential>,
+    volumes: Vec<Volume>,
+    network_infos: Vec<NetworkInfo>,
+    hostname: Option<String>,
+}
+
+impl MesosBuilder {
+    /// A container without an image, running on the agent's filesystem.
+    pub fn new() -> MesosBuilder {
+        MesosBuilder::default()
+    }
+
+    pub fn image(mut self, image: Image) -> MesosBuilder {
+        self.image = Some(image);
+        self
+    }
+
+    pub fn docker_image(self, name: &str) -> MesosBuilder {
+        self.image(docker_image(name, None))
+    }
+
+    pub fn appc_image(self, name: &str) -> MesosBuilder {
+        self.image(appc_image(name, None))
+    }
+
+    /// Authenticates pulls of the Docker image with a private registry.
+    pub fn credential(mut self,
+                      principal: &str,
+                      secret: &str)
+                      -> MesosBuilder {
+        self.credential = Some(credential(principal, secret));
+        self
+    }
+
+    /// Mounts `host_path` at `container_path`.
+    pub fn volume(mut self,
+                  host_path: &str,
+                  container_path: &str,
+                  mode: Volume_Mode)
+                  -> MesosBuilder {
+        self.volumes.push(volume(host_path, container_path, mode));
+        self
+    }
+
+    /// Mounts the root filesystem of `image` at `container_path`, e.g. to
+    /// ship tools or data alongside the task's own image.
+    pub fn image_volume(mut self,
+                        image: Image,
+                        container_path: &str,
+                        mode: Volume_Mode)
+                        -> MesosBuilder {
+        let mut volume = volume("", container_path, mode);
+        volume.set_image(image);
+        self.volumes.push(volume);
+        self
+    }
+
+    /// Joins the network described by `network_info`, e.g. a named CNI
+    /// network.
+    pub fn network(mut self, network_info: NetworkInfo) -> MesosBuilder {
+        self.network_infos.push(network_info);
+        self
+    }
+
+    pub fn hostname(mut self, hostname: &str) -> MesosBuilder {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<ContainerInfo, ContainerError> {
+        let mut container = ContainerInfo::new();
+        container.set_field_type(ContainerInfo_Type::MESOS);
+
+        let is_docker = self.image.as_ref().map_or(false, |i| {
+            i.get_field_type() == Image_Type::DOCKER
+        });
+        if self.credential.is_some() && !is_docker {
+            return Err(ContainerError::CredentialWithoutDockerImage);
+        }
+        if let Some(mut image) = self.image {
+            let name = match image.get_field_type() {
+                Image_Type::APPC => image.get_appc().get_name(),
+                Image_Type::DOCKER => image.get_docker().get_name(),
+            };
+            if !image.has_field_type() || name.is_empty() {
+                return Err(ContainerError::MissingImage);
+            }
+            if let Some(credential) = self.credential {
+                image.mut_docker().set_credential(credential);
+            }
+            let mut mesos = ContainerInfo_MesosInfo::new();
+            mesos.set_image(image);
+            container.set_mesos(mesos);
+        }
+
+        container.set_volumes(RepeatedField::from_vec(self.volumes));
+        let networks = RepeatedField::from_vec(self.network_infos);
+        container.set_network_infos(networks);
+        if let Some(hostname) = self.hostname {
+            container.set_hostname(hostname);
+        }
+        validate(&container)?;
+        Ok(container)
+    }
+}
@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use protobuf::RepeatedField;
+
+use command::{self, CommandError};
+use proto::mesos::{CommandInfo, ContainerInfo, DiscoveryInfo, ExecutorInfo,
+                   FrameworkID, Resource};
+use util;
+
+/// Why an executor would be rejected or could not start.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecutorError {
+    /// The executor id is not a valid Mesos id, for the given reason.
+    InvalidExecutorId(String, &'static str),
+    /// The command that starts the executor is invalid.
+    InvalidCommand(CommandError),
+}
+
+impl fmt::Display for ExecutorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExecutorError::InvalidExecutorId(ref id, reason) => {
+                write!(f, "invalid executor id {:?}: {}", id, reason)
+            }
+            ExecutorError::InvalidCommand(ref e) => {
+                write!(f, "invalid executor command: {}", e)
+            }
+        }
+    }
+}
+
+impl Error for ExecutorError {
+    fn description(&self) -> &str {
+        "invalid executor"
+    }
+}
+
+/// Builds the `ExecutorInfo` of a custom executor.  Launch tasks on it
+/// with `TaskBuilder::executor`, `util::task_info_for_executor` or
+/// `TaskSpec::for_executor`; tasks given the same executor id on an
+/// agent all run in one instance of it.
+#[derive(Clone, Debug)]
+pub struct ExecutorBuilder {
+    executor_info: ExecutorInfo,
+}
+
+impl ExecutorBuilder {
+    /// An executor started by running `command`.
+    pub fn new(id: &str, command: CommandInfo) -> ExecutorBuilder {
+        let mut executor_info = ExecutorInfo::new();
+        executor_info.set_executor_id(util::executor_id(id));
+        executor_info.set_command(command);
+        ExecutorBuilder { executor_info: executor_info }
+    }
+
+    pub fn name(mut self, name: &str) -> ExecutorBuilder {
+        self.executor_info.set_name(name.to_string());
+        self
+    }
+
+    /// Groups related executors, e.g. every version of one service, in
+    /// the agent's resource usage reports.
+    pub fn source(mut self, source: &str) -> ExecutorBuilder {
+        self.executor_info.set_source(source.to_string());
+        self
+    }
+
+    /// Sets the framework, which `SchedulerClient` otherwise fills in
+    /// when launching.
+    pub fn framework_id(mut self,
+                        framework_id: &FrameworkID)
+                        -> ExecutorBuilder {
+        self.executor_info.set_framework_id(framework_id.clone());
+        self
+    }
+
+    pub fn container(mut self, container: ContainerInfo) -> ExecutorBuilder {
+        self.executor_info.set_container(container);
+        self
+    }
+
+    /// Adds resources the executor itself uses, on top of its tasks'.
+    pub fn resource(mut self, resource: Resource) -> ExecutorBuilder {
+        self.executor_info.mut_resources().push(resource);
+        self
+    }
+
+    pub fn resources(mut self, resources: Vec<Resource>) -> ExecutorBuilder {
+        for resource in resources {
+            self.executor_info.mut_resources().push(resource);
+        }
+        self
+    }
+
+    /// Data handed to the executor when it registers.
+    pub fn data(mut self, data: Vec<u8>) -> ExecutorBuilder {
+        self.executor_info.set_data(data);
+        self
+    }
+
+    pub fn discovery(mut self, discovery: DiscoveryInfo) -> ExecutorBuilder {
+        self.executor_info.set_discovery(discovery);
+        self
+    }
+
+    /// How long the agent waits for the executor to exit when shutting
+    /// it down before destroying its container.
+    pub fn shutdown_grace_period(mut self,
+                                 grace_period: Duration)
+                                 -> ExecutorBuilder {
+        self.executor_info
+            .set_shutdown_grace_period(util::duration_info(grace_period));
+        self
+    }
+
+    pub fn build(self) -> Result<ExecutorInfo, ExecutorError> {
+        let id = self.executor_info.get_executor_id().get_value();
+        if let Err(reason) = util::check_id(id) {
+            return Err(ExecutorError::InvalidExecutorId(id.to_string(),
+                                                        reason));
+        }
+        command::validate(self.executor_info.get_command())
+            .map_err(ExecutorError::InvalidCommand)?;
+        Ok(self.executor_info)
+    }
+}
+
+/// Returns `executor` with its resources replaced by `resources`, e.g.
+/// the offered resources matched for them.
+pub fn with_resources(executor: &ExecutorInfo,
+                      resources: Vec<Resource>)
+                      -> ExecutorInfo {
+    let mut executor = executor.clone();
+    executor.set_resources(RepeatedField::from_vec(resources));
+    executor
+}
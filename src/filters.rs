@@ -0,0 +1,178 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use proto::mesos::{Filters, SlaveID};
+
+/// Why `Filters` would be rejected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterError {
+    /// `refuse_seconds` is negative or not a number.
+    InvalidRefuseSeconds(f64),
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FilterError::InvalidRefuseSeconds(seconds) => {
+                write!(f,
+                       "refuse_seconds must be a positive number, got {}",
+                       seconds)
+            }
+        }
+    }
+}
+
+impl Error for FilterError {
+    fn description(&self) -> &str {
+        "invalid filters"
+    }
+}
+
+/// Builds the `Filters` passed along when accepting or declining
+/// offers.  Without any, Mesos refuses the remaining resources for five
+/// seconds.
+#[derive(Clone, Debug)]
+pub struct FiltersBuilder {
+    filters: Filters,
+}
+
+impl Default for FiltersBuilder {
+    fn default() -> FiltersBuilder {
+        FiltersBuilder::new()
+    }
+}
+
+impl FiltersBuilder {
+    pub fn new() -> FiltersBuilder {
+        FiltersBuilder { filters: Filters::new() }
+    }
+
+    /// How long the declined resources are not offered again.
+    pub fn refuse(self, refuse: Duration) -> FiltersBuilder {
+        let seconds = refuse.as_secs() as f64 +
+                      refuse.subsec_nanos() as f64 / 1e9;
+        self.refuse_seconds(seconds)
+    }
+
+    pub fn refuse_seconds(mut self, seconds: f64) -> FiltersBuilder {
+        self.filters.set_refuse_seconds(seconds);
+        self
+    }
+
+    pub fn build(self) -> Result<Filters, FilterError> {
+        let seconds = self.filters.get_refuse_seconds();
+        if !seconds.is_finite() || seconds < 0f64 {
+            return Err(FilterError::InvalidRefuseSeconds(seconds));
+        }
+        Ok(self.filters)
+    }
+}
+
+fn filters(refuse: Duration) -> Filters {
+    FiltersBuilder::new().refuse(refuse).build().unwrap()
+}
+
+/// Chooses how long declined offers are refused.
+///
+/// While tasks are pending, offers are refused for `short` so that
+/// resources freed elsewhere come back quickly; an agent whose offers
+/// keep going unused backs off, doubling its refusal each time up to
+/// `long`, until a task is placed there.  With nothing to do, offers
+/// are refused for `long`.  `SchedulerClient` declines through it and
+/// revives offers when work arrives while long refusals are in force;
+/// reviving clears every refusal, so the backoffs start over.
+#[derive(Debug)]
+pub struct FilterPolicy {
+    short: Duration,
+    long: Duration,
+    backoffs: HashMap<String, u32>,
+    long_refusals_until: Option<Instant>,
+}
+
+impl Default for FilterPolicy {
+    fn default() -> FilterPolicy {
+        FilterPolicy::new(Duration::from_secs(5), Duration::from_secs(300))
+    }
+}
+
+impl FilterPolicy {
+    pub fn new(short: Duration, long: Duration) -> FilterPolicy {
+        FilterPolicy {
+            short: short,
+            long: long,
+            backoffs: HashMap::new(),
+            long_refusals_until: None,
+        }
+    }
+
+    pub fn short(&self) -> Duration {
+        self.short
+    }
+
+    pub fn set_short(&mut self, short: Duration) {
+        self.short = short;
+    }
+
+    pub fn long(&self) -> Duration {
+        self.long
+    }
+
+    pub fn set_long(&mut self, long: Duration) {
+        self.long = long;
+    }
+
+    /// How long the next unusable offer from `slave_id` is refused while
+    /// work is pending.
+    pub fn refusal(&self, slave_id: &SlaveID) -> Duration {
+        let declines = self.backoffs
+                           .get(slave_id.get_value())
+                           .cloned()
+                           .unwrap_or(0);
+        let mut refusal = self.short;
+        for _ in 0..declines {
+            if refusal >= self.long {
+                break;
+            }
+            refusal = refusal * 2;
+        }
+        cmp::min(refusal, self.long)
+    }
+
+    /// The filters to decline an offer from `slave_id` with, counting it
+    /// towards the agent's backoff if `pending` work could not use it.
+    pub fn decline(&mut self,
+                   slave_id: &SlaveID,
+                   pending: bool,
+                   now: Instant)
+                   -> Filters {
+        if !pending {
+            self.long_refusals_until = Some(now + self.long);
+            return filters(self.long);
+        }
+        let refusal = self.refusal(slave_id);
+        *self.backoffs
+             .entry(slave_id.get_value().to_string())
+             .or_insert(0) += 1;
+        filters(refusal)
+    }
+
+    /// Resets the backoff of an agent tasks were placed on.
+    pub fn used(&mut self, slave_id: &SlaveID) {
+        self.backoffs.remove(slave_id.get_value());
+    }
+
+    /// Returns true if offers were declined for `long` recently enough
+    /// that new work has to revive them.
+    pub fn needs_revive(&self, now: Instant) -> bool {
+        self.long_refusals_until.map_or(false, |until| now < until)
+    }
+
+    /// Called once offers have been revived, which clears all refusals.
+    pub fn revived(&mut self) {
+        self.backoffs.clear();
+        self.long_refusals_until = None;
+    }
+}
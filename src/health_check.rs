@@ -0,0 +1,242 @@
+use std::error::Error;
+use std::fmt;
+
+use proto::mesos::{CommandInfo, HealthCheck, HealthCheck_HTTP, TaskStatus};
+
+/// Why a health check would be rejected or never pass.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HealthCheckError {
+    /// HTTP checks need the port to send requests to.
+    MissingPort,
+    /// The HTTP path must be absolute.
+    RelativePath(String),
+    /// The expected status is not an HTTP status code.
+    InvalidStatus(u32),
+    /// The command has nothing to run.
+    EmptyCommand,
+    /// The named setting is negative or not a number.
+    InvalidSeconds(&'static str, f64),
+    /// The named setting must be greater than zero.
+    Zero(&'static str),
+}
+
+impl fmt::Display for HealthCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HealthCheckError::MissingPort => {
+                write!(f, "HTTP health check has no port")
+            }
+            HealthCheckError::RelativePath(ref path) => {
+                write!(f, "HTTP health check path {:?} is not absolute", path)
+            }
+            HealthCheckError::InvalidStatus(status) => {
+                write!(f, "{} is not an HTTP status code", status)
+            }
+            HealthCheckError::EmptyCommand => {
+                write!(f, "command health check has nothing to run")
+            }
+            HealthCheckError::InvalidSeconds(name, value) => {
+                write!(f, "{} must be a positive number, got {}", name, value)
+            }
+            HealthCheckError::Zero(name) => {
+                write!(f, "{} must be greater than zero", name)
+            }
+        }
+    }
+}
+
+impl Error for HealthCheckError {
+    fn description(&self) -> &str {
+        "invalid health check"
+    }
+}
+
+/// Builds the `HealthCheck` the executor runs against a task, either an
+/// HTTP request or a command.  Settings left alone keep Mesos' defaults.
+#[derive(Clone, Debug)]
+pub struct HealthCheckBuilder {
+    health_check: HealthCheck,
+}
+
+impl HealthCheckBuilder {
+    /// Checks that GET requests to `port` on the task's host succeed.
+    pub fn http(port: u32) -> HealthCheckBuilder {
+        let mut http = HealthCheck_HTTP::new();
+        http.set_port(port);
+        let mut health_check = HealthCheck::new();
+        health_check.set_http(http);
+        HealthCheckBuilder { health_check: health_check }
+    }
+
+    /// Checks that `command` exits with status 0.
+    pub fn command(command: CommandInfo) -> HealthCheckBuilder {
+        let mut health_check = HealthCheck::new();
+        health_check.set_command(command);
+        HealthCheckBuilder { health_check: health_check }
+    }
+
+    /// Checks that running `command` through the shell exits with
+    /// status 0.
+    pub fn shell(command: &str) -> HealthCheckBuilder {
+        let mut command_info = CommandInfo::new();
+        command_info.set_value(command.to_string());
+        HealthCheckBuilder::command(command_info)
+    }
+
+    /// The path HTTP requests are sent to, `/` by default.  Ignored by
+    /// command checks.
+    pub fn path(mut self, path: &str) -> HealthCheckBuilder {
+        if self.health_check.has_http() {
+            self.health_check.mut_http().set_path(path.to_string());
+        }
+        self
+    }
+
+    /// Adds a status an HTTP response may have.  Without any, every
+    /// status counts as healthy.  Ignored by command checks.
+    pub fn status(mut self, status: u32) -> HealthCheckBuilder {
+        if self.health_check.has_http() {
+            self.health_check.mut_http().mut_statuses().push(status);
+        }
+        self
+    }
+
+    pub fn delay_seconds(mut self, seconds: f64) -> HealthCheckBuilder {
+        self.health_check.set_delay_seconds(seconds);
+        self
+    }
+
+    pub fn interval_seconds(mut self, seconds: f64) -> HealthCheckBuilder {
+        self.health_check.set_interval_seconds(seconds);
+        self
+    }
+
+    pub fn timeout_seconds(mut self, seconds: f64) -> HealthCheckBuilder {
+        self.health_check.set_timeout_seconds(seconds);
+        self
+    }
+
+    /// How many checks in a row have to fail before the task is
+    /// reported unhealthy and killed by its executor.
+    pub fn consecutive_failures(mut self, failures: u32) -> HealthCheckBuilder {
+        self.health_check.set_consecutive_failures(failures);
+        self
+    }
+
+    /// How long after launch failed checks are not held against the
+    /// task.
+    pub fn grace_period_seconds(mut self,
+                                seconds: f64)
+                                -> HealthCheckBuilder {
+        self.health_check.set_grace_period_seconds(seconds);
+        self
+    }
+
+    pub fn build(self) -> Result<HealthCheck, HealthCheckError> {
+        let health_check = self.health_check;
+        if health_check.has_http() {
+            let http = health_check.get_http();
+            if http.get_port() == 0 {
+                return Err(HealthCheckError::MissingPort);
+            }
+            if !http.get_path().starts_with('/') {
+                let path = http.get_path().to_string();
+                return Err(HealthCheckError::RelativePath(path));
+            }
+            if let Some(status) = http.get_statuses()
+                                      .iter()
+                                      .find(|&&s| s < 100 || s > 599) {
+                return Err(HealthCheckError::InvalidStatus(*status));
+            }
+        }
+        if health_check.has_command() &&
+           health_check.get_command().get_value().is_empty() {
+            return Err(HealthCheckError::EmptyCommand);
+        }
+
+        let seconds = [("delay_seconds", health_check.get_delay_seconds()),
+                       ("interval_seconds",
+                        health_check.get_interval_seconds()),
+                       ("timeout_seconds", health_check.get_timeout_seconds()),
+                       ("grace_period_seconds",
+                        health_check.get_grace_period_seconds())];
+        for &(name, value) in seconds.iter() {
+            if !value.is_finite() || value < 0f64 {
+                return Err(HealthCheckError::InvalidSeconds(name, value));
+            }
+        }
+        if health_check.get_interval_seconds() == 0f64 {
+            return Err(HealthCheckError::Zero("interval_seconds"));
+        }
+        if health_check.get_timeout_seconds() == 0f64 {
+            return Err(HealthCheckError::Zero("timeout_seconds"));
+        }
+        if health_check.get_consecutive_failures() == 0 {
+            return Err(HealthCheckError::Zero("consecutive_failures"));
+        }
+        Ok(health_check)
+    }
+}
+
+/// Returns the task's new health if `status` changes it from
+/// `previous`, the health last reported for the task.  Updates that do
+/// not report health, e.g. for tasks without a check, change nothing.
+pub fn health_change(previous: Option<bool>,
+                     status: &TaskStatus)
+                     -> Option<bool> {
+    if !status.has_healthy() || previous == Some(status.get_healthy()) {
+        return None;
+    }
+    Some(status.get_healthy())
+}
+
+/// What happens to a task once it is reported unhealthy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnhealthyPolicy {
+    /// Only `Scheduler::task_health_changed` is called.
+    Notify,
+    /// The task is killed with `SchedulerClient::kill_gracefully`, so
+    /// the kill is retried until it is confirmed.
+    Kill,
+    /// The task is killed and, if it was launched from the
+    /// `TaskQueue`, its spec is queued again once it has terminated.
+    Replace,
+}
+
+/// Applies the `UnhealthyPolicy` to tasks reported unhealthy.  The
+/// `TaskQueue` keeps track of the ones to replace.
+#[derive(Debug)]
+pub struct HealthMonitor {
+    policy: UnhealthyPolicy,
+}
+
+impl Default for HealthMonitor {
+    fn default() -> HealthMonitor {
+        HealthMonitor::new(UnhealthyPolicy::Notify)
+    }
+}
+
+impl HealthMonitor {
+    pub fn new(policy: UnhealthyPolicy) -> HealthMonitor {
+        HealthMonitor { policy: policy }
+    }
+
+    pub fn policy(&self) -> UnhealthyPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: UnhealthyPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns true if a task that turned unhealthy should be killed.
+    pub fn kills(&self) -> bool {
+        self.policy != UnhealthyPolicy::Notify
+    }
+
+    /// Returns true if a task killed for being unhealthy should be
+    /// replaced.
+    pub fn replaces(&self) -> bool {
+        self.policy == UnhealthyPolicy::Replace
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use proto::mesos::{KillPolicy, SlaveID, TaskID, TaskState, TaskStatus};
+use task_registry;
+use util;
+
+/// How a task being killed has progressed, as reported to
+/// `Scheduler::kill_updated`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KillProgress {
+    /// The executor received the kill and is giving the task its grace
+    /// period (TASK_KILLING).
+    Killing,
+    /// The task has terminated, with the given state.  This is usually
+    /// TASK_KILLED, but the task may have finished or failed first.
+    Terminated(TaskState),
+}
+
+/// A kill that has been requested but not yet confirmed by a terminal
+/// update.
+#[derive(Clone, Debug)]
+pub struct PendingKill {
+    pub task_id: TaskID,
+    pub slave_id: Option<SlaveID>,
+    /// The grace period the task was given: the one asked for with the
+    /// first KILL call, or else the one it was launched with.
+    pub grace_period: Option<Duration>,
+    /// Whether TASK_KILLING has been seen.
+    pub killing: bool,
+    /// Number of KILL calls sent, including the first.
+    pub attempts: u32,
+    pub requested_at: Instant,
+    next_attempt: Instant,
+}
+
+impl PendingKill {
+    /// Returns true once the retry giving the task its grace period
+    /// again has gone unanswered too, so further KILLs are forcible.
+    pub fn is_forced(&self) -> bool {
+        self.attempts > 2
+    }
+
+    /// The `KillPolicy` to send the latest KILL call with: the task's
+    /// grace period, or none at all once forced.
+    pub fn kill_policy(&self) -> Option<KillPolicy> {
+        if self.is_forced() {
+            Some(util::kill_policy(Duration::from_secs(0)))
+        } else {
+            self.grace_period.map(util::kill_policy)
+        }
+    }
+}
+
+/// Tracks the tasks `SchedulerClient::kill_gracefully` was asked to
+/// kill until a terminal update arrives for each.
+///
+/// A task that is still around `timeout` after its grace period ran
+/// out, e.g. because the KILL call or the update was lost, is killed
+/// again with the same grace period.  If that goes unanswered as well,
+/// it is killed forcibly, with a zero grace period, every `timeout`.
+/// `ProtobufCallbackRouter` resends whatever `poll` returns with the
+/// kill's `kill_policy`.
+pub struct KillTracker {
+    timeout: Duration,
+    pending: BTreeMap<String, PendingKill>,
+}
+
+impl Default for KillTracker {
+    fn default() -> KillTracker {
+        KillTracker::new(Duration::from_secs(30))
+    }
+}
+
+impl KillTracker {
+    pub fn new(timeout: Duration) -> KillTracker {
+        KillTracker {
+            timeout: timeout,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn pending(&self) -> Vec<&PendingKill> {
+        self.pending.values().collect()
+    }
+
+    pub fn get(&self, task_id: &TaskID) -> Option<&PendingKill> {
+        self.pending.get(task_id.get_value())
+    }
+
+    /// Records that a KILL was sent for `task_id`, giving it
+    /// `grace_period`, which should be the one the task was launched
+    /// with unless the KILL asked for another.  Killing a task that is
+    /// already pending keeps its original request time.
+    pub fn start(&mut self,
+                 task_id: &TaskID,
+                 slave_id: Option<SlaveID>,
+                 grace_period: Option<Duration>,
+                 now: Instant) {
+        let next_attempt = now + grace_period.unwrap_or_default() +
+                           self.timeout;
+        let kill = self.pending
+                       .entry(task_id.get_value().to_string())
+                       .or_insert_with(|| {
+                           PendingKill {
+                               task_id: task_id.clone(),
+                               slave_id: None,
+                               grace_period: grace_period,
+                               killing: false,
+                               attempts: 0,
+                               requested_at: now,
+                               next_attempt: next_attempt,
+                           }
+                       });
+        if slave_id.is_some() {
+            kill.slave_id = slave_id;
+        }
+        kill.attempts += 1;
+        kill.next_attempt = next_attempt;
+    }
+
+    /// Applies a status update, returning how it moved a pending kill
+    /// along, if it did.
+    pub fn observe(&mut self, status: &TaskStatus) -> Option<KillProgress> {
+        let key = status.get_task_id().get_value();
+        let state = status.get_state();
+        if task_registry::is_terminal(state) {
+            return self.pending
+                       .remove(key)
+                       .map(|_| KillProgress::Terminated(state));
+        }
+        match self.pending.get_mut(key) {
+            Some(kill) if state == TaskState::TASK_KILLING &&
+                          !kill.killing => {
+                kill.killing = true;
+                Some(KillProgress::Killing)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the kills to retry now, counting the retries as sent.
+    pub fn poll(&mut self, now: Instant) -> Vec<PendingKill> {
+        let timeout = self.timeout;
+        self.pending
+            .values_mut()
+            .filter(|k| k.next_attempt <= now)
+            .map(|k| {
+                k.attempts += 1;
+                let grace_period = if k.is_forced() {
+                    Duration::from_secs(0)
+                } else {
+                    k.grace_period.unwrap_or_default()
+                };
+                k.next_attempt = now + grace_period + timeout;
+                k.clone()
+            })
+            .collect()
+    }
+
+    /// Stops tracking a kill, e.g. for a task reconciliation found gone.
+    pub fn forget(&mut self, task_id: &TaskID) -> Option<PendingKill> {
+        self.pending.remove(task_id.get_value())
+    }
+}
@@ -1,18 +1,57 @@
 #![crate_type = "lib"]
 
+pub mod command;
+pub mod constraints;
+pub mod container;
+pub mod executor;
+pub mod filters;
+pub mod health_check;
+pub mod kill_tracker;
+pub mod maintenance;
+pub mod message_channel;
+pub mod network;
+pub mod offer_pool;
+pub mod persistent_volume;
+pub mod placement;
+pub mod ports;
 pub mod proto;
+pub mod reconciler;
 pub mod recordio;
+pub mod reservation;
+pub mod resources;
 pub mod scheduler;
 pub mod scheduler_client;
 pub mod scheduler_event_stream;
 pub mod scheduler_router;
+pub mod task_builder;
+pub mod task_queue;
+pub mod task_registry;
 pub mod util;
 
+pub use command::CommandBuilder;
+pub use constraints::Constraint;
+pub use container::{DockerBuilder, MesosBuilder};
+pub use executor::ExecutorBuilder;
+pub use filters::{FilterPolicy, FiltersBuilder};
+pub use health_check::{HealthCheckBuilder, HealthMonitor, UnhealthyPolicy};
+pub use maintenance::Maintenance;
+pub use network::NetworkBuilder;
+pub use offer_pool::OfferPool;
+pub use persistent_volume::PersistentVolume;
+pub use placement::PlacementStrategy;
+pub use ports::PortAllocator;
+pub use reconciler::Reconciler;
+pub use reservation::Reservation;
+pub use resources::Resources;
 pub use scheduler::{Scheduler, SchedulerConf};
 pub use scheduler_client::SchedulerClient;
 pub use scheduler_router::{SchedulerRouter, ProtobufCallbackRouter};
 pub use scheduler_event_stream::run_protobuf_scheduler;
+pub use task_builder::TaskBuilder;
+pub use task_queue::{TaskQueue, TaskSpec};
+pub use task_registry::{TaskRegistry, TrackedTask};
 
 #[macro_use] extern crate hyper;
 extern crate protobuf;
 extern crate itertools;
+extern crate regex;
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use proto::mesos::{InverseOffer, Offer, OfferID, SlaveID, TaskID,
+                   Unavailability};
+use task_registry::TaskRegistry;
+use util;
+
+/// The interval an `Unavailability` describes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Window {
+    pub start: SystemTime,
+    /// None if the agent is not coming back.
+    pub end: Option<SystemTime>,
+}
+
+impl Window {
+    pub fn new(unavailability: &Unavailability) -> Window {
+        let start = util::system_time(unavailability.get_start());
+        let end = if unavailability.has_duration() {
+            Some(start + util::duration(unavailability.get_duration()))
+        } else {
+            None
+        };
+        Window {
+            start: start,
+            end: end,
+        }
+    }
+
+    /// Returns true if work running from `start` for `runtime`, or
+    /// indefinitely, would still be running at some point of the
+    /// window.
+    pub fn overlaps(&self,
+                    start: SystemTime,
+                    runtime: Option<Duration>)
+                    -> bool {
+        if self.end.map_or(false, |end| end <= start) {
+            return false;
+        }
+        runtime.map_or(true, |runtime| start + runtime > self.start)
+    }
+}
+
+/// The maintenance window announced for the agent of `offer`, if any.
+pub fn offer_window(offer: &Offer) -> Option<Window> {
+    if offer.has_unavailability() {
+        Some(Window::new(offer.get_unavailability()))
+    } else {
+        None
+    }
+}
+
+/// Returns true if work started on `offer` at `now` and running for
+/// `runtime`, or indefinitely if None, would be done before the agent's
+/// maintenance starts.
+pub fn fits_before_maintenance(offer: &Offer,
+                               runtime: Option<Duration>,
+                               now: SystemTime)
+                               -> bool {
+    offer_window(offer).map_or(true, |w| !w.overlaps(now, runtime))
+}
+
+/// The `offers` work of the given `runtime` can be placed on without
+/// running into maintenance.
+pub fn available_offers<'a>(offers: Vec<&'a Offer>,
+                            runtime: Option<Duration>,
+                            now: SystemTime)
+                            -> Vec<&'a Offer> {
+    offers.into_iter()
+          .filter(|o| fits_before_maintenance(o, runtime, now))
+          .collect()
+}
+
+/// An agent being drained for the maintenance announced by an inverse
+/// offer.
+#[derive(Clone, Debug)]
+pub struct Drain {
+    pub slave_id: SlaveID,
+    /// The latest inverse offer for the agent, which is the one that
+    /// gets accepted.
+    pub inverse_offer_id: OfferID,
+    pub unavailability: Unavailability,
+    /// The tasks killed to drain the agent that have not terminated yet.
+    pub tasks: Vec<TaskID>,
+    /// Whether the tasks have been killed.
+    pub started: bool,
+    /// Whether the inverse offer has been accepted.
+    pub accepted: bool,
+}
+
+impl Drain {
+    pub fn window(&self) -> Window {
+        Window::new(&self.unavailability)
+    }
+}
+
+/// What `Maintenance::poll` asks for next.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrainStep {
+    /// Kill the task on the agent being drained, replacing it if the
+    /// `TaskQueue` launched it.
+    Kill(TaskID, SlaveID),
+    /// The agent is drained; accept its inverse offer.
+    Accept(OfferID),
+}
+
+/// Drains agents ahead of the maintenance announced by inverse offers.
+///
+/// `SchedulerClient::drain` hands it an inverse offer.  Once the
+/// maintenance window is less than `lead` away, the tasks on the agent
+/// are killed gracefully and, if the `TaskQueue` launched them, marked
+/// there to be relaunched; offers from a draining agent are declined so that
+/// they land elsewhere.  When the last of them has terminated the
+/// inverse offer is accepted.  `ProtobufCallbackRouter` carries out the
+/// steps `poll` returns.
+pub struct Maintenance {
+    lead: Duration,
+    drains: BTreeMap<String, Drain>,
+}
+
+impl Default for Maintenance {
+    fn default() -> Maintenance {
+        Maintenance::new(Duration::from_secs(15 * 60))
+    }
+}
+
+impl Maintenance {
+    pub fn new(lead: Duration) -> Maintenance {
+        Maintenance {
+            lead: lead,
+            drains: BTreeMap::new(),
+        }
+    }
+
+    pub fn lead(&self) -> Duration {
+        self.lead
+    }
+
+    pub fn set_lead(&mut self, lead: Duration) {
+        self.lead = lead;
+    }
+
+    pub fn drains(&self) -> Vec<&Drain> {
+        self.drains.values().collect()
+    }
+
+    pub fn get(&self, slave_id: &SlaveID) -> Option<&Drain> {
+        self.drains.get(slave_id.get_value())
+    }
+
+    /// Returns true if tasks are being moved off `slave_id`, so no new
+    /// ones should be placed there.
+    pub fn is_draining(&self, slave_id: &SlaveID) -> bool {
+        self.get(slave_id).map_or(false, |d| d.started)
+    }
+
+    /// Plans to drain the agent of `inverse_offer`.  Returns false if it
+    /// names no agent, in which case there is nothing to drain and it
+    /// should be declined instead.  A later inverse offer for an agent
+    /// that is already drained is accepted again.
+    pub fn drain(&mut self, inverse_offer: &InverseOffer) -> bool {
+        if !inverse_offer.has_slave_id() {
+            return false;
+        }
+        let slave_id = inverse_offer.get_slave_id();
+        let drain = self.drains
+                        .entry(slave_id.get_value().to_string())
+                        .or_insert_with(|| {
+                            Drain {
+                                slave_id: slave_id.clone(),
+                                inverse_offer_id: inverse_offer.get_id()
+                                                               .clone(),
+                                unavailability: Unavailability::new(),
+                                tasks: vec![],
+                                started: false,
+                                accepted: false,
+                            }
+                        });
+        drain.inverse_offer_id = inverse_offer.get_id().clone();
+        drain.unavailability = inverse_offer.get_unavailability().clone();
+        drain.accepted = false;
+        true
+    }
+
+    /// Stops draining for a rescinded inverse offer.  Tasks already
+    /// killed are still relaunched.
+    pub fn rescind(&mut self, offer_id: &OfferID) -> Option<Drain> {
+        let slave = self.drains
+                        .iter()
+                        .find(|&(_, d)| &d.inverse_offer_id == offer_id)
+                        .map(|(slave, _)| slave.clone());
+        slave.and_then(|slave| self.drains.remove(&slave))
+    }
+
+    /// Called when `task_id` has terminated, so drains stop waiting for
+    /// it.
+    pub fn terminated(&mut self, task_id: &TaskID) {
+        for drain in self.drains.values_mut() {
+            drain.tasks.retain(|t| t != task_id);
+        }
+    }
+
+    /// Returns what to do now: the tasks to kill on agents whose
+    /// maintenance is less than `lead` away, including any that showed
+    /// up there since, and the inverse offers of drained agents to
+    /// accept.  Drains are forgotten once their
+    /// window has ended.
+    pub fn poll(&mut self,
+                registry: &TaskRegistry,
+                now: SystemTime)
+                -> Vec<DrainStep> {
+        self.drains
+            .retain(|_, d| d.window().end.map_or(true, |end| now < end));
+
+        let mut steps = vec![];
+        for drain in self.drains.values_mut() {
+            if !drain.started && now + self.lead >= drain.window().start {
+                drain.started = true;
+            }
+            if !drain.started || drain.accepted {
+                continue;
+            }
+            // tasks launched on the agent after the drain started, from
+            // offers accepted by hand, are killed as well
+            for task in registry.non_terminal() {
+                if task.slave_id.as_ref() != Some(&drain.slave_id) ||
+                   drain.tasks.contains(&task.task_id) {
+                    continue;
+                }
+                drain.tasks.push(task.task_id.clone());
+                steps.push(DrainStep::Kill(task.task_id.clone(),
+                                           drain.slave_id.clone()));
+            }
+            if drain.tasks.is_empty() {
+                drain.accepted = true;
+                steps.push(DrainStep::Accept(drain.inverse_offer_id.clone()));
+            }
+        }
+        steps
+    }
+}
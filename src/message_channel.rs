@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use protobuf::{self, MessageStatic};
+
+use proto::mesos::{ExecutorID, SlaveID};
+
+/// A type that can travel in framework messages.  Every protobuf message
+/// is one; other types, e.g. serde-serializable ones, implement it with
+/// the format of their choice.
+pub trait Codec: Sized {
+    fn encode(&self) -> Result<Vec<u8>, MessageError>;
+    fn decode(data: &[u8]) -> Result<Self, MessageError>;
+}
+
+impl<M: MessageStatic> Codec for M {
+    fn encode(&self) -> Result<Vec<u8>, MessageError> {
+        self.write_to_bytes()
+            .map_err(|e| MessageError::Encode(e.to_string()))
+    }
+
+    fn decode(data: &[u8]) -> Result<M, MessageError> {
+        protobuf::parse_from_bytes(data)
+            .map_err(|e| MessageError::Decode(e.to_string()))
+    }
+}
+
+/// Why a framework message could not be sent, read or answered.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageError {
+    /// The data is too short to be an `Envelope`.
+    Truncated,
+    /// The data does not start with the envelope's magic byte.
+    NotAnEnvelope,
+    /// The envelope was framed by a version this one cannot read.
+    UnsupportedVersion(u8),
+    /// The envelope's kind is not one this version knows.
+    UnknownKind(u8),
+    Encode(String),
+    Decode(String),
+    /// No reply arrived in time.
+    Timeout,
+    /// The request was given up on, e.g. because it expired, without a
+    /// reply.
+    NoReply,
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MessageError::Truncated => write!(f, "message is truncated"),
+            MessageError::NotAnEnvelope => {
+                write!(f, "message is not in an envelope")
+            }
+            MessageError::UnsupportedVersion(version) => {
+                write!(f, "unsupported envelope version {}", version)
+            }
+            MessageError::UnknownKind(kind) => {
+                write!(f, "unknown message kind {}", kind)
+            }
+            MessageError::Encode(ref e) => {
+                write!(f, "cannot encode message: {}", e)
+            }
+            MessageError::Decode(ref e) => {
+                write!(f, "cannot decode message: {}", e)
+            }
+            MessageError::Timeout => write!(f, "timed out awaiting reply"),
+            MessageError::NoReply => write!(f, "request was not answered"),
+        }
+    }
+}
+
+impl Error for MessageError {
+    fn description(&self) -> &str {
+        "framework message error"
+    }
+}
+
+/// What an `Envelope` carries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// A message that expects no reply.
+    OneWay,
+    /// A message that expects a `Response` with the same correlation id.
+    Request,
+    Response,
+}
+
+/// The first byte of every envelope, telling it apart from other
+/// framework messages.
+pub const MAGIC: u8 = 0xe7;
+/// The version of the framing written by `Envelope::to_bytes`.
+pub const VERSION: u8 = 1;
+
+// Magic, version and kind, then the correlation id as a big-endian u64.
+const HEADER_LEN: usize = 11;
+
+/// The framing of typed framework messages: the `MAGIC` byte, the
+/// `VERSION` byte, a kind byte and an 8 byte big-endian correlation id,
+/// followed by the encoded payload.  Executors taking part in the
+/// channel read and write this framing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Envelope {
+    pub kind: Kind,
+    pub correlation_id: u64,
+    pub payload: Vec<u8>,
+}
+
+impl Envelope {
+    pub fn one_way<M: Codec>(message: &M) -> Result<Envelope, MessageError> {
+        Ok(Envelope {
+            kind: Kind::OneWay,
+            correlation_id: 0,
+            payload: message.encode()?,
+        })
+    }
+
+    /// The response to the request with `correlation_id`.
+    pub fn response<M: Codec>(correlation_id: u64,
+                              message: &M)
+                              -> Result<Envelope, MessageError> {
+        Ok(Envelope {
+            kind: Kind::Response,
+            correlation_id: correlation_id,
+            payload: message.encode()?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(HEADER_LEN + self.payload.len());
+        data.push(MAGIC);
+        data.push(VERSION);
+        data.push(match self.kind {
+            Kind::OneWay => 0,
+            Kind::Request => 1,
+            Kind::Response => 2,
+        });
+        for shift in (0..8).rev() {
+            data.push((self.correlation_id >> (shift * 8)) as u8);
+        }
+        data.extend_from_slice(&self.payload);
+        data
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Envelope, MessageError> {
+        if data.len() < HEADER_LEN {
+            return Err(MessageError::Truncated);
+        }
+        if data[0] != MAGIC {
+            return Err(MessageError::NotAnEnvelope);
+        }
+        if data[1] != VERSION {
+            return Err(MessageError::UnsupportedVersion(data[1]));
+        }
+        let kind = match data[2] {
+            0 => Kind::OneWay,
+            1 => Kind::Request,
+            2 => Kind::Response,
+            kind => return Err(MessageError::UnknownKind(kind)),
+        };
+        let correlation_id = data[3..HEADER_LEN]
+                                 .iter()
+                                 .fold(0u64, |id, b| (id << 8) | *b as u64);
+        Ok(Envelope {
+            kind: kind,
+            correlation_id: correlation_id,
+            payload: data[HEADER_LEN..].to_vec(),
+        })
+    }
+
+    pub fn decode<M: Codec>(&self) -> Result<M, MessageError> {
+        M::decode(&self.payload)
+    }
+}
+
+/// A reply to a request sent with `SchedulerClient::call_executor`.
+/// Wait for it from any thread but the one running the scheduler's
+/// callbacks, which is the one that delivers it.
+pub struct Reply<R> {
+    correlation_id: u64,
+    rx: Receiver<Vec<u8>>,
+    reply: PhantomData<R>,
+}
+
+impl<R: Codec> Reply<R> {
+    pub fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+
+    pub fn wait(&self, timeout: Duration) -> Result<R, MessageError> {
+        match self.rx.recv_timeout(timeout) {
+            Ok(data) => R::decode(&data),
+            Err(RecvTimeoutError::Timeout) => Err(MessageError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => Err(MessageError::NoReply),
+        }
+    }
+
+    /// The reply, if it has arrived.
+    pub fn try_get(&self) -> Option<Result<R, MessageError>> {
+        self.rx.try_recv().ok().map(|data| R::decode(&data))
+    }
+}
+
+struct PendingRequest {
+    tx: Sender<Vec<u8>>,
+    sent_at: Instant,
+    slave_id: SlaveID,
+    executor_id: ExecutorID,
+}
+
+/// Hands out correlation ids for requests to executors and routes the
+/// responses back to their `Reply`.
+///
+/// `ProtobufCallbackRouter` passes it every MESSAGE event; responses to
+/// pending requests, coming from the executor the request went to, are
+/// consumed, everything else goes on to `Scheduler::message`.  Requests
+/// unanswered after `timeout` are dropped, failing their `Reply` with
+/// `MessageError::NoReply`.
+pub struct MessageChannel {
+    timeout: Duration,
+    next_id: u64,
+    pending: HashMap<u64, PendingRequest>,
+}
+
+impl Default for MessageChannel {
+    fn default() -> MessageChannel {
+        MessageChannel::new(Duration::from_secs(60))
+    }
+}
+
+impl MessageChannel {
+    pub fn new(timeout: Duration) -> MessageChannel {
+        MessageChannel {
+            timeout: timeout,
+            next_id: 0,
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Number of requests awaiting a response.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Wraps `message` in a request envelope with a fresh correlation id
+    /// and starts waiting for the response of the executor it is sent to.
+    pub fn request<M: Codec, R: Codec>(&mut self,
+                                       slave_id: &SlaveID,
+                                       executor_id: &ExecutorID,
+                                       message: &M,
+                                       now: Instant)
+                                       -> Result<(Envelope, Reply<R>),
+                                                 MessageError> {
+        let payload = message.encode()?;
+        self.next_id += 1;
+        let (tx, rx) = mpsc::channel();
+        self.pending.insert(self.next_id,
+                            PendingRequest {
+                                tx: tx,
+                                sent_at: now,
+                                slave_id: slave_id.clone(),
+                                executor_id: executor_id.clone(),
+                            });
+        let envelope = Envelope {
+            kind: Kind::Request,
+            correlation_id: self.next_id,
+            payload: payload,
+        };
+        let reply = Reply {
+            correlation_id: self.next_id,
+            rx: rx,
+            reply: PhantomData,
+        };
+        Ok((envelope, reply))
+    }
+
+    /// Stops waiting for the response to `correlation_id`, e.g. because
+    /// sending the request failed.
+    pub fn cancel(&mut self, correlation_id: u64) {
+        self.pending.remove(&correlation_id);
+    }
+
+    /// Delivers `data`, sent by `executor_id` on `slave_id`, to the
+    /// `Reply` it answers.  Returns false if it is not a response to a
+    /// request pending with that executor.
+    pub fn receive(&mut self,
+                   slave_id: &SlaveID,
+                   executor_id: &ExecutorID,
+                   data: &[u8])
+                   -> bool {
+        let envelope = match Envelope::from_bytes(data) {
+            Ok(envelope) => envelope,
+            Err(_) => return false,
+        };
+        if envelope.kind != Kind::Response {
+            return false;
+        }
+        let answers = self.pending
+                          .get(&envelope.correlation_id)
+                          .map_or(false, |r| {
+                              &r.slave_id == slave_id &&
+                              &r.executor_id == executor_id
+                          });
+        if !answers {
+            return false;
+        }
+        let request = self.pending.remove(&envelope.correlation_id).unwrap();
+        // the reply may have been dropped by now
+        let _ = request.tx.send(envelope.payload);
+        true
+    }
+
+    /// Gives up on requests sent more than `timeout` ago.
+    pub fn expire(&mut self, now: Instant) -> usize {
+        let timeout = self.timeout;
+        let before = self.pending.len();
+        self.pending.retain(|_, r| now < r.sent_at + timeout);
+        before - self.pending.len()
+    }
+}
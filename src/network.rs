@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use protobuf::RepeatedField;
+
+use container::ContainerError;
+use ports::PortAllocation;
+use proto::mesos::{Label, Labels, NetworkInfo, NetworkInfo_IPAddress,
+                   NetworkInfo_PortMapping, NetworkInfo_Protocol,
+                   TaskStatus};
+
+/// Builds the `NetworkInfo` that attaches a Mesos container to a named
+/// network, e.g. one configured through CNI on the agents.  Pass the
+/// result to `MesosBuilder::network`, once per network to join.
+#[derive(Clone, Debug)]
+pub struct NetworkBuilder {
+    name: String,
+    ip_addresses: Vec<NetworkInfo_IPAddress>,
+    groups: Vec<String>,
+    labels: Labels,
+    port_mappings: Vec<NetworkInfo_PortMapping>,
+}
+
+impl NetworkBuilder {
+    pub fn new(name: &str) -> NetworkBuilder {
+        NetworkBuilder {
+            name: name.to_string(),
+            ip_addresses: vec![],
+            groups: vec![],
+            labels: Labels::new(),
+            port_mappings: vec![],
+        }
+    }
+
+    /// Asks the network for an address of the given protocol.  Without
+    /// any request, the network assigns a single address of its choice.
+    pub fn ip(mut self, protocol: NetworkInfo_Protocol) -> NetworkBuilder {
+        let mut ip_address = NetworkInfo_IPAddress::new();
+        ip_address.set_protocol(protocol);
+        self.ip_addresses.push(ip_address);
+        self
+    }
+
+    /// Asks the network for a specific address.  The task fails if it
+    /// is not available.
+    pub fn static_ip(mut self, address: &str) -> NetworkBuilder {
+        let mut ip_address = NetworkInfo_IPAddress::new();
+        ip_address.set_ip_address(address.to_string());
+        self.ip_addresses.push(ip_address);
+        self
+    }
+
+    pub fn group(mut self, group: &str) -> NetworkBuilder {
+        self.groups.push(group.to_string());
+        self
+    }
+
+    /// Adds a label for the network's plugins, e.g. to pick a subnet.
+    pub fn label(mut self, key: &str, value: &str) -> NetworkBuilder {
+        let mut label = Label::new();
+        label.set_key(key.to_string());
+        label.set_value(value.to_string());
+        self.labels.mut_labels().push(label);
+        self
+    }
+
+    pub fn port_mapping(mut self,
+                        host_port: u32,
+                        container_port: u32,
+                        protocol: &str)
+                        -> NetworkBuilder {
+        self.port_mappings
+            .push(port_mapping(host_port, container_port, protocol));
+        self
+    }
+
+    /// Maps the ports allocated from the offer onto `container_ports`;
+    /// see `PortAllocation::port_mappings`.
+    pub fn ports(mut self,
+                 ports: &PortAllocation,
+                 container_ports: &[u32],
+                 protocol: &str)
+                 -> NetworkBuilder {
+        for mapping in ports.port_mappings(container_ports, protocol) {
+            self.port_mappings.push(port_mapping(mapping.get_host_port(),
+                                                 mapping.get_container_port(),
+                                                 mapping.get_protocol()));
+        }
+        self
+    }
+
+    pub fn build(self) -> Result<NetworkInfo, ContainerError> {
+        if self.name.is_empty() {
+            return Err(ContainerError::MissingNetworkName);
+        }
+        let mut host_ports = HashSet::new();
+        for mapping in self.port_mappings.iter() {
+            let host_port = mapping.get_host_port();
+            if !host_ports.insert(host_port) {
+                return Err(ContainerError::DuplicateHostPort(host_port));
+            }
+        }
+
+        let mut network_info = NetworkInfo::new();
+        network_info.set_name(self.name);
+        let ip_addresses = RepeatedField::from_vec(self.ip_addresses);
+        network_info.set_ip_addresses(ip_addresses);
+        network_info.set_groups(RepeatedField::from_vec(self.groups));
+        if !self.labels.get_labels().is_empty() {
+            network_info.set_labels(self.labels);
+        }
+        let port_mappings = RepeatedField::from_vec(self.port_mappings);
+        network_info.set_port_mappings(port_mappings);
+        Ok(network_info)
+    }
+}
+
+fn port_mapping(host_port: u32,
+                container_port: u32,
+                protocol: &str)
+                -> NetworkInfo_PortMapping {
+    let mut mapping = NetworkInfo_PortMapping::new();
+    mapping.set_host_port(host_port);
+    mapping.set_container_port(container_port);
+    if !protocol.is_empty() {
+        mapping.set_protocol(protocol.to_string());
+    }
+    mapping
+}
+
+/// The addresses assigned on one network, in the order reported.  Both
+/// the `ip_addresses` and the deprecated `ip_address` field are read.
+pub fn network_ips(network_info: &NetworkInfo) -> Vec<String> {
+    let mut ips: Vec<String> = vec![];
+    let reported = network_info.get_ip_addresses()
+                               .iter()
+                               .filter(|ip| ip.has_ip_address())
+                               .map(|ip| ip.get_ip_address());
+    let deprecated = if network_info.has_ip_address() {
+        Some(network_info.get_ip_address())
+    } else {
+        None
+    };
+    for ip in reported.chain(deprecated) {
+        if !ip.is_empty() && !ips.iter().any(|known| known == ip) {
+            ips.push(ip.to_string());
+        }
+    }
+    ips
+}
+
+/// The container's addresses as reported in `status`, paired with the
+/// name of the network each was assigned on (empty for the agent's
+/// default network).  Only TASK_RUNNING updates are guaranteed to carry
+/// them.
+pub fn assigned_ips(status: &TaskStatus) -> Vec<(String, String)> {
+    status.get_container_status()
+          .get_network_infos()
+          .iter()
+          .flat_map(|n| {
+              network_ips(n).into_iter()
+                            .map(move |ip| (n.get_name().to_string(), ip))
+          })
+          .collect()
+}
@@ -0,0 +1,152 @@
+use std::time::{Duration, Instant};
+
+use proto::mesos::{Filters, Offer, OfferID, SlaveID};
+
+struct HeldOffer {
+    offer: Offer,
+    expires_at: Option<Instant>,
+}
+
+/// Holds offers the scheduler has not used yet so they can be pulled on
+/// demand instead of being hoarded or declined straight away.
+///
+/// `ProtobufCallbackRouter` adds every offer it receives and drops
+/// rescinded ones.  It also declines offers once they have been held
+/// for longer than the hold duration, thirty seconds by default, with
+/// the `Filters` the client's `FilterPolicy` picks.  Offers passed to
+/// `SchedulerClient::accept` or `decline` leave the pool automatically.
+pub struct OfferPool {
+    offers: Vec<HeldOffer>,
+    hold: Option<Duration>,
+    refuse_seconds: f64,
+}
+
+impl Default for OfferPool {
+    fn default() -> OfferPool {
+        OfferPool {
+            offers: vec![],
+            hold: Some(Duration::from_secs(30)),
+            refuse_seconds: 5f64,
+        }
+    }
+}
+
+impl OfferPool {
+    /// A pool that declines offers after holding them for `hold`.
+    pub fn new(hold: Duration, refuse_seconds: f64) -> OfferPool {
+        OfferPool {
+            offers: vec![],
+            hold: Some(hold),
+            refuse_seconds: refuse_seconds,
+        }
+    }
+
+    pub fn hold(&self) -> Option<Duration> {
+        self.hold
+    }
+
+    /// Sets how long offers are held before being declined, or None to
+    /// hold them indefinitely.  Offers already held keep their expiry.
+    pub fn set_hold(&mut self, hold: Option<Duration>) {
+        self.hold = hold;
+    }
+
+    pub fn refuse_seconds(&self) -> f64 {
+        self.refuse_seconds
+    }
+
+    pub fn set_refuse_seconds(&mut self, refuse_seconds: f64) {
+        self.refuse_seconds = refuse_seconds;
+    }
+
+    /// Filters refusing offers for a fixed `refuse_seconds`, for
+    /// declining offers outside of the `FilterPolicy`.
+    pub fn filters(&self) -> Filters {
+        let mut filters = Filters::new();
+        filters.set_refuse_seconds(self.refuse_seconds);
+        filters
+    }
+
+    pub fn add(&mut self, offers: Vec<&Offer>, now: Instant) {
+        let expires_at = self.hold.map(|hold| now + hold);
+        for offer in offers {
+            self.remove(offer.get_id());
+            self.offers.push(HeldOffer {
+                offer: offer.clone(),
+                expires_at: expires_at,
+            });
+        }
+    }
+
+    pub fn remove(&mut self, offer_id: &OfferID) -> Option<Offer> {
+        match self.offers.iter().position(|h| h.offer.get_id() == offer_id) {
+            Some(idx) => Some(self.offers.remove(idx).offer),
+            None => None,
+        }
+    }
+
+    /// Drops an offer Mesos has rescinded.
+    pub fn rescind(&mut self, offer_id: &OfferID) -> Option<Offer> {
+        self.remove(offer_id)
+    }
+
+    /// Drops every offer, e.g. when a new subscription invalidates them.
+    pub fn clear(&mut self) {
+        self.offers.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.offers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offers.is_empty()
+    }
+
+    pub fn contains(&self, offer_id: &OfferID) -> bool {
+        self.offers.iter().any(|h| h.offer.get_id() == offer_id)
+    }
+
+    /// The held offers, oldest first.
+    pub fn offers(&self) -> Vec<&Offer> {
+        self.offers.iter().map(|h| &h.offer).collect()
+    }
+
+    /// Removes and returns the offers accepted by `predicate`.  The
+    /// caller becomes responsible for accepting or declining them.
+    pub fn take_matching<F>(&mut self, predicate: F) -> Vec<Offer>
+        where F: Fn(&Offer) -> bool
+    {
+        self.take_matching_held(|h| predicate(&h.offer))
+    }
+
+    /// Removes and returns every offer for `slave_id`.
+    pub fn take_slave(&mut self, slave_id: &SlaveID) -> Vec<Offer> {
+        self.take_matching(|o| o.get_slave_id() == slave_id)
+    }
+
+    /// Removes offers that have been held past the hold duration and
+    /// returns their ids so they can be declined.
+    pub fn expire(&mut self, now: Instant) -> Vec<OfferID> {
+        self.expire_offers(now)
+            .into_iter()
+            .map(|o| o.get_id().clone())
+            .collect()
+    }
+
+    /// Like `expire`, but returns the offers themselves.
+    pub fn expire_offers(&mut self, now: Instant) -> Vec<Offer> {
+        self.take_matching_held(|h| {
+            h.expires_at.map_or(false, |at| at <= now)
+        })
+    }
+
+    fn take_matching_held<F>(&mut self, predicate: F) -> Vec<Offer>
+        where F: Fn(&HeldOffer) -> bool
+    {
+        let (taken, kept): (Vec<HeldOffer>, Vec<HeldOffer>) =
+            self.offers.drain(..).partition(|h| predicate(h));
+        self.offers = kept;
+        taken.into_iter().map(|h| h.offer).collect()
+    }
+}
@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt;
+
+use proto::mesos::{Offer, Offer_Operation, Resource, Resource_DiskInfo,
+                   Resource_DiskInfo_Persistence, Value_Type, Volume,
+                   Volume_Mode};
+use resources;
+use util;
+
+/// Why a persistent volume cannot be created on a resource.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VolumeError {
+    /// The resource is not a scalar `disk` resource.
+    NotDisk(String),
+    /// The disk is unreserved; volumes can only be created on disk
+    /// reserved for a role, statically or dynamically.
+    Unreserved,
+    /// The disk already holds the persistent volume with this id.
+    AlreadyPersistent(String),
+}
+
+impl fmt::Display for VolumeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VolumeError::NotDisk(ref name) => {
+                write!(f, "volumes need a scalar disk resource, not {}", name)
+            }
+            VolumeError::Unreserved => {
+                write!(f, "volumes can only be created on reserved disk")
+            }
+            VolumeError::AlreadyPersistent(ref id) => {
+                write!(f, "disk already holds persistent volume {}", id)
+            }
+        }
+    }
+}
+
+impl Error for VolumeError {
+    fn description(&self) -> &str {
+        "invalid persistent volume"
+    }
+}
+
+/// A persistent volume, identified by its persistence id, and where it
+/// is mounted in the containers of tasks that use it.
+///
+/// A volume is created on reserved disk with a CREATE operation, after
+/// which it is offered again, to this framework's role only, as a disk
+/// resource carrying the persistence id.  Launching a task with that
+/// resource attaches the volume; a DESTROY operation deletes its data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PersistentVolume {
+    pub id: String,
+    pub principal: Option<String>,
+    pub container_path: String,
+    pub mode: Volume_Mode,
+}
+
+impl PersistentVolume {
+    pub fn new(id: &str, container_path: &str) -> PersistentVolume {
+        PersistentVolume {
+            id: id.to_string(),
+            principal: None,
+            container_path: container_path.to_string(),
+            mode: Volume_Mode::RW,
+        }
+    }
+
+    pub fn principal(mut self, principal: &str) -> PersistentVolume {
+        self.principal = Some(principal.to_string());
+        self
+    }
+
+    pub fn read_only(mut self) -> PersistentVolume {
+        self.mode = Volume_Mode::RO;
+        self
+    }
+
+    pub fn disk_info(&self) -> Resource_DiskInfo {
+        let mut persistence = Resource_DiskInfo_Persistence::new();
+        persistence.set_id(self.id.clone());
+        if let Some(ref principal) = self.principal {
+            persistence.set_principal(principal.clone());
+        }
+
+        let mut volume = Volume::new();
+        volume.set_container_path(self.container_path.clone());
+        volume.set_mode(self.mode);
+
+        let mut disk = Resource_DiskInfo::new();
+        disk.set_persistence(persistence);
+        disk.set_volume(volume);
+        disk
+    }
+
+    /// Returns the reserved `disk` as it will look once this volume has
+    /// been created on it.  Any disk source is kept.
+    pub fn create(&self, disk: &Resource) -> Result<Resource, VolumeError> {
+        if disk.get_name() != "disk" ||
+           disk.get_field_type() != Value_Type::SCALAR {
+            return Err(VolumeError::NotDisk(disk.get_name().to_string()));
+        }
+        if resources::is_unreserved(disk) {
+            return Err(VolumeError::Unreserved);
+        }
+        if let Some(id) = resources::persistence_id(disk) {
+            return Err(VolumeError::AlreadyPersistent(id.to_string()));
+        }
+
+        let mut info = self.disk_info();
+        if disk.get_disk().has_source() {
+            info.set_source(disk.get_disk().get_source().clone());
+        }
+        let mut volume = disk.clone();
+        volume.set_disk(info);
+        Ok(volume)
+    }
+
+    /// Builds the CREATE operation for this volume on the reserved
+    /// `disk`.
+    pub fn create_operation(&self,
+                            disk: &Resource)
+                            -> Result<Offer_Operation, VolumeError> {
+        Ok(util::create_operation(vec![self.create(disk)?]))
+    }
+
+    /// Finds this volume in `offers`.  The returned resource is what a
+    /// task has to be launched with to use the volume.
+    pub fn find<'a>(&self,
+                    offers: &[&'a Offer])
+                    -> Option<(&'a Offer, &'a Resource)> {
+        find_volume(offers, &self.id)
+    }
+
+    /// Builds the DESTROY operation for this volume if it is in
+    /// `offers`.
+    pub fn destroy_operation(&self,
+                             offers: &[&Offer])
+                             -> Option<Offer_Operation> {
+        self.find(offers)
+            .map(|(_, volume)| util::destroy_operation(vec![volume.clone()]))
+    }
+}
+
+/// Finds the persistent volume with persistence id `id` in `offers`,
+/// along with the offer it is in.
+pub fn find_volume<'a>(offers: &[&'a Offer],
+                       id: &str)
+                       -> Option<(&'a Offer, &'a Resource)> {
+    for offer in offers {
+        for resource in offer.get_resources() {
+            if resources::persistence_id(resource) == Some(id) {
+                return Some((offer, resource));
+            }
+        }
+    }
+    None
+}
+
+/// All persistent volumes in `offers`.
+pub fn volumes<'a>(offers: &[&'a Offer]) -> Vec<&'a Resource> {
+    offers.iter()
+          .flat_map(|o| o.get_resources())
+          .filter(|r| resources::persistence_id(r).is_some())
+          .collect()
+}
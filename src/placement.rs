@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use proto::mesos::{Offer, Resource, SlaveID, Value_Type};
+use task_queue::TaskSpec;
+use util;
+
+/// One agent's offers, as grouped by the router, together with what is
+/// left of them after the tasks already placed this round.  Only
+/// candidates that can hold the task are ever offered to a strategy.
+pub struct Candidate<'a> {
+    pub slave_id: &'a SlaveID,
+    pub offers: &'a [&'a Offer],
+    pub remaining: &'a [Resource],
+}
+
+/// Decides which agent a task goes to.
+pub trait PlacementStrategy {
+    /// Returns the index into `candidates` to place `spec` on, or None
+    /// to leave the task queued.  `candidates` is never empty.
+    fn select(&mut self,
+              spec: &TaskSpec,
+              candidates: &[Candidate])
+              -> Option<usize>;
+
+    /// Called once the task has been placed on `offer`'s agent.
+    fn placed(&mut self, _spec: &TaskSpec, _offer: &Offer) {}
+
+    /// Called once a task placed on `slave_id` has gone, e.g. because it
+    /// terminated or failed to launch.
+    fn released(&mut self, _spec: &TaskSpec, _slave_id: &SlaveID) {}
+}
+
+/// Places each task on the first agent it fits on, in offer order.
+pub struct FirstFit;
+
+impl PlacementStrategy for FirstFit {
+    fn select(&mut self, _: &TaskSpec, _: &[Candidate]) -> Option<usize> {
+        Some(0)
+    }
+}
+
+/// Bin-packs tasks onto the agent they fill the most, i.e. the one
+/// with the smallest fraction of the requested scalar resources left
+/// over afterwards.  Ties go to the earlier agent.
+pub struct BestFit;
+
+impl BestFit {
+    fn leftover(spec: &TaskSpec, candidate: &Candidate) -> f64 {
+        spec.resources
+            .iter()
+            .filter(|r| r.get_field_type() == Value_Type::SCALAR)
+            .map(|want| {
+                let have = util::sum_scalar_resources(want.get_name(),
+                                                      candidate.remaining);
+                if have <= 0f64 {
+                    0f64
+                } else {
+                    (have - want.get_scalar().get_value()) / have
+                }
+            })
+            .fold(0f64, |acc, l| acc + l)
+    }
+}
+
+impl PlacementStrategy for BestFit {
+    fn select(&mut self,
+              spec: &TaskSpec,
+              candidates: &[Candidate])
+              -> Option<usize> {
+        let mut best: Option<(usize, f64)> = None;
+        for (idx, candidate) in candidates.iter().enumerate() {
+            let leftover = BestFit::leftover(spec, candidate);
+            match best {
+                Some((_, l)) if l <= leftover => {}
+                _ => best = Some((idx, leftover)),
+            }
+        }
+        best.map(|(idx, _)| idx)
+    }
+}
+
+/// Spreads tasks evenly across the values of an agent attribute such as
+/// a rack or zone, placing each task under the value that has received
+/// the fewest of this strategy's tasks still running.  Agents without
+/// the attribute share the empty value.  Ties go to the earlier agent.
+pub struct SpreadByAttribute {
+    attribute: String,
+    counts: HashMap<String, usize>,
+    /// The attribute value of each agent tasks were placed on, to
+    /// release them under once they are gone.
+    values: HashMap<String, String>,
+}
+
+impl SpreadByAttribute {
+    pub fn new(attribute: &str) -> SpreadByAttribute {
+        SpreadByAttribute {
+            attribute: attribute.to_string(),
+            counts: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+
+    /// Tasks placed and not yet released under each attribute value.
+    pub fn counts(&self) -> &HashMap<String, usize> {
+        &self.counts
+    }
+
+    /// Forgets a task placed under `value`, e.g. once it has terminated.
+    pub fn release(&mut self, value: &str) {
+        if let Some(count) = self.counts.get_mut(value) {
+            if *count > 0 {
+                *count -= 1;
+            }
+        }
+    }
+
+    fn value_of(&self, offer: &Offer) -> String {
+        util::get_attribute_value(offer, &self.attribute)
+            .unwrap_or_default()
+    }
+}
+
+impl PlacementStrategy for SpreadByAttribute {
+    fn select(&mut self,
+              _: &TaskSpec,
+              candidates: &[Candidate])
+              -> Option<usize> {
+        let mut best: Option<(usize, usize)> = None;
+        for (idx, candidate) in candidates.iter().enumerate() {
+            let value = self.value_of(candidate.offers[0]);
+            let count = *self.counts.get(&value).unwrap_or(&0);
+            match best {
+                Some((_, c)) if c <= count => {}
+                _ => best = Some((idx, count)),
+            }
+        }
+        best.map(|(idx, _)| idx)
+    }
+
+    fn placed(&mut self, _: &TaskSpec, offer: &Offer) {
+        let value = self.value_of(offer);
+        self.values
+            .insert(offer.get_slave_id().get_value().to_string(),
+                    value.clone());
+        *self.counts.entry(value).or_insert(0) += 1;
+    }
+
+    fn released(&mut self, _: &TaskSpec, slave_id: &SlaveID) {
+        let value = self.values.get(slave_id.get_value()).cloned();
+        if let Some(value) = value {
+            self.release(&value);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use proto::mesos::{ContainerInfo_DockerInfo_PortMapping, DiscoveryInfo,
+                   DiscoveryInfo_Visibility, Environment_Variable, Offer,
+                   Port, Ports, Resource, TaskInfo, Value_Type};
+use resources::{self, Resources};
+use util;
+
+/// Ports handed out by a `PortAllocator`, together with the `ports`
+/// resources (keeping their offered roles) a task must claim for them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortAllocation {
+    pub ports: Vec<u64>,
+    pub resources: Vec<Resource>,
+}
+
+impl PortAllocation {
+    /// Names each allocated port, in order, for a `DiscoveryInfo`.
+    /// Ports beyond the end of `names` are left unnamed.
+    pub fn discovery_ports(&self, names: &[&str], protocol: &str) -> Ports {
+        let mut ports = Ports::new();
+        for (idx, number) in self.ports.iter().enumerate() {
+            let mut port = Port::new();
+            port.set_number(*number as u32);
+            port.set_protocol(protocol.to_string());
+            if let Some(name) = names.get(idx) {
+                port.set_name(name.to_string());
+            }
+            ports.mut_ports().push(port);
+        }
+        ports
+    }
+
+    /// Maps each allocated host port to the container port at the same
+    /// position in `container_ports`, or to itself if there is none.
+    pub fn port_mappings(&self,
+                         container_ports: &[u32],
+                         protocol: &str)
+                         -> Vec<ContainerInfo_DockerInfo_PortMapping> {
+        self.ports
+            .iter()
+            .enumerate()
+            .map(|(idx, host_port)| {
+                let host_port = *host_port as u32;
+                let container_port = container_ports.get(idx)
+                                                    .cloned()
+                                                    .unwrap_or(host_port);
+                let mut mapping = ContainerInfo_DockerInfo_PortMapping::new();
+                mapping.set_host_port(host_port);
+                mapping.set_container_port(container_port);
+                mapping.set_protocol(protocol.to_string());
+                mapping
+            })
+            .collect()
+    }
+
+    /// Adds the ports resources to `task_info` and, Marathon style,
+    /// exposes the ports to its command as `PORT0`, `PORT1`, ... and
+    /// `PORT` (the first one).
+    pub fn apply(&self, task_info: &mut TaskInfo) {
+        for resource in self.resources.iter() {
+            task_info.mut_resources().push(resource.clone());
+        }
+        if self.ports.is_empty() || !task_info.has_command() {
+            return;
+        }
+        let mut vars = vec![("PORT".to_string(), self.ports[0])];
+        for (idx, port) in self.ports.iter().enumerate() {
+            vars.push((format!("PORT{}", idx), *port));
+        }
+        let environment = task_info.mut_command().mut_environment();
+        for (name, port) in vars {
+            let mut var = Environment_Variable::new();
+            var.set_name(name);
+            var.set_value(port.to_string());
+            environment.mut_variables().push(var);
+        }
+    }
+
+    /// Sets the allocated ports on a task's `DiscoveryInfo`, creating
+    /// one visible to the framework if the task has none.
+    pub fn apply_discovery(&self,
+                           task_info: &mut TaskInfo,
+                           names: &[&str],
+                           protocol: &str) {
+        if !task_info.has_discovery() {
+            let mut discovery = DiscoveryInfo::new();
+            discovery.set_visibility(DiscoveryInfo_Visibility::FRAMEWORK);
+            discovery.set_name(task_info.get_name().to_string());
+            task_info.set_discovery(discovery);
+        }
+        task_info.mut_discovery()
+                 .set_ports(self.discovery_ports(names, protocol));
+    }
+}
+
+/// Hands out ports from the `ports` resources of an agent's offers,
+/// never giving out the same port twice.
+pub struct PortAllocator {
+    available: Resources,
+}
+
+impl PortAllocator {
+    /// Allocates from the `ports` offered in an offer group as passed to
+    /// `Scheduler::offers`.
+    pub fn new(offers: &[&Offer]) -> PortAllocator {
+        PortAllocator::from_resources(&offers.iter()
+                                             .flat_map(|o| o.get_resources())
+                                             .cloned()
+                                             .collect::<Vec<Resource>>()
+                                             .into())
+    }
+
+    pub fn from_resources(resources: &Resources) -> PortAllocator {
+        PortAllocator {
+            available: resources.filter(|r| {
+                r.get_name() == "ports" &&
+                r.get_field_type() == Value_Type::RANGES
+            }),
+        }
+    }
+
+    /// The ports still available, as merged `(begin, end)` intervals.
+    pub fn available(&self) -> Vec<(u64, u64)> {
+        self.available
+            .flatten("*")
+            .iter()
+            .flat_map(|r| resources::range_intervals(r))
+            .collect()
+    }
+
+    pub fn count(&self) -> u64 {
+        self.available()
+            .iter()
+            .fold(0, |acc, &(begin, end)| acc + (end - begin + 1))
+    }
+
+    /// Allocates the `count` lowest available ports.
+    pub fn allocate(&mut self, count: usize) -> Option<PortAllocation> {
+        let ports: Vec<u64> = self.available()
+                                  .into_iter()
+                                  .flat_map(|(begin, end)| begin..end + 1)
+                                  .take(count)
+                                  .collect();
+        if ports.len() < count {
+            return None;
+        }
+        self.allocate_specific(&ports)
+    }
+
+    /// Allocates `count` consecutive ports from the lowest range that
+    /// has room for them.
+    pub fn allocate_contiguous(&mut self,
+                               count: usize)
+                               -> Option<PortAllocation> {
+        let begin = self.available()
+                        .into_iter()
+                        .find(|&(begin, end)| end - begin + 1 >= count as u64)
+                        .map(|(begin, _)| begin);
+        match begin {
+            Some(begin) => {
+                let ports: Vec<u64> = (begin..begin + count as u64).collect();
+                self.allocate_specific(&ports)
+            }
+            None => None,
+        }
+    }
+
+    /// Allocates exactly the requested ports, or nothing if any of them
+    /// is not available.
+    pub fn allocate_specific(&mut self,
+                             ports: &[u64])
+                             -> Option<PortAllocation> {
+        let wanted: Vec<(u64, u64)> = ports.iter().map(|&p| (p, p)).collect();
+        let wanted = Resources::from_vec(vec![util::ranges("ports",
+                                                           "*",
+                                                           &wanted)]);
+        let found = match self.available.find(&wanted) {
+            Some(found) => found,
+            None => return None,
+        };
+        self.available -= &found;
+        Some(PortAllocation {
+            ports: ports.to_vec(),
+            resources: found.into_vec(),
+        })
+    }
+}
@@ -2218,6 +2218,8 @@ pub struct Call {
     reconcile: ::protobuf::SingularPtrField<Call_Reconcile>,
     message: ::protobuf::SingularPtrField<Call_Message>,
     request: ::protobuf::SingularPtrField<Call_Request>,
+    accept_inverse_offers: ::protobuf::SingularPtrField<Call_AcceptInverseOffers>,
+    decline_inverse_offers: ::protobuf::SingularPtrField<Call_DeclineInverseOffers>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -2250,6 +2252,8 @@ impl Call {
                     reconcile: ::protobuf::SingularPtrField::none(),
                     message: ::protobuf::SingularPtrField::none(),
                     request: ::protobuf::SingularPtrField::none(),
+                    accept_inverse_offers: ::protobuf::SingularPtrField::none(),
+                    decline_inverse_offers: ::protobuf::SingularPtrField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2605,6 +2609,72 @@ impl Call {
     pub fn get_request<'a>(&'a self) -> &'a Call_Request {
         self.request.as_ref().unwrap_or_else(|| Call_Request::default_instance())
     }
+
+    // optional .mesos.scheduler.Call.AcceptInverseOffers accept_inverse_offers = 13;
+
+    pub fn clear_accept_inverse_offers(&mut self) {
+        self.accept_inverse_offers.clear();
+    }
+
+    pub fn has_accept_inverse_offers(&self) -> bool {
+        self.accept_inverse_offers.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accept_inverse_offers(&mut self, v: Call_AcceptInverseOffers) {
+        self.accept_inverse_offers = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_accept_inverse_offers<'a>(&'a mut self) -> &'a mut Call_AcceptInverseOffers {
+        if self.accept_inverse_offers.is_none() {
+            self.accept_inverse_offers.set_default();
+        };
+        self.accept_inverse_offers.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_accept_inverse_offers(&mut self) -> Call_AcceptInverseOffers {
+        self.accept_inverse_offers.take().unwrap_or_else(|| Call_AcceptInverseOffers::new())
+    }
+
+    pub fn get_accept_inverse_offers<'a>(&'a self) -> &'a Call_AcceptInverseOffers {
+        self.accept_inverse_offers.as_ref().unwrap_or_else(|| Call_AcceptInverseOffers::default_instance())
+    }
+
+    // optional .mesos.scheduler.Call.DeclineInverseOffers decline_inverse_offers = 14;
+
+    pub fn clear_decline_inverse_offers(&mut self) {
+        self.decline_inverse_offers.clear();
+    }
+
+    pub fn has_decline_inverse_offers(&self) -> bool {
+        self.decline_inverse_offers.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decline_inverse_offers(&mut self, v: Call_DeclineInverseOffers) {
+        self.decline_inverse_offers = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_decline_inverse_offers<'a>(&'a mut self) -> &'a mut Call_DeclineInverseOffers {
+        if self.decline_inverse_offers.is_none() {
+            self.decline_inverse_offers.set_default();
+        };
+        self.decline_inverse_offers.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_decline_inverse_offers(&mut self) -> Call_DeclineInverseOffers {
+        self.decline_inverse_offers.take().unwrap_or_else(|| Call_DeclineInverseOffers::new())
+    }
+
+    pub fn get_decline_inverse_offers<'a>(&'a self) -> &'a Call_DeclineInverseOffers {
+        self.decline_inverse_offers.as_ref().unwrap_or_else(|| Call_DeclineInverseOffers::default_instance())
+    }
 }
 
 impl ::protobuf::Message for Call {
@@ -2653,6 +2723,12 @@ impl ::protobuf::Message for Call {
                 11 => {
                     try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.request));
                 },
+                13 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.accept_inverse_offers));
+                },
+                14 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.decline_inverse_offers));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2708,6 +2784,14 @@ impl ::protobuf::Message for Call {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.accept_inverse_offers.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.decline_inverse_offers.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2767,6 +2851,16 @@ impl ::protobuf::Message for Call {
             try!(os.write_raw_varint32(v.get_cached_size()));
             try!(v.write_to_with_cached_sizes(os));
         };
+        if let Some(v) = self.accept_inverse_offers.as_ref() {
+            try!(os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.decline_inverse_offers.as_ref() {
+            try!(os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2864,6 +2958,16 @@ impl ::protobuf::MessageStatic for Call {
                     Call::has_request,
                     Call::get_request,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "accept_inverse_offers",
+                    Call::has_accept_inverse_offers,
+                    Call::get_accept_inverse_offers,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "decline_inverse_offers",
+                    Call::has_decline_inverse_offers,
+                    Call::get_decline_inverse_offers,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Call>(
                     "Call",
                     fields,
@@ -2887,6 +2991,8 @@ impl ::protobuf::Clear for Call {
         self.clear_reconcile();
         self.clear_message();
         self.clear_request();
+        self.clear_accept_inverse_offers();
+        self.clear_decline_inverse_offers();
         self.unknown_fields.clear();
     }
 }
@@ -2904,6 +3010,8 @@ impl ::std::cmp::PartialEq for Call {
         self.reconcile == other.reconcile &&
         self.message == other.message &&
         self.request == other.request &&
+        self.accept_inverse_offers == other.accept_inverse_offers &&
+        self.decline_inverse_offers == other.decline_inverse_offers &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -3647,6 +3755,7 @@ pub struct Call_Kill {
     // message fields
     task_id: ::protobuf::SingularPtrField<super::mesos::TaskID>,
     slave_id: ::protobuf::SingularPtrField<super::mesos::SlaveID>,
+    kill_policy: ::protobuf::SingularPtrField<super::mesos::KillPolicy>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -3670,6 +3779,7 @@ impl Call_Kill {
                 Call_Kill {
                     task_id: ::protobuf::SingularPtrField::none(),
                     slave_id: ::protobuf::SingularPtrField::none(),
+                    kill_policy: ::protobuf::SingularPtrField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3742,6 +3852,39 @@ impl Call_Kill {
     pub fn get_slave_id<'a>(&'a self) -> &'a super::mesos::SlaveID {
         self.slave_id.as_ref().unwrap_or_else(|| super::mesos::SlaveID::default_instance())
     }
+
+    // optional .mesos.KillPolicy kill_policy = 3;
+
+    pub fn clear_kill_policy(&mut self) {
+        self.kill_policy.clear();
+    }
+
+    pub fn has_kill_policy(&self) -> bool {
+        self.kill_policy.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kill_policy(&mut self, v: super::mesos::KillPolicy) {
+        self.kill_policy = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kill_policy<'a>(&'a mut self) -> &'a mut super::mesos::KillPolicy {
+        if self.kill_policy.is_none() {
+            self.kill_policy.set_default();
+        };
+        self.kill_policy.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_kill_policy(&mut self) -> super::mesos::KillPolicy {
+        self.kill_policy.take().unwrap_or_else(|| super::mesos::KillPolicy::new())
+    }
+
+    pub fn get_kill_policy<'a>(&'a self) -> &'a super::mesos::KillPolicy {
+        self.kill_policy.as_ref().unwrap_or_else(|| super::mesos::KillPolicy::default_instance())
+    }
 }
 
 impl ::protobuf::Message for Call_Kill {
@@ -3762,6 +3905,9 @@ impl ::protobuf::Message for Call_Kill {
                 2 => {
                     try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.slave_id));
                 },
+                3 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.kill_policy));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3782,6 +3928,10 @@ impl ::protobuf::Message for Call_Kill {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.kill_policy.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3798,6 +3948,11 @@ impl ::protobuf::Message for Call_Kill {
             try!(os.write_raw_varint32(v.get_cached_size()));
             try!(v.write_to_with_cached_sizes(os));
         };
+        if let Some(v) = self.kill_policy.as_ref() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -3850,6 +4005,11 @@ impl ::protobuf::MessageStatic for Call_Kill {
                     Call_Kill::has_slave_id,
                     Call_Kill::get_slave_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "kill_policy",
+                    Call_Kill::has_kill_policy,
+                    Call_Kill::get_kill_policy,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Call_Kill>(
                     "Call_Kill",
                     fields,
@@ -3864,6 +4024,7 @@ impl ::protobuf::Clear for Call_Kill {
     fn clear(&mut self) {
         self.clear_task_id();
         self.clear_slave_id();
+        self.clear_kill_policy();
         self.unknown_fields.clear();
     }
 }
@@ -3872,6 +4033,7 @@ impl ::std::cmp::PartialEq for Call_Kill {
     fn eq(&self, other: &Call_Kill) -> bool {
         self.task_id == other.task_id &&
         self.slave_id == other.slave_id &&
+        self.kill_policy == other.kill_policy &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -5313,6 +5475,462 @@ impl ::std::fmt::Debug for Call_Request {
     }
 }
 
+#[derive(Clone,Default)]
+pub struct Call_AcceptInverseOffers {
+    // message fields
+    inverse_offer_ids: ::protobuf::RepeatedField<super::mesos::OfferID>,
+    filters: ::protobuf::SingularPtrField<super::mesos::Filters>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Call_AcceptInverseOffers {}
+
+impl Call_AcceptInverseOffers {
+    pub fn new() -> Call_AcceptInverseOffers {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Call_AcceptInverseOffers {
+        static mut instance: ::protobuf::lazy::Lazy<Call_AcceptInverseOffers> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Call_AcceptInverseOffers,
+        };
+        unsafe {
+            instance.get(|| {
+                Call_AcceptInverseOffers {
+                    inverse_offer_ids: ::protobuf::RepeatedField::new(),
+                    filters: ::protobuf::SingularPtrField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .mesos.OfferID inverse_offer_ids = 1;
+
+    pub fn clear_inverse_offer_ids(&mut self) {
+        self.inverse_offer_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inverse_offer_ids(&mut self, v: ::protobuf::RepeatedField<super::mesos::OfferID>) {
+        self.inverse_offer_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_inverse_offer_ids<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<super::mesos::OfferID> {
+        &mut self.inverse_offer_ids
+    }
+
+    // Take field
+    pub fn take_inverse_offer_ids(&mut self) -> ::protobuf::RepeatedField<super::mesos::OfferID> {
+        ::std::mem::replace(&mut self.inverse_offer_ids, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_inverse_offer_ids<'a>(&'a self) -> &'a [super::mesos::OfferID] {
+        &self.inverse_offer_ids
+    }
+
+    // optional .mesos.Filters filters = 2;
+
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    pub fn has_filters(&self) -> bool {
+        self.filters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filters(&mut self, v: super::mesos::Filters) {
+        self.filters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filters<'a>(&'a mut self) -> &'a mut super::mesos::Filters {
+        if self.filters.is_none() {
+            self.filters.set_default();
+        };
+        self.filters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_filters(&mut self) -> super::mesos::Filters {
+        self.filters.take().unwrap_or_else(|| super::mesos::Filters::new())
+    }
+
+    pub fn get_filters<'a>(&'a self) -> &'a super::mesos::Filters {
+        self.filters.as_ref().unwrap_or_else(|| super::mesos::Filters::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Call_AcceptInverseOffers {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.inverse_offer_ids));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.filters));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.inverse_offer_ids.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.filters.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.inverse_offer_ids.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.filters.as_ref() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Call_AcceptInverseOffers>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Call_AcceptInverseOffers {
+    fn new() -> Call_AcceptInverseOffers {
+        Call_AcceptInverseOffers::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Call_AcceptInverseOffers>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "inverse_offer_ids",
+                    Call_AcceptInverseOffers::get_inverse_offer_ids,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "filters",
+                    Call_AcceptInverseOffers::has_filters,
+                    Call_AcceptInverseOffers::get_filters,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Call_AcceptInverseOffers>(
+                    "Call_AcceptInverseOffers",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Call_AcceptInverseOffers {
+    fn clear(&mut self) {
+        self.clear_inverse_offer_ids();
+        self.clear_filters();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Call_AcceptInverseOffers {
+    fn eq(&self, other: &Call_AcceptInverseOffers) -> bool {
+        self.inverse_offer_ids == other.inverse_offer_ids &&
+        self.filters == other.filters &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Call_AcceptInverseOffers {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Call_DeclineInverseOffers {
+    // message fields
+    inverse_offer_ids: ::protobuf::RepeatedField<super::mesos::OfferID>,
+    filters: ::protobuf::SingularPtrField<super::mesos::Filters>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Call_DeclineInverseOffers {}
+
+impl Call_DeclineInverseOffers {
+    pub fn new() -> Call_DeclineInverseOffers {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Call_DeclineInverseOffers {
+        static mut instance: ::protobuf::lazy::Lazy<Call_DeclineInverseOffers> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Call_DeclineInverseOffers,
+        };
+        unsafe {
+            instance.get(|| {
+                Call_DeclineInverseOffers {
+                    inverse_offer_ids: ::protobuf::RepeatedField::new(),
+                    filters: ::protobuf::SingularPtrField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .mesos.OfferID inverse_offer_ids = 1;
+
+    pub fn clear_inverse_offer_ids(&mut self) {
+        self.inverse_offer_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inverse_offer_ids(&mut self, v: ::protobuf::RepeatedField<super::mesos::OfferID>) {
+        self.inverse_offer_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_inverse_offer_ids<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<super::mesos::OfferID> {
+        &mut self.inverse_offer_ids
+    }
+
+    // Take field
+    pub fn take_inverse_offer_ids(&mut self) -> ::protobuf::RepeatedField<super::mesos::OfferID> {
+        ::std::mem::replace(&mut self.inverse_offer_ids, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_inverse_offer_ids<'a>(&'a self) -> &'a [super::mesos::OfferID] {
+        &self.inverse_offer_ids
+    }
+
+    // optional .mesos.Filters filters = 2;
+
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    pub fn has_filters(&self) -> bool {
+        self.filters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filters(&mut self, v: super::mesos::Filters) {
+        self.filters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filters<'a>(&'a mut self) -> &'a mut super::mesos::Filters {
+        if self.filters.is_none() {
+            self.filters.set_default();
+        };
+        self.filters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_filters(&mut self) -> super::mesos::Filters {
+        self.filters.take().unwrap_or_else(|| super::mesos::Filters::new())
+    }
+
+    pub fn get_filters<'a>(&'a self) -> &'a super::mesos::Filters {
+        self.filters.as_ref().unwrap_or_else(|| super::mesos::Filters::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Call_DeclineInverseOffers {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.inverse_offer_ids));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.filters));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.inverse_offer_ids.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.filters.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.inverse_offer_ids.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.filters.as_ref() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Call_DeclineInverseOffers>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Call_DeclineInverseOffers {
+    fn new() -> Call_DeclineInverseOffers {
+        Call_DeclineInverseOffers::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Call_DeclineInverseOffers>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "inverse_offer_ids",
+                    Call_DeclineInverseOffers::get_inverse_offer_ids,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "filters",
+                    Call_DeclineInverseOffers::has_filters,
+                    Call_DeclineInverseOffers::get_filters,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Call_DeclineInverseOffers>(
+                    "Call_DeclineInverseOffers",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Call_DeclineInverseOffers {
+    fn clear(&mut self) {
+        self.clear_inverse_offer_ids();
+        self.clear_filters();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Call_DeclineInverseOffers {
+    fn eq(&self, other: &Call_DeclineInverseOffers) -> bool {
+        self.inverse_offer_ids == other.inverse_offer_ids &&
+        self.filters == other.filters &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Call_DeclineInverseOffers {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum Call_Type {
     UNKNOWN = 0,
@@ -5328,6 +5946,8 @@ pub enum Call_Type {
     MESSAGE = 10,
     REQUEST = 11,
     SUPPRESS = 12,
+    ACCEPT_INVERSE_OFFERS = 13,
+    DECLINE_INVERSE_OFFERS = 14,
 }
 
 impl ::protobuf::ProtobufEnum for Call_Type {
@@ -5350,6 +5970,8 @@ impl ::protobuf::ProtobufEnum for Call_Type {
             10 => ::std::option::Option::Some(Call_Type::MESSAGE),
             11 => ::std::option::Option::Some(Call_Type::REQUEST),
             12 => ::std::option::Option::Some(Call_Type::SUPPRESS),
+            13 => ::std::option::Option::Some(Call_Type::ACCEPT_INVERSE_OFFERS),
+            14 => ::std::option::Option::Some(Call_Type::DECLINE_INVERSE_OFFERS),
             _ => ::std::option::Option::None
         }
     }
@@ -5369,6 +5991,8 @@ impl ::protobuf::ProtobufEnum for Call_Type {
             Call_Type::MESSAGE,
             Call_Type::REQUEST,
             Call_Type::SUPPRESS,
+            Call_Type::ACCEPT_INVERSE_OFFERS,
+            Call_Type::DECLINE_INVERSE_OFFERS,
         ];
         values
     }
@@ -5456,7 +6080,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x12, 0x0b, 0x0a, 0x07, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x10, 0x05, 0x12, 0x0b, 0x0a,
     0x07, 0x46, 0x41, 0x49, 0x4c, 0x55, 0x52, 0x45, 0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x52,
     0x52, 0x4f, 0x52, 0x10, 0x07, 0x12, 0x0d, 0x0a, 0x09, 0x48, 0x45, 0x41, 0x52, 0x54, 0x42, 0x45,
-    0x41, 0x54, 0x10, 0x08, 0x22, 0xfb, 0x0b, 0x0a, 0x04, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x28, 0x0a,
+    0x41, 0x54, 0x10, 0x08, 0x22, 0xb7, 0x0f, 0x0a, 0x04, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x28, 0x0a,
     0x0c, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
     0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x72, 0x61, 0x6d,
     0x65, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x44, 0x12, 0x28, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18,
@@ -5489,1004 +6113,1032 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x61, 0x6c, 0x6c, 0x2e, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x2e, 0x0a, 0x07, 0x72,
     0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x6d,
     0x65, 0x73, 0x6f, 0x73, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x43,
-    0x61, 0x6c, 0x6c, 0x2e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x48, 0x0a, 0x09, 0x53,
-    0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x12, 0x2c, 0x0a, 0x0e, 0x66, 0x72, 0x61, 0x6d,
-    0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b,
-    0x32, 0x14, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f,
-    0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x0d, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x18,
-    0x02, 0x20, 0x01, 0x28, 0x08, 0x1a, 0x78, 0x0a, 0x06, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x12,
-    0x21, 0x0a, 0x09, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03,
-    0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4f, 0x66, 0x66, 0x65, 0x72,
-    0x49, 0x44, 0x12, 0x2a, 0x0a, 0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73,
-    0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4f,
-    0x66, 0x66, 0x65, 0x72, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1f,
-    0x0a, 0x07, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32,
-    0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x1a,
-    0x4d, 0x0a, 0x07, 0x44, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x21, 0x0a, 0x09, 0x6f, 0x66,
-    0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e,
-    0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x49, 0x44, 0x12, 0x1f, 0x0a,
-    0x07, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e,
-    0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x1a, 0x48,
-    0x0a, 0x04, 0x4b, 0x69, 0x6c, 0x6c, 0x12, 0x1e, 0x0a, 0x07, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69,
+    0x61, 0x6c, 0x6c, 0x2e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x48, 0x0a, 0x15, 0x61,
+    0x63, 0x63, 0x65, 0x70, 0x74, 0x5f, 0x69, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x5f, 0x6f, 0x66,
+    0x66, 0x65, 0x72, 0x73, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x6d, 0x65, 0x73,
+    0x6f, 0x73, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x43, 0x61, 0x6c,
+    0x6c, 0x2e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x49, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x4f,
+    0x66, 0x66, 0x65, 0x72, 0x73, 0x12, 0x4a, 0x0a, 0x16, 0x64, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65,
+    0x5f, 0x69, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x5f, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x73, 0x18,
+    0x0e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x73, 0x63,
+    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x2e, 0x44, 0x65, 0x63,
+    0x6c, 0x69, 0x6e, 0x65, 0x49, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x4f, 0x66, 0x66, 0x65, 0x72,
+    0x73, 0x1a, 0x48, 0x0a, 0x09, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x12, 0x2c,
+    0x0a, 0x0e, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46,
+    0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x0d, 0x0a, 0x05,
+    0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x1a, 0x78, 0x0a, 0x06, 0x41,
+    0x63, 0x63, 0x65, 0x70, 0x74, 0x12, 0x21, 0x0a, 0x09, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69,
+    0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73,
+    0x2e, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x49, 0x44, 0x12, 0x2a, 0x0a, 0x0a, 0x6f, 0x70, 0x65, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x6d,
+    0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x2e, 0x4f, 0x70, 0x65, 0x72, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1f, 0x0a, 0x07, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x69,
+    0x6c, 0x74, 0x65, 0x72, 0x73, 0x1a, 0x4d, 0x0a, 0x07, 0x44, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65,
+    0x12, 0x21, 0x0a, 0x09, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4f, 0x66, 0x66, 0x65,
+    0x72, 0x49, 0x44, 0x12, 0x1f, 0x0a, 0x07, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x69, 0x6c,
+    0x74, 0x65, 0x72, 0x73, 0x1a, 0x70, 0x0a, 0x04, 0x4b, 0x69, 0x6c, 0x6c, 0x12, 0x1e, 0x0a, 0x07,
+    0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0d, 0x2e,
+    0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x49, 0x44, 0x12, 0x20, 0x0a, 0x08,
+    0x73, 0x6c, 0x61, 0x76, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e,
+    0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x12, 0x26,
+    0x0a, 0x0b, 0x6b, 0x69, 0x6c, 0x6c, 0x5f, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4b, 0x69, 0x6c, 0x6c,
+    0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x1a, 0x54, 0x0a, 0x08, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f,
+    0x77, 0x6e, 0x12, 0x26, 0x0a, 0x0b, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e,
+    0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x49, 0x44, 0x12, 0x20, 0x0a, 0x08, 0x73, 0x6c,
+    0x61, 0x76, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d,
+    0x65, 0x73, 0x6f, 0x73, 0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x1a, 0x5d, 0x0a, 0x0b,
+    0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x12, 0x20, 0x0a, 0x08, 0x73,
+    0x6c, 0x61, 0x76, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e,
+    0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x12, 0x1e, 0x0a,
+    0x07, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0d,
+    0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x49, 0x44, 0x12, 0x0c, 0x0a,
+    0x04, 0x75, 0x75, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c, 0x1a, 0x8a, 0x01, 0x0a, 0x09,
+    0x52, 0x65, 0x63, 0x6f, 0x6e, 0x63, 0x69, 0x6c, 0x65, 0x12, 0x33, 0x0a, 0x05, 0x74, 0x61, 0x73,
+    0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73,
+    0x2e, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x2e,
+    0x52, 0x65, 0x63, 0x6f, 0x6e, 0x63, 0x69, 0x6c, 0x65, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x1a, 0x48,
+    0x0a, 0x04, 0x54, 0x61, 0x73, 0x6b, 0x12, 0x1e, 0x0a, 0x07, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69,
     0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e,
     0x54, 0x61, 0x73, 0x6b, 0x49, 0x44, 0x12, 0x20, 0x0a, 0x08, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x5f,
     0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73,
-    0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x1a, 0x54, 0x0a, 0x08, 0x53, 0x68, 0x75, 0x74,
-    0x64, 0x6f, 0x77, 0x6e, 0x12, 0x26, 0x0a, 0x0b, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6d, 0x65, 0x73, 0x6f,
-    0x73, 0x2e, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x49, 0x44, 0x12, 0x20, 0x0a, 0x08,
-    0x73, 0x6c, 0x61, 0x76, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e,
-    0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x1a, 0x5d,
-    0x0a, 0x0b, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x12, 0x20, 0x0a,
-    0x08, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32,
-    0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x12,
-    0x1e, 0x0a, 0x07, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b,
-    0x32, 0x0d, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x49, 0x44, 0x12,
-    0x0c, 0x0a, 0x04, 0x75, 0x75, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c, 0x1a, 0x8a, 0x01,
-    0x0a, 0x09, 0x52, 0x65, 0x63, 0x6f, 0x6e, 0x63, 0x69, 0x6c, 0x65, 0x12, 0x33, 0x0a, 0x05, 0x74,
-    0x61, 0x73, 0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x6d, 0x65, 0x73,
-    0x6f, 0x73, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x43, 0x61, 0x6c,
-    0x6c, 0x2e, 0x52, 0x65, 0x63, 0x6f, 0x6e, 0x63, 0x69, 0x6c, 0x65, 0x2e, 0x54, 0x61, 0x73, 0x6b,
-    0x1a, 0x48, 0x0a, 0x04, 0x54, 0x61, 0x73, 0x6b, 0x12, 0x1e, 0x0a, 0x07, 0x74, 0x61, 0x73, 0x6b,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x6d, 0x65, 0x73, 0x6f,
-    0x73, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x49, 0x44, 0x12, 0x20, 0x0a, 0x08, 0x73, 0x6c, 0x61, 0x76,
-    0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73,
-    0x6f, 0x73, 0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x1a, 0x61, 0x0a, 0x07, 0x4d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x20, 0x0a, 0x08, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x5f, 0x69,
-    0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e,
-    0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x12, 0x26, 0x0a, 0x0b, 0x65, 0x78, 0x65, 0x63, 0x75,
-    0x74, 0x6f, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6d,
-    0x65, 0x73, 0x6f, 0x73, 0x2e, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x49, 0x44, 0x12,
-    0x0c, 0x0a, 0x04, 0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c, 0x1a, 0x2b, 0x0a,
-    0x07, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x20, 0x0a, 0x08, 0x72, 0x65, 0x71, 0x75,
-    0x65, 0x73, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73,
-    0x6f, 0x73, 0x2e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0xb5, 0x01, 0x0a, 0x04, 0x54,
-    0x79, 0x70, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x10, 0x00,
-    0x12, 0x0d, 0x0a, 0x09, 0x53, 0x55, 0x42, 0x53, 0x43, 0x52, 0x49, 0x42, 0x45, 0x10, 0x01, 0x12,
-    0x0c, 0x0a, 0x08, 0x54, 0x45, 0x41, 0x52, 0x44, 0x4f, 0x57, 0x4e, 0x10, 0x02, 0x12, 0x0a, 0x0a,
-    0x06, 0x41, 0x43, 0x43, 0x45, 0x50, 0x54, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x44, 0x45, 0x43,
-    0x4c, 0x49, 0x4e, 0x45, 0x10, 0x04, 0x12, 0x0a, 0x0a, 0x06, 0x52, 0x45, 0x56, 0x49, 0x56, 0x45,
-    0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x4b, 0x49, 0x4c, 0x4c, 0x10, 0x06, 0x12, 0x0c, 0x0a, 0x08,
-    0x53, 0x48, 0x55, 0x54, 0x44, 0x4f, 0x57, 0x4e, 0x10, 0x07, 0x12, 0x0f, 0x0a, 0x0b, 0x41, 0x43,
-    0x4b, 0x4e, 0x4f, 0x57, 0x4c, 0x45, 0x44, 0x47, 0x45, 0x10, 0x08, 0x12, 0x0d, 0x0a, 0x09, 0x52,
-    0x45, 0x43, 0x4f, 0x4e, 0x43, 0x49, 0x4c, 0x45, 0x10, 0x09, 0x12, 0x0b, 0x0a, 0x07, 0x4d, 0x45,
-    0x53, 0x53, 0x41, 0x47, 0x45, 0x10, 0x0a, 0x12, 0x0b, 0x0a, 0x07, 0x52, 0x45, 0x51, 0x55, 0x45,
-    0x53, 0x54, 0x10, 0x0b, 0x12, 0x0c, 0x0a, 0x08, 0x53, 0x55, 0x50, 0x50, 0x52, 0x45, 0x53, 0x53,
-    0x10, 0x0c, 0x42, 0x24, 0x0a, 0x1a, 0x6f, 0x72, 0x67, 0x2e, 0x61, 0x70, 0x61, 0x63, 0x68, 0x65,
-    0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72,
-    0x42, 0x06, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x73, 0x4a, 0xbb, 0x74, 0x0a, 0x07, 0x12, 0x05, 0x10,
-    0x00, 0xd0, 0x02, 0x01, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x10, 0x07, 0x14, 0x0a,
-    0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x12, 0x08, 0x17, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03,
-    0x14, 0x00, 0x33, 0x0a, 0x0b, 0x0a, 0x04, 0x08, 0xe7, 0x07, 0x00, 0x12, 0x03, 0x14, 0x00, 0x33,
-    0x0a, 0x0c, 0x0a, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x12, 0x03, 0x14, 0x07, 0x13, 0x0a, 0x0d,
-    0x0a, 0x06, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x12, 0x03, 0x14, 0x07, 0x13, 0x0a, 0x0e, 0x0a,
-    0x07, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x07, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x08, 0xe7, 0x07, 0x00, 0x07, 0x12, 0x03, 0x14, 0x16, 0x32, 0x0a, 0x08, 0x0a, 0x01, 0x08,
-    0x12, 0x03, 0x15, 0x00, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x08, 0xe7, 0x07, 0x01, 0x12, 0x03, 0x15,
-    0x00, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x08, 0xe7, 0x07, 0x01, 0x02, 0x12, 0x03, 0x15, 0x07, 0x1b,
-    0x0a, 0x0d, 0x0a, 0x06, 0x08, 0xe7, 0x07, 0x01, 0x02, 0x00, 0x12, 0x03, 0x15, 0x07, 0x1b, 0x0a,
-    0x0e, 0x0a, 0x07, 0x08, 0xe7, 0x07, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x07, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x08, 0xe7, 0x07, 0x01, 0x07, 0x12, 0x03, 0x15, 0x1e, 0x26, 0x0a, 0xbd, 0x01,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x05, 0x1f, 0x00, 0x97, 0x01, 0x01, 0x1a, 0xaf, 0x01, 0x2a, 0x0a,
-    0x20, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
-    0x20, 0x41, 0x50, 0x49, 0x2e, 0x0a, 0x0a, 0x20, 0x41, 0x6e, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
-    0x20, 0x69, 0x73, 0x20, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x64, 0x20, 0x75, 0x73,
-    0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64,
-    0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72,
-    0x20, 0x22, 0x75, 0x6e, 0x69, 0x6f, 0x6e, 0x22, 0x0a, 0x20, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x2c,
-    0x20, 0x73, 0x65, 0x65, 0x3a, 0x0a, 0x20, 0x68, 0x74, 0x74, 0x70, 0x73, 0x3a, 0x2f, 0x2f, 0x64,
-    0x65, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x72, 0x73, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65,
-    0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x2d, 0x62, 0x75,
-    0x66, 0x66, 0x65, 0x72, 0x73, 0x2f, 0x64, 0x6f, 0x63, 0x73, 0x2f, 0x74, 0x65, 0x63, 0x68, 0x6e,
-    0x69, 0x71, 0x75, 0x65, 0x73, 0x23, 0x75, 0x6e, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x08, 0x0d, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x00, 0x04,
-    0x00, 0x12, 0x04, 0x22, 0x02, 0x39, 0x03, 0x1a, 0x47, 0x20, 0x50, 0x6f, 0x73, 0x73, 0x69, 0x62,
-    0x6c, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2c, 0x20,
-    0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20,
-    0x69, 0x66, 0x0a, 0x20, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x07, 0x0b, 0x0a, 0xdc,
-    0x01, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x27, 0x04, 0x10, 0x1a, 0xcc,
-    0x01, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x76, 0x61,
-    0x6c, 0x75, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6c, 0x69, 0x73, 0x74,
-    0x2c, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x65, 0x6e, 0x73, 0x75, 0x72, 0x65, 0x20, 0x74, 0x68, 0x61,
-    0x74, 0x20, 0x69, 0x66, 0x20, 0x27, 0x74, 0x79, 0x70, 0x65, 0x27, 0x20, 0x69, 0x73, 0x20, 0x6e,
-    0x6f, 0x74, 0x20, 0x73, 0x65, 0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x66, 0x61,
-    0x75, 0x6c, 0x74, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x55, 0x4e,
-    0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x61, 0x62,
-    0x6c, 0x65, 0x73, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20,
-    0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x0a, 0x20, 0x69, 0x6e, 0x20,
-    0x61, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x77, 0x61, 0x72, 0x64, 0x73, 0x2d, 0x63, 0x6f, 0x6d, 0x70,
-    0x61, 0x74, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x77, 0x61, 0x79, 0x2e, 0x20, 0x53, 0x65, 0x65, 0x3a,
-    0x20, 0x4d, 0x45, 0x53, 0x4f, 0x53, 0x2d, 0x34, 0x39, 0x39, 0x37, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x27, 0x04, 0x0b, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x27, 0x0e, 0x0f, 0x0a, 0x28, 0x0a,
-    0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x29, 0x04, 0x13, 0x22, 0x19, 0x20, 0x53,
-    0x65, 0x65, 0x20, 0x27, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x64, 0x27, 0x20,
-    0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x29, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02,
-    0x01, 0x02, 0x12, 0x03, 0x29, 0x11, 0x12, 0x0a, 0x24, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02,
-    0x02, 0x12, 0x03, 0x2a, 0x04, 0x0f, 0x22, 0x15, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x4f, 0x66,
-    0x66, 0x65, 0x72, 0x73, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x04, 0x0a, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x2a, 0x0d, 0x0e, 0x0a, 0x25, 0x0a,
-    0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x2b, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53,
-    0x65, 0x65, 0x20, 0x27, 0x52, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x27, 0x20, 0x62, 0x65, 0x6c,
-    0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x2b, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12,
-    0x03, 0x2b, 0x0e, 0x0f, 0x0a, 0x24, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03,
-    0x2c, 0x04, 0x0f, 0x22, 0x15, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x55, 0x70, 0x64, 0x61, 0x74,
-    0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00,
-    0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x2c, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00,
-    0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x2c, 0x0d, 0x0e, 0x0a, 0x25, 0x0a, 0x06, 0x04, 0x00,
-    0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x2d, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20,
+    0x2e, 0x53, 0x6c, 0x61, 0x76, 0x65, 0x49, 0x44, 0x1a, 0x61, 0x0a, 0x07, 0x4d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x12, 0x20, 0x0a, 0x08, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x53, 0x6c,
+    0x61, 0x76, 0x65, 0x49, 0x44, 0x12, 0x26, 0x0a, 0x0b, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6d, 0x65, 0x73,
+    0x6f, 0x73, 0x2e, 0x45, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x49, 0x44, 0x12, 0x0c, 0x0a,
+    0x04, 0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c, 0x1a, 0x2b, 0x0a, 0x07, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x20, 0x0a, 0x08, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73,
+    0x2e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x61, 0x0a, 0x13, 0x41, 0x63, 0x63, 0x65,
+    0x70, 0x74, 0x49, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x73, 0x12,
+    0x29, 0x0a, 0x11, 0x69, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x5f, 0x6f, 0x66, 0x66, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65, 0x73,
+    0x6f, 0x73, 0x2e, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x49, 0x44, 0x12, 0x1f, 0x0a, 0x07, 0x66, 0x69,
+    0x6c, 0x74, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6d, 0x65,
+    0x73, 0x6f, 0x73, 0x2e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x1a, 0x62, 0x0a, 0x14, 0x44,
+    0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x4f, 0x66, 0x66,
+    0x65, 0x72, 0x73, 0x12, 0x29, 0x0a, 0x11, 0x69, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x5f, 0x6f,
+    0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e,
+    0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x49, 0x44, 0x12, 0x1f,
+    0x0a, 0x07, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x0e, 0x2e, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x22,
+    0xec, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e,
+    0x4f, 0x57, 0x4e, 0x10, 0x00, 0x12, 0x0d, 0x0a, 0x09, 0x53, 0x55, 0x42, 0x53, 0x43, 0x52, 0x49,
+    0x42, 0x45, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x54, 0x45, 0x41, 0x52, 0x44, 0x4f, 0x57, 0x4e,
+    0x10, 0x02, 0x12, 0x0a, 0x0a, 0x06, 0x41, 0x43, 0x43, 0x45, 0x50, 0x54, 0x10, 0x03, 0x12, 0x0b,
+    0x0a, 0x07, 0x44, 0x45, 0x43, 0x4c, 0x49, 0x4e, 0x45, 0x10, 0x04, 0x12, 0x0a, 0x0a, 0x06, 0x52,
+    0x45, 0x56, 0x49, 0x56, 0x45, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x4b, 0x49, 0x4c, 0x4c, 0x10,
+    0x06, 0x12, 0x0c, 0x0a, 0x08, 0x53, 0x48, 0x55, 0x54, 0x44, 0x4f, 0x57, 0x4e, 0x10, 0x07, 0x12,
+    0x0f, 0x0a, 0x0b, 0x41, 0x43, 0x4b, 0x4e, 0x4f, 0x57, 0x4c, 0x45, 0x44, 0x47, 0x45, 0x10, 0x08,
+    0x12, 0x0d, 0x0a, 0x09, 0x52, 0x45, 0x43, 0x4f, 0x4e, 0x43, 0x49, 0x4c, 0x45, 0x10, 0x09, 0x12,
+    0x0b, 0x0a, 0x07, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x10, 0x0a, 0x12, 0x0b, 0x0a, 0x07,
+    0x52, 0x45, 0x51, 0x55, 0x45, 0x53, 0x54, 0x10, 0x0b, 0x12, 0x0c, 0x0a, 0x08, 0x53, 0x55, 0x50,
+    0x50, 0x52, 0x45, 0x53, 0x53, 0x10, 0x0c, 0x12, 0x19, 0x0a, 0x15, 0x41, 0x43, 0x43, 0x45, 0x50,
+    0x54, 0x5f, 0x49, 0x4e, 0x56, 0x45, 0x52, 0x53, 0x45, 0x5f, 0x4f, 0x46, 0x46, 0x45, 0x52, 0x53,
+    0x10, 0x0d, 0x12, 0x1a, 0x0a, 0x16, 0x44, 0x45, 0x43, 0x4c, 0x49, 0x4e, 0x45, 0x5f, 0x49, 0x4e,
+    0x56, 0x45, 0x52, 0x53, 0x45, 0x5f, 0x4f, 0x46, 0x46, 0x45, 0x52, 0x53, 0x10, 0x0e, 0x42, 0x24,
+    0x0a, 0x1a, 0x6f, 0x72, 0x67, 0x2e, 0x61, 0x70, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x6d, 0x65, 0x73,
+    0x6f, 0x73, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x42, 0x06, 0x50, 0x72,
+    0x6f, 0x74, 0x6f, 0x73, 0x4a, 0xbb, 0x74, 0x0a, 0x07, 0x12, 0x05, 0x10, 0x00, 0xd0, 0x02, 0x01,
+    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x10, 0x07, 0x14, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x12, 0x08, 0x17, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x14, 0x00, 0x33, 0x0a,
+    0x0b, 0x0a, 0x04, 0x08, 0xe7, 0x07, 0x00, 0x12, 0x03, 0x14, 0x00, 0x33, 0x0a, 0x0c, 0x0a, 0x05,
+    0x08, 0xe7, 0x07, 0x00, 0x02, 0x12, 0x03, 0x14, 0x07, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x08, 0xe7,
+    0x07, 0x00, 0x02, 0x00, 0x12, 0x03, 0x14, 0x07, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x08, 0xe7, 0x07,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x07, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x08, 0xe7, 0x07,
+    0x00, 0x07, 0x12, 0x03, 0x14, 0x16, 0x32, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x15, 0x00,
+    0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x08, 0xe7, 0x07, 0x01, 0x12, 0x03, 0x15, 0x00, 0x27, 0x0a, 0x0c,
+    0x0a, 0x05, 0x08, 0xe7, 0x07, 0x01, 0x02, 0x12, 0x03, 0x15, 0x07, 0x1b, 0x0a, 0x0d, 0x0a, 0x06,
+    0x08, 0xe7, 0x07, 0x01, 0x02, 0x00, 0x12, 0x03, 0x15, 0x07, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x08,
+    0xe7, 0x07, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x07, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x08,
+    0xe7, 0x07, 0x01, 0x07, 0x12, 0x03, 0x15, 0x1e, 0x26, 0x0a, 0xbd, 0x01, 0x0a, 0x02, 0x04, 0x00,
+    0x12, 0x05, 0x1f, 0x00, 0x97, 0x01, 0x01, 0x1a, 0xaf, 0x01, 0x2a, 0x0a, 0x20, 0x53, 0x63, 0x68,
+    0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x41, 0x50, 0x49,
+    0x2e, 0x0a, 0x0a, 0x20, 0x41, 0x6e, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20,
+    0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x64, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x20, 0x22, 0x75, 0x6e,
+    0x69, 0x6f, 0x6e, 0x22, 0x0a, 0x20, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x2c, 0x20, 0x73, 0x65, 0x65,
+    0x3a, 0x0a, 0x20, 0x68, 0x74, 0x74, 0x70, 0x73, 0x3a, 0x2f, 0x2f, 0x64, 0x65, 0x76, 0x65, 0x6c,
+    0x6f, 0x70, 0x65, 0x72, 0x73, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x63, 0x6f, 0x6d,
+    0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x2d, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72,
+    0x73, 0x2f, 0x64, 0x6f, 0x63, 0x73, 0x2f, 0x74, 0x65, 0x63, 0x68, 0x6e, 0x69, 0x71, 0x75, 0x65,
+    0x73, 0x23, 0x75, 0x6e, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01,
+    0x12, 0x03, 0x1f, 0x08, 0x0d, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x00, 0x04, 0x00, 0x12, 0x04, 0x22,
+    0x02, 0x39, 0x03, 0x1a, 0x47, 0x20, 0x50, 0x6f, 0x73, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x65,
+    0x76, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2c, 0x20, 0x66, 0x6f, 0x6c, 0x6c,
+    0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20,
+    0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x66, 0x0a, 0x20,
+    0x61, 0x70, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x07, 0x0b, 0x0a, 0xdc, 0x01, 0x0a, 0x06, 0x04,
+    0x00, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x27, 0x04, 0x10, 0x1a, 0xcc, 0x01, 0x20, 0x54, 0x68,
+    0x69, 0x73, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66,
+    0x69, 0x72, 0x73, 0x74, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x2c, 0x20, 0x74, 0x6f,
+    0x0a, 0x20, 0x65, 0x6e, 0x73, 0x75, 0x72, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x66,
+    0x20, 0x27, 0x74, 0x79, 0x70, 0x65, 0x27, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x73,
+    0x65, 0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x20,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57,
+    0x4e, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x20,
+    0x65, 0x6e, 0x75, 0x6d, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x62,
+    0x65, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x0a, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x62, 0x61,
+    0x63, 0x6b, 0x77, 0x61, 0x72, 0x64, 0x73, 0x2d, 0x63, 0x6f, 0x6d, 0x70, 0x61, 0x74, 0x69, 0x62,
+    0x6c, 0x65, 0x20, 0x77, 0x61, 0x79, 0x2e, 0x20, 0x53, 0x65, 0x65, 0x3a, 0x20, 0x4d, 0x45, 0x53,
+    0x4f, 0x53, 0x2d, 0x34, 0x39, 0x39, 0x37, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x27, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x27, 0x0e, 0x0f, 0x0a, 0x28, 0x0a, 0x06, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x29, 0x04, 0x13, 0x22, 0x19, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27,
+    0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x64, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f,
+    0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x29, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03,
+    0x29, 0x11, 0x12, 0x0a, 0x24, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x2a,
+    0x04, 0x0f, 0x22, 0x15, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x73,
+    0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x2a, 0x0d, 0x0e, 0x0a, 0x25, 0x0a, 0x06, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x03, 0x12, 0x03, 0x2b, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27,
+    0x52, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2b, 0x04, 0x0b,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x2b, 0x0e, 0x0f,
+    0x0a, 0x24, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x2c, 0x04, 0x0f, 0x22,
+    0x15, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x27, 0x20, 0x62,
+    0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04,
+    0x01, 0x12, 0x03, 0x2c, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x04,
+    0x02, 0x12, 0x03, 0x2c, 0x0d, 0x0e, 0x0a, 0x25, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05,
+    0x12, 0x03, 0x2d, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x4d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2d, 0x04, 0x0b, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x2d, 0x0e, 0x0f, 0x0a, 0x25, 0x0a,
+    0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x2e, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53,
+    0x65, 0x65, 0x20, 0x27, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c,
+    0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x2e, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12,
+    0x03, 0x2e, 0x0e, 0x0f, 0x0a, 0x23, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03,
+    0x2f, 0x04, 0x0e, 0x22, 0x14, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x45, 0x72, 0x72, 0x6f, 0x72,
+    0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2f, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x2f, 0x0c, 0x0d, 0x0a, 0xa0, 0x03, 0x0a, 0x06, 0x04, 0x00,
+    0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x38, 0x04, 0x12, 0x1a, 0x90, 0x03, 0x20, 0x50, 0x65, 0x72,
+    0x69, 0x6f, 0x64, 0x69, 0x63, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x73, 0x65,
+    0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x20,
+    0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x74, 0x6f, 0x0a, 0x20, 0x27, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x64,
+    0x2e, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x76, 0x61, 0x6c, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x27, 0x2e, 0x20, 0x49, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x64,
+    0x6f, 0x65, 0x73, 0x0a, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65,
+    0x20, 0x61, 0x6e, 0x79, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x28, 0x69, 0x6e, 0x63,
+    0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74,
+    0x73, 0x29, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x64,
+    0x65, 0x64, 0x0a, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x69,
+    0x6d, 0x65, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x35, 0x20, 0x78, 0x20, 0x68, 0x65,
+    0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x76, 0x61, 0x6c,
+    0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x29, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65,
+    0x20, 0x69, 0x73, 0x0a, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x6c, 0x79, 0x20, 0x61, 0x20, 0x6e, 0x65,
+    0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
+    0x20, 0x49, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x68, 0x20, 0x61, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x73, 0x68,
+    0x6f, 0x75, 0x6c, 0x64, 0x0a, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x65, 0x78, 0x69, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69,
+    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x73, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65,
+    0x0a, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x6f, 0x66,
+    0x66, 0x20, 0x73, 0x74, 0x72, 0x61, 0x74, 0x65, 0x67, 0x79, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x38, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x03, 0x38, 0x10, 0x11, 0x0a, 0x43, 0x0a, 0x04,
+    0x04, 0x00, 0x03, 0x00, 0x12, 0x04, 0x3c, 0x02, 0x42, 0x03, 0x1a, 0x35, 0x20, 0x46, 0x69, 0x72,
+    0x73, 0x74, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65,
+    0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64,
+    0x75, 0x6c, 0x65, 0x72, 0x20, 0x73, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x00, 0x01, 0x12, 0x03, 0x3c, 0x0a, 0x14, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x3d, 0x04, 0x2a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3d, 0x04, 0x0c, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3d, 0x0d, 0x18, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3d, 0x19, 0x25, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3d, 0x28, 0x29, 0x0a, 0x84,
+    0x01, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x41, 0x04, 0x33, 0x1a, 0x75,
+    0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c,
+    0x20, 0x62, 0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d,
+    0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x73, 0x2e, 0x20, 0x53, 0x65, 0x65,
+    0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x62,
+    0x6f, 0x76, 0x65, 0x20, 0x6f, 0x6e, 0x20, 0x27, 0x48, 0x45, 0x41, 0x52, 0x54, 0x42, 0x45, 0x41,
+    0x54, 0x27, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x64, 0x65, 0x74, 0x61,
+    0x69, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x41, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x41, 0x0d, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x41, 0x14, 0x2e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x41, 0x31, 0x32, 0x0a, 0xc1, 0x03, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x01, 0x12, 0x04,
+    0x4b, 0x02, 0x4e, 0x03, 0x1a, 0xb2, 0x03, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20,
+    0x61, 0x72, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
+    0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72,
+    0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64,
+    0x75, 0x6c, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
+    0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x62, 0x61, 0x63, 0x6b,
+    0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
+    0x6c, 0x65, 0x72, 0x2e, 0x20, 0x45, 0x61, 0x63, 0x68, 0x0a, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72,
+    0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20,
+    0x61, 0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63,
+    0x65, 0x73, 0x20, 0x6f, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x2e, 0x20, 0x55,
+    0x6e, 0x74, 0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
+    0x6c, 0x65, 0x72, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x73, 0x20, 0x6f, 0x72, 0x20, 0x64,
+    0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x61,
+    0x72, 0x65, 0x0a, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x69, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x61,
+    0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70,
+    0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x72, 0x20, 0x44, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x69, 0x6e,
+    0x67, 0x20, 0x61, 0x6e, 0x0a, 0x20, 0x69, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x65, 0x20, 0x6f, 0x66,
+    0x66, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x27, 0x73, 0x20, 0x61, 0x62, 0x69,
+    0x6c, 0x69, 0x74, 0x79, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x76, 0x69, 0x6f, 0x6c, 0x61, 0x74, 0x69, 0x6e, 0x67,
+    0x20, 0x61, 0x6e, 0x20, 0x53, 0x4c, 0x41, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03,
+    0x01, 0x01, 0x12, 0x03, 0x4b, 0x0a, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x01, 0x02,
+    0x00, 0x12, 0x03, 0x4c, 0x04, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x4c, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x00,
+    0x06, 0x12, 0x03, 0x4c, 0x0d, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x4c, 0x13, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x4c, 0x1c, 0x1d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x01, 0x02, 0x01,
+    0x12, 0x03, 0x4d, 0x04, 0x2d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x4d, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x4d, 0x0d, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x4d, 0x1a, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x01, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x4d, 0x2b, 0x2c, 0x0a, 0x8a, 0x02, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x02, 0x12, 0x04,
+    0x54, 0x02, 0x56, 0x03, 0x1a, 0xfb, 0x01, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x63, 0x75, 0x6c,
+    0x61, 0x72, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x6c,
+    0x6f, 0x6e, 0x67, 0x65, 0x72, 0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x28, 0x65, 0x2e, 0x67,
+    0x2e, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x63, 0x6f,
+    0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62, 0x65, 0x65,
+    0x6e, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x29, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68,
+    0x65, 0x6e, 0x63, 0x65, 0x0a, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x62,
+    0x65, 0x20, 0x72, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x65, 0x64, 0x2e, 0x20, 0x41, 0x6e, 0x79,
+    0x20, 0x66, 0x75, 0x74, 0x75, 0x72, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x28, 0x27,
+    0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x27, 0x20, 0x2f, 0x20, 0x27, 0x44, 0x65, 0x63, 0x6c, 0x69,
+    0x6e, 0x65, 0x27, 0x29, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x0a, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x72, 0x65, 0x67, 0x61,
+    0x72, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72,
+    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x02, 0x01, 0x12, 0x03, 0x54, 0x0a, 0x11,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x12, 0x03, 0x55, 0x04, 0x22, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x55, 0x04, 0x0c, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x55, 0x0d, 0x14, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x55, 0x15, 0x1d, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x55, 0x20, 0x21, 0x0a,
+    0xcf, 0x04, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x03, 0x12, 0x04, 0x61, 0x02, 0x63, 0x03, 0x1a, 0xc0,
+    0x04, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x65,
+    0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x69, 0x73, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20,
+    0x6f, 0x72, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x6d, 0x61, 0x73, 0x74,
+    0x65, 0x72, 0x2e, 0x20, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74,
+    0x65, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65,
+    0x64, 0x20, 0x62, 0x79, 0x0a, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x73, 0x20,
+    0x74, 0x6f, 0x20, 0x72, 0x65, 0x6c, 0x69, 0x61, 0x62, 0x6c, 0x79, 0x20, 0x63, 0x6f, 0x6d, 0x6d,
+    0x75, 0x6e, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x75, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x20,
+    0x74, 0x68, 0x61, 0x74, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x6d, 0x61, 0x6e, 0x61, 0x67,
+    0x65, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x63, 0x72, 0x75, 0x63, 0x69, 0x61, 0x6c,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c,
+    0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x28, 0x73, 0x65, 0x65, 0x20, 0x54, 0x61, 0x73,
+    0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x0a, 0x20, 0x69, 0x6e, 0x20, 0x6d, 0x65, 0x73, 0x6f, 0x73,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x29, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20,
+    0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20,
+    0x61, 0x73, 0x20, 0x73, 0x6f, 0x6f, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x61, 0x73, 0x6b, 0x0a, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x73, 0x2c,
+    0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x4d, 0x65,
+    0x73, 0x6f, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x61, 0x6c, 0x6c,
+    0x6f, 0x63, 0x61, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x61, 0x73, 0x6b, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x69, 0x62, 0x69, 0x6c, 0x69,
+    0x74, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
+    0x6c, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x65, 0x78, 0x70, 0x6c, 0x69, 0x63, 0x69, 0x74,
+    0x6c, 0x79, 0x20, 0x61, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20,
+    0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x53,
+    0x65, 0x65, 0x0a, 0x20, 0x27, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65,
+    0x27, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x27, 0x43, 0x61, 0x6c, 0x6c, 0x27, 0x20,
+    0x73, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x03, 0x01, 0x12, 0x03, 0x61, 0x0a, 0x10, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x12, 0x03, 0x62, 0x04, 0x23, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x62, 0x04, 0x0c, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x62, 0x0d, 0x17, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x62, 0x18, 0x1e, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x62, 0x21, 0x22, 0x0a, 0xb1,
+    0x02, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x04, 0x12, 0x04, 0x6a, 0x02, 0x6e, 0x03, 0x1a, 0xa2, 0x02,
+    0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61,
+    0x20, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20,
+    0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x66, 0x6f,
+    0x72, 0x77, 0x61, 0x72, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d,
+    0x61, 0x73, 0x74, 0x65, 0x72, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x6e, 0x6f, 0x74, 0x0a, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x70, 0x72, 0x65, 0x74, 0x65,
+    0x64, 0x20, 0x62, 0x79, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69,
+    0x73, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x65, 0x64,
+    0x20, 0x28, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x72, 0x65, 0x6c, 0x69, 0x61, 0x62,
+    0x69, 0x6c, 0x69, 0x74, 0x79, 0x0a, 0x20, 0x67, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x65, 0x65,
+    0x73, 0x29, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
+    0x6c, 0x65, 0x72, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x75, 0x70, 0x20, 0x74, 0x6f,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x74, 0x6f,
+    0x20, 0x72, 0x65, 0x74, 0x72, 0x79, 0x0a, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x64, 0x72, 0x6f, 0x70, 0x70, 0x65,
+    0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x04, 0x01, 0x12, 0x03, 0x6a, 0x0a, 0x11,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x12, 0x03, 0x6b, 0x04, 0x22, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6b, 0x04, 0x0c, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x6b, 0x0d, 0x14, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6b, 0x15, 0x1d, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6b, 0x20, 0x21, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x6c, 0x04, 0x28, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6c, 0x04, 0x0c, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6c, 0x0d, 0x17, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6c, 0x18, 0x23, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6c, 0x26, 0x27, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x12, 0x03, 0x6d, 0x04, 0x1c, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6d, 0x04, 0x0c, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6d, 0x0d, 0x12, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x13, 0x17, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6d, 0x1a, 0x1b, 0x0a, 0xdf, 0x04,
+    0x0a, 0x04, 0x04, 0x00, 0x03, 0x05, 0x12, 0x05, 0x7a, 0x02, 0x82, 0x01, 0x03, 0x1a, 0xcf, 0x04,
+    0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61,
+    0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65,
+    0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x75, 0x73, 0x74,
+    0x65, 0x72, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64,
+    0x0a, 0x20, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x29,
+    0x20, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x78, 0x65, 0x63,
+    0x75, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74,
+    0x65, 0x64, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x2c, 0x20, 0x74,
+    0x68, 0x69, 0x73, 0x0a, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x63, 0x6f, 0x69, 0x6e, 0x63,
+    0x69, 0x64, 0x65, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70,
+    0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x55, 0x50,
+    0x44, 0x41, 0x54, 0x45, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x61, 0x6e, 0x79, 0x0a, 0x20, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b,
+    0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x65, 0x63,
+    0x75, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74,
+    0x20, 0x6f, 0x66, 0x0a, 0x20, 0x27, 0x52, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x27, 0x20, 0x65,
+    0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x75,
+    0x74, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x73,
+    0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74,
+    0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x20,
+    0x67, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x65, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x27, 0x46,
+    0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x27, 0x2c, 0x20, 0x27, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65,
+    0x27, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x27, 0x52, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x27, 0x20,
+    0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x6c,
+    0x61, 0x76, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x0a,
+    0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x20, 0x54, 0x4f,
+    0x44, 0x4f, 0x28, 0x76, 0x69, 0x6e, 0x6f, 0x64, 0x29, 0x3a, 0x20, 0x43, 0x6f, 0x6e, 0x73, 0x69,
+    0x64, 0x65, 0x72, 0x20, 0x73, 0x70, 0x6c, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6c, 0x6f, 0x73, 0x74, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x65, 0x78, 0x65,
+    0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x73, 0x65, 0x70, 0x61, 0x72,
+    0x61, 0x74, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x65,
+    0x6e, 0x73, 0x75, 0x72, 0x65, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x72, 0x65, 0x6c, 0x69, 0x61,
+    0x62, 0x6c, 0x79, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x05, 0x01, 0x12, 0x03, 0x7a, 0x0a, 0x11, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x12, 0x03, 0x7b, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7b, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x7b, 0x0d, 0x14, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7b, 0x15, 0x1d, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7b, 0x20, 0x21, 0x0a, 0xae, 0x01, 0x0a,
+    0x06, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x12, 0x04, 0x80, 0x01, 0x04, 0x28, 0x1a, 0x9d, 0x01,
+    0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x61, 0x73, 0x20, 0x6a, 0x75, 0x73,
+    0x74, 0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x6e, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x6f, 0x6e, 0x20, 0x61, 0x20,
+    0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x0a, 0x20, 0x27, 0x65, 0x78, 0x65,
+    0x63, 0x75, 0x74, 0x6f, 0x72, 0x5f, 0x69, 0x64, 0x27, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62,
+    0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x70, 0x6f, 0x73, 0x73, 0x69, 0x62,
+    0x6c, 0x79, 0x20, 0x27, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x27, 0x20, 0x28, 0x69, 0x66, 0x20,
+    0x77, 0x65, 0x20, 0x77, 0x65, 0x72, 0x65, 0x0a, 0x20, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f,
+    0x20, 0x64, 0x65, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
+    0x78, 0x69, 0x74, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x29, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x04, 0x12, 0x04, 0x80, 0x01, 0x04, 0x0c, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x06, 0x12, 0x04, 0x80, 0x01, 0x0d, 0x17, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x01, 0x12, 0x04, 0x80, 0x01, 0x18, 0x23,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x03, 0x12, 0x04, 0x80, 0x01, 0x26,
+    0x27, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x12, 0x04, 0x81, 0x01, 0x04,
+    0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x04, 0x12, 0x04, 0x81, 0x01,
+    0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x05, 0x12, 0x04, 0x81,
+    0x01, 0x0d, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0x81, 0x01, 0x13, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0x81, 0x01, 0x1c, 0x1d, 0x0a, 0xd0, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x06, 0x12, 0x06,
+    0x87, 0x01, 0x02, 0x89, 0x01, 0x03, 0x1a, 0xbf, 0x01, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76,
+    0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x61, 0x6e, 0x20, 0x75, 0x6e, 0x72, 0x65, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x61, 0x62, 0x6c,
+    0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x0a,
+    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65,
+    0x64, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x2c, 0x20, 0x72, 0x61, 0x74, 0x65, 0x20, 0x6c, 0x69, 0x6d,
+    0x69, 0x74, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x61, 0x75, 0x74, 0x68, 0x6f, 0x72, 0x69, 0x7a, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x73, 0x20, 0x65, 0x74, 0x63, 0x2e,
+    0x29, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65,
+    0x72, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x61, 0x62, 0x6f, 0x72, 0x74, 0x20, 0x6f,
+    0x6e, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x06,
+    0x01, 0x12, 0x04, 0x87, 0x01, 0x0a, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x06, 0x02,
+    0x00, 0x12, 0x04, 0x88, 0x01, 0x04, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x06, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x06,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0d, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03,
+    0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x14, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00,
+    0x03, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x01, 0x1e, 0x1f, 0x0a, 0xbb, 0x01, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x19, 0x1a, 0xac, 0x01, 0x20, 0x54, 0x79,
+    0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2c,
+    0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20,
+    0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x0a,
+    0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x73, 0x74,
+    0x65, 0x64, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x20, 0x45, 0x6e, 0x75, 0x6d, 0x20, 0x66, 0x69, 0x65,
+    0x6c, 0x64, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x20, 0x6f, 0x70,
+    0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x3a, 0x20, 0x4d, 0x45, 0x53,
+    0x4f, 0x53, 0x2d, 0x34, 0x39, 0x39, 0x37, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0x8e, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x8e, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x8e, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x90,
+    0x01, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x04, 0x90, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x04, 0x90, 0x01, 0x0b,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x90, 0x01, 0x16, 0x20,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0x90, 0x01, 0x23, 0x24, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x91, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x91, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x03, 0x12, 0x04, 0x92, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04,
+    0x12, 0x04, 0x92, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12,
+    0x04, 0x92, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0x92, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x04, 0x92,
+    0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0x93, 0x01, 0x02,
+    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x04, 0x93, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12, 0x04, 0x93, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x93, 0x01, 0x12, 0x18, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x04, 0x93, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0x94, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x04, 0x12, 0x04, 0x94, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x05, 0x06, 0x12, 0x04, 0x94, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x05, 0x01, 0x12, 0x04, 0x94, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05,
+    0x03, 0x12, 0x04, 0x94, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12,
+    0x04, 0x95, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x04,
+    0x95, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x04, 0x95,
+    0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0x95, 0x01,
+    0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x04, 0x95, 0x01, 0x1d,
+    0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04, 0x96, 0x01, 0x02, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x04, 0x96, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x04, 0x96, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0x96, 0x01, 0x11, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x04, 0x96, 0x01, 0x19, 0x1a, 0x0a, 0x86, 0x01, 0x0a, 0x02,
+    0x04, 0x01, 0x12, 0x06, 0xa0, 0x01, 0x00, 0xd0, 0x02, 0x01, 0x1a, 0x78, 0x2a, 0x0a, 0x20, 0x53,
+    0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x41, 0x50,
+    0x49, 0x2e, 0x0a, 0x0a, 0x20, 0x4c, 0x69, 0x6b, 0x65, 0x20, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2c,
+    0x20, 0x61, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x64, 0x65, 0x73, 0x63, 0x72,
+    0x69, 0x62, 0x65, 0x64, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c,
+    0x20, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x0a, 0x20, 0x22, 0x75, 0x6e, 0x69, 0x6f, 0x6e, 0x22,
+    0x20, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x20, 0x28, 0x73, 0x65, 0x65, 0x20, 0x61, 0x62, 0x6f, 0x76,
+    0x65, 0x29, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x08,
+    0x0c, 0x0a, 0x56, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x06, 0xa3, 0x01, 0x02, 0xbb, 0x01,
+    0x03, 0x1a, 0x46, 0x20, 0x50, 0x6f, 0x73, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2c, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65,
+    0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x64, 0x65, 0x66,
+    0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x66, 0x0a, 0x20, 0x61, 0x70, 0x70,
+    0x6c, 0x69, 0x63, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x04,
+    0x00, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x07, 0x0b, 0x0a, 0x5a, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x04, 0xa5, 0x01, 0x04, 0x10, 0x1a, 0x4a, 0x20, 0x53, 0x65, 0x65, 0x20, 0x63,
+    0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x62, 0x6f, 0x76, 0x65, 0x20, 0x6f, 0x6e,
+    0x20, 0x60, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x3a, 0x3a, 0x54, 0x79, 0x70, 0x65, 0x60, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x20,
+    0x6f, 0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x76, 0x61, 0x6c,
+    0x75, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xa5, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x04, 0xa5, 0x01, 0x0e, 0x0f, 0x0a, 0x28, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x04, 0xa7, 0x01, 0x04, 0x12, 0x22, 0x18, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x53, 0x75,
+    0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x04,
+    0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xa7, 0x01,
+    0x10, 0x11, 0x0a, 0x47, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xa8, 0x01,
+    0x04, 0x11, 0x22, 0x37, 0x20, 0x53, 0x68, 0x75, 0x74, 0x73, 0x20, 0x64, 0x6f, 0x77, 0x6e, 0x20,
+    0x61, 0x6c, 0x6c, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x2f, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74,
+    0x6f, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x73, 0x20,
+    0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xa8, 0x01, 0x0f, 0x10, 0x0a, 0x25, 0x0a,
+    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x0f, 0x22, 0x15, 0x20,
+    0x53, 0x65, 0x65, 0x20, 0x27, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x27, 0x20, 0x62, 0x65, 0x6c,
+    0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xa9, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x02,
+    0x12, 0x04, 0xa9, 0x01, 0x0d, 0x0e, 0x0a, 0x26, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04,
+    0x12, 0x04, 0xaa, 0x01, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x44, 0x65,
+    0x63, 0x6c, 0x69, 0x6e, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x0b, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xaa, 0x01, 0x0e, 0x0f,
+    0x0a, 0x49, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0xab, 0x01, 0x04, 0x0f,
+    0x22, 0x39, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x70,
+    0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x73, 0x20,
+    0x73, 0x65, 0x74, 0x20, 0x76, 0x69, 0x61, 0x20, 0x41, 0x43, 0x43, 0x45, 0x50, 0x54, 0x20, 0x6f,
+    0x72, 0x20, 0x44, 0x45, 0x43, 0x4c, 0x49, 0x4e, 0x45, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xab, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xab, 0x01, 0x0d, 0x0e, 0x0a, 0x23, 0x0a,
+    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x06, 0x12, 0x04, 0xac, 0x01, 0x04, 0x0d, 0x22, 0x13, 0x20,
+    0x53, 0x65, 0x65, 0x20, 0x27, 0x4b, 0x69, 0x6c, 0x6c, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77,
+    0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0xac,
+    0x01, 0x04, 0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x04,
+    0xac, 0x01, 0x0b, 0x0c, 0x0a, 0x27, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04,
+    0xad, 0x01, 0x04, 0x11, 0x22, 0x17, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x53, 0x68, 0x75, 0x74,
+    0x64, 0x6f, 0x77, 0x6e, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0xad, 0x01, 0x04, 0x0c, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0xad, 0x01, 0x0f, 0x10, 0x0a,
+    0x2a, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x08, 0x12, 0x04, 0xae, 0x01, 0x04, 0x14, 0x22,
+    0x1a, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64,
+    0x67, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0xae, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0xae, 0x01, 0x12, 0x13, 0x0a, 0x28, 0x0a,
+    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x09, 0x12, 0x04, 0xaf, 0x01, 0x04, 0x12, 0x22, 0x18, 0x20,
+    0x53, 0x65, 0x65, 0x20, 0x27, 0x52, 0x65, 0x63, 0x6f, 0x6e, 0x63, 0x69, 0x6c, 0x65, 0x27, 0x20,
+    0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x09, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
+    0x02, 0x09, 0x02, 0x12, 0x04, 0xaf, 0x01, 0x10, 0x11, 0x0a, 0x26, 0x0a, 0x06, 0x04, 0x01, 0x04,
+    0x00, 0x02, 0x0a, 0x12, 0x04, 0xb0, 0x01, 0x04, 0x11, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20,
     0x27, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e,
-    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2d, 0x04,
-    0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x2d, 0x0e,
-    0x0f, 0x0a, 0x25, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x2e, 0x04, 0x10,
-    0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x27,
-    0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00,
-    0x02, 0x06, 0x01, 0x12, 0x03, 0x2e, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00,
-    0x02, 0x06, 0x02, 0x12, 0x03, 0x2e, 0x0e, 0x0f, 0x0a, 0x23, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00,
-    0x02, 0x07, 0x12, 0x03, 0x2f, 0x04, 0x0e, 0x22, 0x14, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x45,
-    0x72, 0x72, 0x6f, 0x72, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2f, 0x04, 0x09, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x2f, 0x0c, 0x0d, 0x0a, 0xa0, 0x03,
-    0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x38, 0x04, 0x12, 0x1a, 0x90, 0x03,
-    0x20, 0x50, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x69, 0x63, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
-    0x65, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4d, 0x65,
-    0x73, 0x6f, 0x73, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x72,
-    0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x27, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72,
-    0x69, 0x62, 0x65, 0x64, 0x2e, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x5f, 0x69,
-    0x6e, 0x74, 0x65, 0x72, 0x76, 0x61, 0x6c, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x27,
-    0x2e, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c,
-    0x65, 0x72, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x0a, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x72, 0x65, 0x63,
-    0x65, 0x69, 0x76, 0x65, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20,
-    0x28, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x68, 0x65, 0x61, 0x72, 0x74,
-    0x62, 0x65, 0x61, 0x74, 0x73, 0x29, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x78,
-    0x74, 0x65, 0x6e, 0x64, 0x65, 0x64, 0x0a, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x20, 0x6f,
-    0x66, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x35, 0x20,
-    0x78, 0x20, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x74, 0x65,
-    0x72, 0x76, 0x61, 0x6c, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x29, 0x2c, 0x20, 0x74,
-    0x68, 0x65, 0x72, 0x65, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x6c, 0x79, 0x20,
-    0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x74,
-    0x69, 0x6f, 0x6e, 0x2e, 0x20, 0x49, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x68, 0x20, 0x61, 0x20, 0x63,
-    0x61, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65,
-    0x72, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x0a, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x75, 0x62,
-    0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63,
-    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x73, 0x75, 0x62, 0x73, 0x63,
-    0x72, 0x69, 0x62, 0x65, 0x0a, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x62, 0x61,
-    0x63, 0x6b, 0x6f, 0x66, 0x66, 0x20, 0x73, 0x74, 0x72, 0x61, 0x74, 0x65, 0x67, 0x79, 0x2e, 0x0a,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x38, 0x04, 0x0d,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x03, 0x38, 0x10, 0x11,
-    0x0a, 0x43, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x00, 0x12, 0x04, 0x3c, 0x02, 0x42, 0x03, 0x1a, 0x35,
-    0x20, 0x46, 0x69, 0x72, 0x73, 0x74, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x65, 0x63,
-    0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
-    0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x73, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69,
-    0x62, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x00, 0x01, 0x12, 0x03,
-    0x3c, 0x0a, 0x14, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x3d,
-    0x04, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3d,
-    0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3d,
-    0x0d, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3d,
-    0x19, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3d,
-    0x28, 0x29, 0x0a, 0x84, 0x01, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x00, 0x02, 0x01, 0x12, 0x03, 0x41,
-    0x04, 0x33, 0x1a, 0x75, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20,
-    0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x6e,
-    0x64, 0x69, 0x6e, 0x67, 0x20, 0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x73, 0x2e,
-    0x20, 0x53, 0x65, 0x65, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e,
-    0x74, 0x20, 0x61, 0x62, 0x6f, 0x76, 0x65, 0x20, 0x6f, 0x6e, 0x20, 0x27, 0x48, 0x45, 0x41, 0x52,
-    0x54, 0x42, 0x45, 0x41, 0x54, 0x27, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20,
-    0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x41, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x41, 0x0d, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x14, 0x2e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x31, 0x32, 0x0a, 0xc1, 0x03, 0x0a, 0x04, 0x04, 0x00,
-    0x03, 0x01, 0x12, 0x04, 0x4b, 0x02, 0x4e, 0x03, 0x1a, 0xb2, 0x03, 0x20, 0x52, 0x65, 0x63, 0x65,
-    0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68,
-    0x65, 0x72, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x72, 0x65, 0x73, 0x6f,
-    0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6f,
-    0x66, 0x66, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73,
-    0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x73, 0x6f,
-    0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20,
-    0x62, 0x61, 0x63, 0x6b, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63,
-    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x20, 0x45, 0x61, 0x63, 0x68, 0x0a, 0x20, 0x6f,
-    0x66, 0x66, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x73,
-    0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73,
-    0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x6f, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x6c, 0x61, 0x76,
-    0x65, 0x2e, 0x20, 0x55, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x63,
-    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x73, 0x20,
-    0x6f, 0x72, 0x20, 0x64, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f,
-    0x66, 0x66, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63,
-    0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x0a, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x69, 0x64, 0x65, 0x72,
-    0x65, 0x64, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x20, 0x41,
-    0x63, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x72, 0x20, 0x44, 0x65, 0x63, 0x6c,
-    0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x0a, 0x20, 0x69, 0x6e, 0x76, 0x65, 0x72, 0x73,
-    0x65, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x73, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6f, 0x66,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x27, 0x73,
-    0x20, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x72, 0x65, 0x6c,
-    0x65, 0x61, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63,
-    0x65, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x76, 0x69, 0x6f, 0x6c, 0x61,
-    0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x20, 0x53, 0x4c, 0x41, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x03, 0x01, 0x01, 0x12, 0x03, 0x4b, 0x0a, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x00, 0x03, 0x01, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x04, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00,
-    0x03, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4c, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00,
-    0x03, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4c, 0x0d, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00,
-    0x03, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x13, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00,
-    0x03, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4c, 0x1c, 0x1d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00,
-    0x03, 0x01, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x04, 0x2d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4d, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4d, 0x0d, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4d, 0x1a, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03,
-    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4d, 0x2b, 0x2c, 0x0a, 0x8a, 0x02, 0x0a, 0x04, 0x04, 0x00,
-    0x03, 0x02, 0x12, 0x04, 0x54, 0x02, 0x56, 0x03, 0x1a, 0xfb, 0x01, 0x20, 0x52, 0x65, 0x63, 0x65,
-    0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x61, 0x72, 0x74,
-    0x69, 0x63, 0x75, 0x6c, 0x61, 0x72, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20,
-    0x6e, 0x6f, 0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x65, 0x72, 0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20,
-    0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x6c, 0x61, 0x76,
-    0x65, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20,
-    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73,
-    0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x29, 0x20, 0x61,
-    0x6e, 0x64, 0x20, 0x68, 0x65, 0x6e, 0x63, 0x65, 0x0a, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x73, 0x20,
-    0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x72, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x65, 0x64, 0x2e,
-    0x20, 0x41, 0x6e, 0x79, 0x20, 0x66, 0x75, 0x74, 0x75, 0x72, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c,
-    0x73, 0x20, 0x28, 0x27, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x27, 0x20, 0x2f, 0x20, 0x27, 0x44,
-    0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x27, 0x29, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x0a, 0x20, 0x62,
+    0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xb0, 0x01,
+    0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0a, 0x02, 0x12, 0x04, 0xb0,
+    0x01, 0x0e, 0x10, 0x0a, 0x26, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x04, 0xb1,
+    0x01, 0x04, 0x11, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x04, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x04, 0xb1, 0x01, 0x0e, 0x10, 0x0a, 0x48, 0x0a,
+    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0c, 0x12, 0x04, 0xb2, 0x01, 0x04, 0x12, 0x22, 0x38, 0x20,
+    0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f,
+    0x20, 0x73, 0x74, 0x6f, 0x70, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66,
+    0x66, 0x65, 0x72, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x72, 0x61, 0x6d,
+    0x65, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x0c, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
+    0x02, 0x0c, 0x02, 0x12, 0x04, 0xb2, 0x01, 0x0f, 0x11, 0x0a, 0xa2, 0x01, 0x0a, 0x04, 0x04, 0x01,
+    0x03, 0x00, 0x12, 0x06, 0xc0, 0x01, 0x02, 0xcd, 0x01, 0x03, 0x1a, 0x91, 0x01, 0x20, 0x53, 0x75,
+    0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68,
+    0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
+    0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20, 0x41, 0x0a, 0x20, 0x73, 0x63, 0x68,
+    0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x73, 0x65, 0x6e, 0x64,
+    0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x6f, 0x6e, 0x6c,
+    0x79, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x69, 0x74, 0x20, 0x68, 0x61, 0x73, 0x20, 0x72,
+    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x53, 0x55, 0x42,
+    0x43, 0x52, 0x49, 0x42, 0x45, 0x44, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x01, 0x03, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x0a, 0x13, 0x0a, 0x65, 0x0a,
+    0x06, 0x04, 0x01, 0x03, 0x00, 0x02, 0x00, 0x12, 0x04, 0xc3, 0x01, 0x04, 0x2e, 0x1a, 0x55, 0x20,
+    0x53, 0x65, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x73,
+    0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x6f, 0x6e, 0x20, 0x27, 0x66, 0x72, 0x61, 0x6d, 0x65,
+    0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x27, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x0a, 0x20, 0x27,
+    0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x2e, 0x69,
+    0x64, 0x27, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0xc3, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xc3, 0x01, 0x0d, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xc3, 0x01, 0x1b, 0x29, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x2c, 0x2d, 0x0a, 0xdf, 0x03, 0x0a, 0x06, 0x04, 0x01, 0x03,
+    0x00, 0x02, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x04, 0x1c, 0x1a, 0xce, 0x03, 0x20, 0x4e, 0x4f, 0x54,
+    0x45, 0x3a, 0x20, 0x27, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x27, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x20,
+    0x69, 0x6e, 0x20, 0x76, 0x31, 0x2f, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74,
+    0x20, 0x69, 0x73, 0x0a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x62,
     0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20,
-    0x72, 0x65, 0x67, 0x61, 0x72, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6f,
-    0x66, 0x66, 0x65, 0x72, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x69, 0x6e, 0x76,
-    0x61, 0x6c, 0x69, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x02, 0x01, 0x12,
-    0x03, 0x54, 0x0a, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x12, 0x03,
-    0x55, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x55, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x55, 0x0d, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x55, 0x15, 0x1d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x55, 0x20, 0x21, 0x0a, 0xcf, 0x04, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x03, 0x12, 0x04, 0x61, 0x02,
-    0x63, 0x03, 0x1a, 0xc0, 0x04, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77,
-    0x68, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x69, 0x73,
-    0x20, 0x61, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65,
-    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-    0x65, 0x64, 0x20, 0x62, 0x79, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75,
-    0x74, 0x6f, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x6f, 0x72, 0x20,
-    0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x2e, 0x20, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75,
-    0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65,
-    0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x62, 0x79, 0x0a, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74,
-    0x6f, 0x72, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x6c, 0x69, 0x61, 0x62, 0x6c, 0x79, 0x20,
-    0x63, 0x6f, 0x6d, 0x6d, 0x75, 0x6e, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61,
-    0x73, 0x6b, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x6d,
-    0x61, 0x6e, 0x61, 0x67, 0x65, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x63, 0x72, 0x75,
-    0x63, 0x69, 0x61, 0x6c, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x74, 0x65, 0x72, 0x6d,
-    0x69, 0x6e, 0x61, 0x6c, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x28, 0x73, 0x65, 0x65,
-    0x20, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x65, 0x0a, 0x20, 0x69, 0x6e, 0x20, 0x6d,
-    0x65, 0x73, 0x6f, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x29, 0x20, 0x69, 0x73, 0x20, 0x73,
-    0x65, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75,
-    0x74, 0x6f, 0x72, 0x20, 0x61, 0x73, 0x20, 0x73, 0x6f, 0x6f, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x0a, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61,
-    0x74, 0x65, 0x73, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x61,
-    0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73,
-    0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61,
-    0x6c, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x69,
-    0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63,
-    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x65, 0x78, 0x70, 0x6c,
+    0x64, 0x72, 0x69, 0x76, 0x65, 0x72, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x64, 0x72, 0x69, 0x76,
+    0x65, 0x72, 0x20, 0x73, 0x65, 0x74, 0x73, 0x20, 0x69, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x72,
+    0x75, 0x65, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x63, 0x68,
+    0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x72, 0x65, 0x2d, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x65, 0x72, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73,
+    0x74, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x61, 0x20, 0x66,
+    0x61, 0x69, 0x6c, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x20, 0x4f, 0x6e, 0x63, 0x65, 0x0a, 0x20, 0x72,
+    0x65, 0x2d, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x61, 0x6c, 0x6c,
+    0x20, 0x73, 0x75, 0x62, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x65, 0x2d, 0x72,
+    0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x74, 0x74, 0x65,
+    0x6d, 0x70, 0x74, 0x73, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x64, 0x75, 0x65, 0x20,
+    0x74, 0x6f, 0x20, 0x5a, 0x4b, 0x0a, 0x20, 0x62, 0x6c, 0x69, 0x70, 0x29, 0x20, 0x77, 0x69, 0x6c,
+    0x6c, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x27, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x27, 0x20, 0x73,
+    0x65, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x2e, 0x20, 0x54, 0x68, 0x69,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x61, 0x6e, 0x74, 0x20, 0x62,
+    0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x0a, 0x20, 0x75,
+    0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x6e,
+    0x65, 0x65, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x46, 0x72, 0x61,
+    0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64,
+    0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x0a, 0x20, 0x76, 0x73, 0x20, 0x46, 0x72, 0x61, 0x6d,
+    0x65, 0x77, 0x6f, 0x72, 0x6b, 0x52, 0x65, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65,
+    0x64, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
+    0x03, 0x00, 0x02, 0x01, 0x04, 0x12, 0x04, 0xcc, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x0d, 0x11, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x12, 0x17, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x1a, 0x1b, 0x0a, 0x8c,
+    0x06, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x01, 0x12, 0x06, 0xe5, 0x01, 0x02, 0xe9, 0x01, 0x03, 0x1a,
+    0xfb, 0x05, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x66,
+    0x66, 0x65, 0x72, 0x2c, 0x20, 0x70, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x6f, 0x70,
+    0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x73,
+    0x65, 0x71, 0x75, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x6d, 0x61, 0x6e, 0x6e, 0x65, 0x72,
+    0x2e, 0x0a, 0x0a, 0x20, 0x45, 0x2e, 0x67, 0x2e, 0x20, 0x4c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x20,
+    0x61, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x6e, 0x65,
+    0x77, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64, 0x20, 0x70, 0x65, 0x72,
+    0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x3a, 0x0a,
+    0x0a, 0x20, 0x20, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x20, 0x7b, 0x0a, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x3a, 0x20, 0x5b, 0x20, 0x2e,
+    0x2e, 0x2e, 0x20, 0x5d, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x20, 0x5b, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x7b,
+    0x20, 0x74, 0x79, 0x70, 0x65, 0x3a, 0x20, 0x52, 0x45, 0x53, 0x45, 0x52, 0x56, 0x45, 0x2c, 0x0a,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x72, 0x65, 0x73, 0x65, 0x72, 0x76, 0x65,
+    0x3a, 0x20, 0x7b, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x3a, 0x20, 0x5b,
+    0x20, 0x64, 0x69, 0x73, 0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a, 0x32, 0x20, 0x5d, 0x20,
+    0x7d, 0x20, 0x7d, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x7b, 0x20, 0x74, 0x79, 0x70,
+    0x65, 0x3a, 0x20, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x2c, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x3a, 0x20, 0x7b, 0x20, 0x76, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x73, 0x3a, 0x20, 0x5b, 0x20, 0x64, 0x69, 0x73, 0x6b, 0x28, 0x72, 0x6f,
+    0x6c, 0x65, 0x29, 0x3a, 0x31, 0x2b, 0x70, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x63,
+    0x65, 0x20, 0x5d, 0x20, 0x7d, 0x20, 0x7d, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x7b,
+    0x20, 0x74, 0x79, 0x70, 0x65, 0x3a, 0x20, 0x4c, 0x41, 0x55, 0x4e, 0x43, 0x48, 0x2c, 0x0a, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x3a, 0x20,
+    0x7b, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x73, 0x20, 0x2e, 0x2e, 0x2e,
+    0x20, 0x64, 0x69, 0x73, 0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a, 0x31, 0x3b, 0x64, 0x69,
+    0x73, 0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a, 0x31, 0x2b, 0x70, 0x65, 0x72, 0x73, 0x69,
+    0x73, 0x74, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x7d, 0x20, 0x7d, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x5d, 0x0a, 0x20, 0x20, 0x20, 0x7d, 0x0a, 0x0a, 0x20, 0x4e, 0x4f, 0x54, 0x45, 0x3a, 0x20, 0x41,
+    0x6e, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0xe2,
+    0x80, 0x99, 0x73, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x6e, 0x6f,
+    0x74, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x41,
+    0x63, 0x63, 0x65, 0x70, 0x74, 0x60, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x0a, 0x20, 0x28, 0x65, 0x2e,
+    0x67, 0x2e, 0x2c, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x20, 0x61, 0x20,
+    0x74, 0x61, 0x73, 0x6b, 0x29, 0x20, 0x61, 0x72, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x69, 0x64,
+    0x65, 0x72, 0x65, 0x64, 0x20, 0x75, 0x6e, 0x75, 0x73, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x6d, 0x69, 0x67, 0x68, 0x74, 0x20, 0x62, 0x65, 0x0a, 0x20, 0x72, 0x65, 0x6f, 0x66, 0x66, 0x65,
+    0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x66, 0x72, 0x61,
+    0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x73, 0x2e, 0x20, 0x49, 0x6e, 0x20, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x20, 0x77, 0x6f, 0x72, 0x64, 0x73, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d,
+    0x65, 0x20, 0x60, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x49, 0x44, 0x60, 0x0a, 0x20, 0x63, 0x61, 0x6e,
+    0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x6d,
+    0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x60, 0x41, 0x63,
+    0x63, 0x65, 0x70, 0x74, 0x60, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x20, 0x4e, 0x4f, 0x54,
+    0x45, 0x3a, 0x20, 0x41, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x73, 0x20, 0x6d, 0x75,
+    0x73, 0x74, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x01, 0x03, 0x01, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x0a, 0x10, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x12, 0x04, 0xe6, 0x01, 0x04, 0x23, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe6, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe6, 0x01, 0x0d, 0x14, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x15, 0x1e, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe6, 0x01, 0x21, 0x22,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x04, 0x2c,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe7, 0x01, 0x04,
+    0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x06, 0x12, 0x04, 0xe7, 0x01,
+    0x0d, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe7,
+    0x01, 0x1d, 0x27, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xe7, 0x01, 0x2a, 0x2b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x01, 0x02, 0x02, 0x12, 0x04,
+    0xe8, 0x01, 0x04, 0x21, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x02, 0x04, 0x12,
+    0x04, 0xe8, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x02, 0x06,
+    0x12, 0x04, 0xe8, 0x01, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xe8, 0x01, 0x15, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0xe8, 0x01, 0x1f, 0x20, 0x0a, 0xf0, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x03,
+    0x02, 0x12, 0x06, 0xef, 0x01, 0x02, 0xf2, 0x01, 0x03, 0x1a, 0xdf, 0x01, 0x20, 0x44, 0x65, 0x63,
+    0x6c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x2c, 0x20,
+    0x73, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61,
+    0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x74, 0x69, 0x61,
+    0x6c, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x0a, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20,
+    0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77,
+    0x6f, 0x72, 0x6b, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x74,
+    0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x0a, 0x20, 0x61, 0x73, 0x20,
+    0x73, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70,
+    0x74, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x6e, 0x6f, 0x20, 0x6f,
+    0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2e, 0x20, 0x53, 0x65, 0x65, 0x20, 0x63,
+    0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x0a, 0x20, 0x74, 0x6f, 0x70, 0x20,
+    0x6f, 0x66, 0x20, 0x27, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x27, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x01, 0x03, 0x02, 0x01, 0x12, 0x04, 0xef, 0x01, 0x0a, 0x11, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01,
+    0x03, 0x02, 0x02, 0x00, 0x12, 0x04, 0xf0, 0x01, 0x04, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
+    0x03, 0x02, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf0, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x03, 0x02, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf0, 0x01, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x15, 0x1e, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf0, 0x01, 0x21, 0x22, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x04, 0x21, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x04, 0x12, 0x04, 0xf1, 0x01, 0x04, 0x0c, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x06, 0x12, 0x04, 0xf1, 0x01, 0x0d, 0x14,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x15,
+    0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf1, 0x01,
+    0x1f, 0x20, 0x0a, 0x9e, 0x03, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x03, 0x12, 0x06, 0xfb, 0x01, 0x02,
+    0xfe, 0x01, 0x03, 0x1a, 0x8d, 0x03, 0x20, 0x4b, 0x69, 0x6c, 0x6c, 0x73, 0x20, 0x61, 0x20, 0x73,
+    0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x2e, 0x20, 0x49, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x68,
+    0x61, 0x73, 0x20, 0x61, 0x20, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x65, 0x78, 0x65, 0x63,
+    0x75, 0x74, 0x6f, 0x72, 0x2c, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6c, 0x6c, 0x20,
+    0x69, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x75, 0x70, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65,
+    0x0a, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x6b, 0x69,
+    0x6c, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x73, 0x65, 0x6e, 0x64, 0x20, 0x61, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4b, 0x49, 0x4c, 0x4c,
+    0x45, 0x44, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x46, 0x41, 0x49, 0x4c,
+    0x45, 0x44, 0x29, 0x0a, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x4e, 0x6f, 0x74,
+    0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x20, 0x72, 0x65, 0x6c,
+    0x65, 0x61, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72,
+    0x63, 0x65, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x6f,
+    0x6e, 0x63, 0x65, 0x20, 0x69, 0x74, 0x0a, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x73,
+    0x20, 0x61, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x75, 0x70, 0x64, 0x61,
+    0x74, 0x65, 0x20, 0x28, 0x53, 0x65, 0x65, 0x20, 0x54, 0x61, 0x73, 0x6b, 0x53, 0x74, 0x61, 0x74,
+    0x65, 0x20, 0x69, 0x6e, 0x20, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x29, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x69, 0x73, 0x20, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77,
+    0x6e, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x2c,
+    0x20, 0x61, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4c, 0x4f, 0x53, 0x54, 0x20, 0x75, 0x70, 0x64,
+    0x61, 0x74, 0x65, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
+    0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x03, 0x01, 0x12, 0x04, 0xfb, 0x01,
+    0x0a, 0x0e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00, 0x12, 0x04, 0xfc, 0x01,
+    0x04, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00, 0x04, 0x12, 0x04, 0xfc,
+    0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0xfc, 0x01, 0x0d, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xfc, 0x01, 0x14, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xfc, 0x01, 0x1e, 0x1f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x03, 0x02, 0x01,
+    0x12, 0x04, 0xfd, 0x01, 0x04, 0x22, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02,
+    0x01, 0x06, 0x12, 0x04, 0xfd, 0x01, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x15, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
+    0x03, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfd, 0x01, 0x20, 0x21, 0x0a, 0xa8, 0x03, 0x0a, 0x04, 0x04,
+    0x01, 0x03, 0x04, 0x12, 0x06, 0x87, 0x02, 0x02, 0x8a, 0x02, 0x03, 0x1a, 0x97, 0x03, 0x20, 0x53,
+    0x68, 0x75, 0x74, 0x73, 0x20, 0x64, 0x6f, 0x77, 0x6e, 0x20, 0x61, 0x20, 0x63, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x2e, 0x20, 0x57, 0x68, 0x65,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x67,
+    0x65, 0x74, 0x73, 0x20, 0x61, 0x20, 0x73, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x0a, 0x20,
+    0x65, 0x76, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x65, 0x78, 0x70,
+    0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x6b, 0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c,
+    0x6c, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x28, 0x61, 0x6e, 0x64,
+    0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4b, 0x49, 0x4c, 0x4c, 0x45,
+    0x44, 0x0a, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x29, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x6e,
+    0xe2, 0x80, 0x99, 0x74, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x69, 0x6e, 0x0a, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x61, 0x69, 0x6e,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x20, 0x28, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x76, 0x69, 0x61, 0x0a, 0x20, 0x27, 0x2d, 0x2d, 0x65,
+    0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x5f, 0x73, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e,
+    0x5f, 0x67, 0x72, 0x61, 0x63, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x27, 0x20, 0x73,
+    0x6c, 0x61, 0x76, 0x65, 0x20, 0x66, 0x6c, 0x61, 0x67, 0x29, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x63,
+    0x65, 0x66, 0x75, 0x6c, 0x6c, 0x79, 0x20, 0x64, 0x65, 0x73, 0x74, 0x72, 0x6f, 0x79, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x65, 0x72, 0x20, 0x28, 0x65, 0x78,
+    0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74,
+    0x61, 0x73, 0x6b, 0x73, 0x29, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65,
+    0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4c,
+    0x4f, 0x53, 0x54, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x04, 0x01, 0x12, 0x04,
+    0x87, 0x02, 0x0a, 0x12, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x04, 0x02, 0x00, 0x12, 0x04,
+    0x88, 0x02, 0x04, 0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0x88, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0x88, 0x02, 0x0d, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x88, 0x02, 0x18, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0x88, 0x02, 0x26, 0x27, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x04,
+    0x02, 0x01, 0x12, 0x04, 0x89, 0x02, 0x04, 0x22, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04,
+    0x02, 0x01, 0x04, 0x12, 0x04, 0x89, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
+    0x04, 0x02, 0x01, 0x06, 0x12, 0x04, 0x89, 0x02, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
+    0x03, 0x04, 0x02, 0x01, 0x01, 0x12, 0x04, 0x89, 0x02, 0x15, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x01, 0x03, 0x04, 0x02, 0x01, 0x03, 0x12, 0x04, 0x89, 0x02, 0x20, 0x21, 0x0a, 0xa0, 0x02, 0x0a,
+    0x04, 0x04, 0x01, 0x03, 0x05, 0x12, 0x06, 0x91, 0x02, 0x02, 0x95, 0x02, 0x03, 0x1a, 0x8f, 0x02,
+    0x20, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x53, 0x63, 0x68, 0x65,
+    0x64, 0x75, 0x6c, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x0a, 0x20, 0x72, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x70, 0x6c,
     0x69, 0x63, 0x69, 0x74, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64,
-    0x67, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x6f,
-    0x66, 0x20, 0x61, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74,
-    0x65, 0x2e, 0x20, 0x53, 0x65, 0x65, 0x0a, 0x20, 0x27, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c,
-    0x65, 0x64, 0x67, 0x65, 0x27, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x27, 0x43, 0x61,
-    0x6c, 0x6c, 0x27, 0x20, 0x73, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x62, 0x65, 0x6c, 0x6f,
-    0x77, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74,
-    0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x03, 0x01, 0x12, 0x03,
-    0x61, 0x0a, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x12, 0x03, 0x62,
-    0x04, 0x23, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x62,
-    0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x62,
-    0x0d, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x62,
-    0x18, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x62,
-    0x21, 0x22, 0x0a, 0xb1, 0x02, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x04, 0x12, 0x04, 0x6a, 0x02, 0x6e,
-    0x03, 0x1a, 0xa2, 0x02, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68,
-    0x65, 0x6e, 0x20, 0x61, 0x20, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73,
-    0x0a, 0x20, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20,
-    0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
-    0x65, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x0a, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x70,
-    0x72, 0x65, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x20, 0x61,
-    0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x77, 0x61,
-    0x72, 0x64, 0x65, 0x64, 0x20, 0x28, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x72, 0x65,
-    0x6c, 0x69, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x0a, 0x20, 0x67, 0x75, 0x61, 0x72, 0x61,
-    0x6e, 0x74, 0x65, 0x65, 0x73, 0x29, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63,
-    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x75,
-    0x70, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f,
-    0x72, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x72, 0x79, 0x0a, 0x20, 0x69, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x64, 0x72,
-    0x6f, 0x70, 0x70, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x72, 0x65,
-    0x61, 0x73, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x04, 0x01, 0x12,
-    0x03, 0x6a, 0x0a, 0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x12, 0x03,
-    0x6b, 0x04, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x6b, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x6b, 0x0d, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x6b, 0x15, 0x1d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x6b, 0x20, 0x21, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x6c,
-    0x04, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6c,
-    0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6c,
-    0x0d, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6c,
-    0x18, 0x23, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6c,
-    0x26, 0x27, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x12, 0x03, 0x6d, 0x04,
-    0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6d, 0x04,
-    0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6d, 0x0d,
-    0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x13,
-    0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6d, 0x1a,
-    0x1b, 0x0a, 0xdf, 0x04, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x05, 0x12, 0x05, 0x7a, 0x02, 0x82, 0x01,
-    0x03, 0x1a, 0xcf, 0x04, 0x20, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68,
-    0x65, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65,
-    0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
-    0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x66, 0x61,
-    0x69, 0x6c, 0x65, 0x64, 0x0a, 0x20, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x20, 0x63, 0x68, 0x65,
-    0x63, 0x6b, 0x73, 0x29, 0x20, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x6e, 0x20,
-    0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x65, 0x72, 0x6d,
-    0x69, 0x6e, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61,
-    0x74, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x0a, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x63,
-    0x6f, 0x69, 0x6e, 0x63, 0x69, 0x64, 0x65, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x72, 0x65,
-    0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61,
-    0x6c, 0x20, 0x55, 0x50, 0x44, 0x41, 0x54, 0x45, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x0a, 0x20, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x20,
-    0x74, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x69, 0x6e, 0x67, 0x20,
-    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x6f, 0x72, 0x20,
-    0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x63,
-    0x65, 0x69, 0x70, 0x74, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x27, 0x52, 0x65, 0x73, 0x63, 0x69, 0x6e,
-    0x64, 0x27, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e,
-    0x79, 0x20, 0x6f, 0x75, 0x74, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66,
-    0x66, 0x65, 0x72, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x74,
-    0x6f, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x2e, 0x20, 0x4e, 0x6f,
-    0x74, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x69, 0x73,
-    0x20, 0x6e, 0x6f, 0x20, 0x67, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x65, 0x65, 0x64, 0x20, 0x6f,
-    0x72, 0x64, 0x65, 0x72, 0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65,
-    0x0a, 0x20, 0x27, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x27, 0x2c, 0x20, 0x27, 0x55, 0x70,
-    0x64, 0x61, 0x74, 0x65, 0x27, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x27, 0x52, 0x65, 0x73, 0x63, 0x69,
-    0x6e, 0x64, 0x27, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
-    0x61, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75,
-    0x74, 0x6f, 0x72, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x2e,
-    0x0a, 0x20, 0x54, 0x4f, 0x44, 0x4f, 0x28, 0x76, 0x69, 0x6e, 0x6f, 0x64, 0x29, 0x3a, 0x20, 0x43,
-    0x6f, 0x6e, 0x73, 0x69, 0x64, 0x65, 0x72, 0x20, 0x73, 0x70, 0x6c, 0x69, 0x74, 0x74, 0x69, 0x6e,
-    0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x73, 0x74, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65,
-    0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x64, 0x0a,
-    0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x73,
-    0x65, 0x70, 0x61, 0x72, 0x61, 0x74, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61,
-    0x6e, 0x64, 0x20, 0x65, 0x6e, 0x73, 0x75, 0x72, 0x65, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x72,
-    0x65, 0x6c, 0x69, 0x61, 0x62, 0x6c, 0x79, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
-    0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x05, 0x01, 0x12, 0x03, 0x7a, 0x0a,
-    0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x12, 0x03, 0x7b, 0x04, 0x22,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7b, 0x04, 0x0c,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x7b, 0x0d, 0x14,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7b, 0x15, 0x1d,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7b, 0x20, 0x21,
-    0x0a, 0xae, 0x01, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x12, 0x04, 0x80, 0x01, 0x04,
-    0x28, 0x1a, 0x9d, 0x01, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x61, 0x73,
-    0x20, 0x6a, 0x75, 0x73, 0x74, 0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x20,
-    0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x6f,
-    0x6e, 0x20, 0x61, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x0a, 0x20,
-    0x27, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x5f, 0x69, 0x64, 0x27, 0x20, 0x77, 0x69,
-    0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x70, 0x6f,
-    0x73, 0x73, 0x69, 0x62, 0x6c, 0x79, 0x20, 0x27, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x27, 0x20,
-    0x28, 0x69, 0x66, 0x20, 0x77, 0x65, 0x20, 0x77, 0x65, 0x72, 0x65, 0x0a, 0x20, 0x61, 0x62, 0x6c,
-    0x65, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x65, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x65, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x29, 0x2e,
-    0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x04, 0x12, 0x04, 0x80, 0x01,
-    0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x06, 0x12, 0x04, 0x80,
-    0x01, 0x0d, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x01, 0x12, 0x04,
-    0x80, 0x01, 0x18, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x01, 0x03, 0x12,
-    0x04, 0x80, 0x01, 0x26, 0x27, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x12,
-    0x04, 0x81, 0x01, 0x04, 0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02, 0x04,
-    0x12, 0x04, 0x81, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02, 0x02,
-    0x05, 0x12, 0x04, 0x81, 0x01, 0x0d, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05, 0x02,
-    0x02, 0x01, 0x12, 0x04, 0x81, 0x01, 0x13, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x05,
-    0x02, 0x02, 0x03, 0x12, 0x04, 0x81, 0x01, 0x1c, 0x1d, 0x0a, 0xd0, 0x01, 0x0a, 0x04, 0x04, 0x00,
-    0x03, 0x06, 0x12, 0x06, 0x87, 0x01, 0x02, 0x89, 0x01, 0x03, 0x1a, 0xbf, 0x01, 0x20, 0x52, 0x65,
-    0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x72,
-    0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x75, 0x6e, 0x72, 0x65, 0x63, 0x6f, 0x76, 0x65,
-    0x72, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x28, 0x65, 0x2e,
-    0x67, 0x2e, 0x2c, 0x0a, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x66,
-    0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x2c, 0x20, 0x72, 0x61, 0x74, 0x65,
-    0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x61, 0x75, 0x74, 0x68, 0x6f,
-    0x72, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x73, 0x20,
-    0x65, 0x74, 0x63, 0x2e, 0x29, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x63, 0x68, 0x65,
-    0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x61, 0x62, 0x6f,
-    0x72, 0x74, 0x20, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x69, 0x6e, 0x67, 0x20,
-    0x74, 0x68, 0x69, 0x73, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x00, 0x03, 0x06, 0x01, 0x12, 0x04, 0x87, 0x01, 0x0a, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
-    0x00, 0x03, 0x06, 0x02, 0x00, 0x12, 0x04, 0x88, 0x01, 0x04, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x00, 0x03, 0x06, 0x02, 0x00, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x00, 0x03, 0x06, 0x02, 0x00, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0d, 0x13, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x00, 0x03, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x14, 0x1b, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x00, 0x03, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x01, 0x1e, 0x1f, 0x0a,
-    0xbb, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x19, 0x1a, 0xac,
-    0x01, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76,
-    0x65, 0x6e, 0x74, 0x2c, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x20, 0x77,
-    0x68, 0x69, 0x63, 0x68, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66, 0x69,
-    0x65, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64,
-    0x20, 0x62, 0x65, 0x0a, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x66, 0x20,
-    0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20,
-    0x6e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x64,
-    0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x20, 0x45, 0x6e, 0x75, 0x6d,
-    0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62,
-    0x65, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x3a,
-    0x20, 0x4d, 0x45, 0x53, 0x4f, 0x53, 0x2d, 0x34, 0x39, 0x39, 0x37, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x04, 0x8e, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x12, 0x04, 0x90, 0x01, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04,
-    0x12, 0x04, 0x90, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12,
-    0x04, 0x90, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04,
-    0x90, 0x01, 0x16, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0x90,
-    0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x91, 0x01, 0x02,
-    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x11, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x91, 0x01, 0x12, 0x18, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x92, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x03, 0x04, 0x12, 0x04, 0x92, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x03, 0x06, 0x12, 0x04, 0x92, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x03, 0x01, 0x12, 0x04, 0x92, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
-    0x03, 0x12, 0x04, 0x92, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12,
-    0x04, 0x93, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x04,
-    0x93, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12, 0x04, 0x93,
-    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x93, 0x01,
-    0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x04, 0x93, 0x01, 0x1b,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0x94, 0x01, 0x02, 0x1f, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x04, 0x94, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x04, 0x94, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0x94, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x04, 0x94, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x06, 0x12, 0x04, 0x95, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x06, 0x04, 0x12, 0x04, 0x95, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06,
-    0x06, 0x12, 0x04, 0x95, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01,
-    0x12, 0x04, 0x95, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12,
-    0x04, 0x95, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04, 0x96,
-    0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x04, 0x96, 0x01,
-    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x04, 0x96, 0x01, 0x0b,
-    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0x96, 0x01, 0x11, 0x16,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x04, 0x96, 0x01, 0x19, 0x1a, 0x0a,
-    0x86, 0x01, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x06, 0xa0, 0x01, 0x00, 0xd0, 0x02, 0x01, 0x1a, 0x78,
-    0x2a, 0x0a, 0x20, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c,
-    0x6c, 0x20, 0x41, 0x50, 0x49, 0x2e, 0x0a, 0x0a, 0x20, 0x4c, 0x69, 0x6b, 0x65, 0x20, 0x45, 0x76,
-    0x65, 0x6e, 0x74, 0x2c, 0x20, 0x61, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x64,
-    0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x64, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x74,
-    0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x62, 0x75, 0x66, 0x66, 0x65, 0x72, 0x0a, 0x20, 0x22, 0x75, 0x6e,
-    0x69, 0x6f, 0x6e, 0x22, 0x20, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x20, 0x28, 0x73, 0x65, 0x65, 0x20,
-    0x61, 0x62, 0x6f, 0x76, 0x65, 0x29, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
-    0x04, 0xa0, 0x01, 0x08, 0x0c, 0x0a, 0x56, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x06, 0xa3,
-    0x01, 0x02, 0xbb, 0x01, 0x03, 0x1a, 0x46, 0x20, 0x50, 0x6f, 0x73, 0x73, 0x69, 0x62, 0x6c, 0x65,
-    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2c, 0x20, 0x66, 0x6f, 0x6c,
-    0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x66, 0x0a,
-    0x20, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x07, 0x0b, 0x0a, 0x5a, 0x0a, 0x06,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xa5, 0x01, 0x04, 0x10, 0x1a, 0x4a, 0x20, 0x53,
-    0x65, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x62, 0x6f, 0x76,
-    0x65, 0x20, 0x6f, 0x6e, 0x20, 0x60, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x3a, 0x3a, 0x54, 0x79, 0x70,
-    0x65, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x64, 0x65, 0x74, 0x61,
-    0x69, 0x6c, 0x73, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x75, 0x6d,
-    0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xa5, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xa5, 0x01, 0x0e, 0x0f, 0x0a, 0x28, 0x0a, 0x06, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x12, 0x22, 0x18, 0x20, 0x53, 0x65, 0x65,
-    0x20, 0x27, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c,
-    0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0xa7, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x02,
-    0x12, 0x04, 0xa7, 0x01, 0x10, 0x11, 0x0a, 0x47, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02,
-    0x12, 0x04, 0xa8, 0x01, 0x04, 0x11, 0x22, 0x37, 0x20, 0x53, 0x68, 0x75, 0x74, 0x73, 0x20, 0x64,
-    0x6f, 0x77, 0x6e, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x2f, 0x65, 0x78,
-    0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x6d, 0x6f,
-    0x76, 0x65, 0x73, 0x20, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x0c,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xa8, 0x01, 0x0f,
-    0x10, 0x0a, 0x25, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x04,
-    0x0f, 0x22, 0x15, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x27,
-    0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
-    0x02, 0x03, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xa9, 0x01, 0x0d, 0x0e, 0x0a, 0x26, 0x0a, 0x06, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x10, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65,
-    0x20, 0x27, 0x44, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77,
-    0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xaa,
-    0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04,
-    0xaa, 0x01, 0x0e, 0x0f, 0x0a, 0x49, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04,
-    0xab, 0x01, 0x04, 0x0f, 0x22, 0x39, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x73, 0x20, 0x61,
-    0x6e, 0x79, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x74,
-    0x65, 0x72, 0x73, 0x20, 0x73, 0x65, 0x74, 0x20, 0x76, 0x69, 0x61, 0x20, 0x41, 0x43, 0x43, 0x45,
-    0x50, 0x54, 0x20, 0x6f, 0x72, 0x20, 0x44, 0x45, 0x43, 0x4c, 0x49, 0x4e, 0x45, 0x2e, 0x0a, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xab, 0x01, 0x04, 0x0a,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xab, 0x01, 0x0d,
-    0x0e, 0x0a, 0x23, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x06, 0x12, 0x04, 0xac, 0x01, 0x04,
-    0x0d, 0x22, 0x13, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x4b, 0x69, 0x6c, 0x6c, 0x27, 0x20, 0x62,
-    0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x06,
-    0x01, 0x12, 0x04, 0xac, 0x01, 0x04, 0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
-    0x06, 0x02, 0x12, 0x04, 0xac, 0x01, 0x0b, 0x0c, 0x0a, 0x27, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00,
-    0x02, 0x07, 0x12, 0x04, 0xad, 0x01, 0x04, 0x11, 0x22, 0x17, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27,
-    0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e,
-    0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0xad, 0x01,
-    0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0xad,
-    0x01, 0x0f, 0x10, 0x0a, 0x2a, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x08, 0x12, 0x04, 0xae,
-    0x01, 0x04, 0x14, 0x22, 0x1a, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x41, 0x63, 0x6b, 0x6e, 0x6f,
-    0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0xae, 0x01, 0x04, 0x0f,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0xae, 0x01, 0x12,
-    0x13, 0x0a, 0x28, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x09, 0x12, 0x04, 0xaf, 0x01, 0x04,
-    0x12, 0x22, 0x18, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x52, 0x65, 0x63, 0x6f, 0x6e, 0x63, 0x69,
-    0x6c, 0x65, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x09, 0x02, 0x12, 0x04, 0xaf, 0x01, 0x10, 0x11, 0x0a, 0x26, 0x0a,
-    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x04, 0xb0, 0x01, 0x04, 0x11, 0x22, 0x16, 0x20,
-    0x53, 0x65, 0x65, 0x20, 0x27, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x27, 0x20, 0x62, 0x65,
-    0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0a, 0x01,
-    0x12, 0x04, 0xb0, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0a,
-    0x02, 0x12, 0x04, 0xb0, 0x01, 0x0e, 0x10, 0x0a, 0x26, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02,
-    0x0b, 0x12, 0x04, 0xb1, 0x01, 0x04, 0x11, 0x22, 0x16, 0x20, 0x53, 0x65, 0x65, 0x20, 0x27, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x27, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x2e, 0x0a, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x04, 0x0b,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x04, 0xb1, 0x01, 0x0e,
-    0x10, 0x0a, 0x48, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x0c, 0x12, 0x04, 0xb2, 0x01, 0x04,
-    0x12, 0x22, 0x38, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65,
-    0x72, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x74, 0x6f, 0x70, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x69, 0x6e,
-    0x67, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x0c, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x0c, 0x02, 0x12, 0x04, 0xb2, 0x01, 0x0f, 0x11, 0x0a, 0xa2, 0x01,
-    0x0a, 0x04, 0x04, 0x01, 0x03, 0x00, 0x12, 0x06, 0xc0, 0x01, 0x02, 0xcd, 0x01, 0x03, 0x1a, 0x91,
-    0x01, 0x20, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65,
-    0x63, 0x65, 0x69, 0x76, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20, 0x41, 0x0a,
-    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20,
-    0x73, 0x65, 0x6e, 0x64, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73,
-    0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x69, 0x74, 0x20, 0x68,
-    0x61, 0x73, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x0a,
-    0x20, 0x53, 0x55, 0x42, 0x43, 0x52, 0x49, 0x42, 0x45, 0x44, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
-    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x0a,
-    0x13, 0x0a, 0x65, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x00, 0x02, 0x00, 0x12, 0x04, 0xc3, 0x01, 0x04,
-    0x2e, 0x1a, 0x55, 0x20, 0x53, 0x65, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d,
-    0x65, 0x6e, 0x74, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x6f, 0x6e, 0x20, 0x27, 0x66,
-    0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x27, 0x20, 0x6f, 0x6e, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x73, 0x20, 0x66, 0x6f,
-    0x72, 0x0a, 0x20, 0x27, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x6e,
-    0x66, 0x6f, 0x2e, 0x69, 0x64, 0x27, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00,
-    0x02, 0x00, 0x04, 0x12, 0x04, 0xc3, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
-    0x00, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc3, 0x01, 0x0d, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
-    0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x1b, 0x29, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x2c, 0x2d, 0x0a, 0xdf, 0x03, 0x0a,
-    0x06, 0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x04, 0x1c, 0x1a, 0xce, 0x03,
-    0x20, 0x4e, 0x4f, 0x54, 0x45, 0x3a, 0x20, 0x27, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x27, 0x20, 0x66,
-    0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x70, 0x72, 0x65, 0x73,
-    0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x76, 0x31, 0x2f, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
-    0x6c, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73,
-    0x65, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x75, 0x73,
-    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
-    0x6c, 0x65, 0x72, 0x20, 0x64, 0x72, 0x69, 0x76, 0x65, 0x72, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x64, 0x72, 0x69, 0x76, 0x65, 0x72, 0x20, 0x73, 0x65, 0x74, 0x73, 0x20, 0x69, 0x74, 0x20, 0x74,
-    0x6f, 0x20, 0x74, 0x72, 0x75, 0x65, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x0a,
-    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x72, 0x65, 0x2d, 0x72, 0x65,
-    0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72,
-    0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x20, 0x4f, 0x6e, 0x63,
-    0x65, 0x0a, 0x20, 0x72, 0x65, 0x2d, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64,
-    0x20, 0x61, 0x6c, 0x6c, 0x20, 0x73, 0x75, 0x62, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x74, 0x20,
-    0x72, 0x65, 0x2d, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
-    0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20,
-    0x64, 0x75, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x5a, 0x4b, 0x0a, 0x20, 0x62, 0x6c, 0x69, 0x70, 0x29,
-    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x27, 0x66, 0x6f, 0x72, 0x63,
-    0x65, 0x27, 0x20, 0x73, 0x65, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x2e,
-    0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x61,
-    0x6e, 0x74, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65,
-    0x72, 0x0a, 0x20, 0x75, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65,
-    0x6c, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
-    0x69, 0x74, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x65, 0x6e, 0x64,
-    0x20, 0x46, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74,
-    0x65, 0x72, 0x65, 0x64, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x0a, 0x20, 0x76, 0x73, 0x20,
-    0x46, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x52, 0x65, 0x72, 0x65, 0x67, 0x69, 0x73,
-    0x74, 0x65, 0x72, 0x65, 0x64, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x04, 0x12, 0x04, 0xcc, 0x01, 0x04, 0x0c, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x0d, 0x11,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x12,
-    0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcc, 0x01,
-    0x1a, 0x1b, 0x0a, 0x8c, 0x06, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x01, 0x12, 0x06, 0xe5, 0x01, 0x02,
-    0xe9, 0x01, 0x03, 0x1a, 0xfb, 0x05, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x73, 0x20, 0x61,
-    0x6e, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x70, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d,
-    0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65,
-    0x64, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x20, 0x69, 0x6e,
-    0x20, 0x61, 0x20, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x6d, 0x61,
-    0x6e, 0x6e, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x20, 0x45, 0x2e, 0x67, 0x2e, 0x20, 0x4c, 0x61, 0x75,
-    0x6e, 0x63, 0x68, 0x20, 0x61, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
-    0x61, 0x20, 0x6e, 0x65, 0x77, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64,
-    0x20, 0x70, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x20, 0x76, 0x6f, 0x6c, 0x75,
-    0x6d, 0x65, 0x3a, 0x0a, 0x0a, 0x20, 0x20, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x20, 0x7b,
-    0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x3a,
-    0x20, 0x5b, 0x20, 0x2e, 0x2e, 0x2e, 0x20, 0x5d, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x6f, 0x70,
-    0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x20, 0x5b, 0x0a, 0x20, 0x20, 0x20, 0x20,
-    0x20, 0x20, 0x20, 0x7b, 0x20, 0x74, 0x79, 0x70, 0x65, 0x3a, 0x20, 0x52, 0x45, 0x53, 0x45, 0x52,
-    0x56, 0x45, 0x2c, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x72, 0x65, 0x73,
-    0x65, 0x72, 0x76, 0x65, 0x3a, 0x20, 0x7b, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
-    0x73, 0x3a, 0x20, 0x5b, 0x20, 0x64, 0x69, 0x73, 0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a,
-    0x32, 0x20, 0x5d, 0x20, 0x7d, 0x20, 0x7d, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x7b,
-    0x20, 0x74, 0x79, 0x70, 0x65, 0x3a, 0x20, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x2c, 0x0a, 0x20,
-    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x3a, 0x20,
-    0x7b, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x3a, 0x20, 0x5b, 0x20, 0x64, 0x69, 0x73,
-    0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a, 0x31, 0x2b, 0x70, 0x65, 0x72, 0x73, 0x69, 0x73,
-    0x74, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x5d, 0x20, 0x7d, 0x20, 0x7d, 0x0a, 0x20, 0x20, 0x20, 0x20,
-    0x20, 0x20, 0x20, 0x7b, 0x20, 0x74, 0x79, 0x70, 0x65, 0x3a, 0x20, 0x4c, 0x41, 0x55, 0x4e, 0x43,
-    0x48, 0x2c, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x6c, 0x61, 0x75, 0x6e,
-    0x63, 0x68, 0x3a, 0x20, 0x7b, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x73,
-    0x20, 0x2e, 0x2e, 0x2e, 0x20, 0x64, 0x69, 0x73, 0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a,
-    0x31, 0x3b, 0x64, 0x69, 0x73, 0x6b, 0x28, 0x72, 0x6f, 0x6c, 0x65, 0x29, 0x3a, 0x31, 0x2b, 0x70,
-    0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x7d, 0x20, 0x7d, 0x0a, 0x20,
-    0x20, 0x20, 0x20, 0x20, 0x5d, 0x0a, 0x20, 0x20, 0x20, 0x7d, 0x0a, 0x0a, 0x20, 0x4e, 0x4f, 0x54,
-    0x45, 0x3a, 0x20, 0x41, 0x6e, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x66,
-    0x66, 0x65, 0x72, 0xe2, 0x80, 0x99, 0x73, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
-    0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x60, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x60, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x0a,
-    0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x61, 0x75, 0x6e, 0x63,
-    0x68, 0x20, 0x61, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x29, 0x20, 0x61, 0x72, 0x65, 0x20, 0x63, 0x6f,
-    0x6e, 0x73, 0x69, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x75, 0x6e, 0x75, 0x73, 0x65, 0x64, 0x20,
-    0x61, 0x6e, 0x64, 0x20, 0x6d, 0x69, 0x67, 0x68, 0x74, 0x20, 0x62, 0x65, 0x0a, 0x20, 0x72, 0x65,
-    0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72,
-    0x20, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x73, 0x2e, 0x20, 0x49, 0x6e, 0x20,
-    0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x77, 0x6f, 0x72, 0x64, 0x73, 0x2c, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x60, 0x4f, 0x66, 0x66, 0x65, 0x72, 0x49, 0x44, 0x60, 0x0a,
-    0x20, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20,
-    0x69, 0x6e, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x6f, 0x6e, 0x65,
-    0x20, 0x60, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x60, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a,
-    0x20, 0x4e, 0x4f, 0x54, 0x45, 0x3a, 0x20, 0x41, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72,
-    0x73, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x74, 0x6f,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x01, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x0a, 0x10,
-    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x12, 0x04, 0xe6, 0x01, 0x04, 0x23,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe6, 0x01, 0x04,
-    0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe6, 0x01,
-    0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe6,
-    0x01, 0x15, 0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x00, 0x03, 0x12, 0x04,
-    0xe6, 0x01, 0x21, 0x22, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x12, 0x04,
-    0xe7, 0x01, 0x04, 0x2c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x04, 0x12,
-    0x04, 0xe7, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01, 0x06,
-    0x12, 0x04, 0xe7, 0x01, 0x0d, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02, 0x01,
-    0x01, 0x12, 0x04, 0xe7, 0x01, 0x1d, 0x27, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x2a, 0x2b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x01,
-    0x02, 0x02, 0x12, 0x04, 0xe8, 0x01, 0x04, 0x21, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x01,
-    0x02, 0x02, 0x04, 0x12, 0x04, 0xe8, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
-    0x01, 0x02, 0x02, 0x06, 0x12, 0x04, 0xe8, 0x01, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
-    0x03, 0x01, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe8, 0x01, 0x15, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x03, 0x01, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe8, 0x01, 0x1f, 0x20, 0x0a, 0xf0, 0x01, 0x0a,
-    0x04, 0x04, 0x01, 0x03, 0x02, 0x12, 0x06, 0xef, 0x01, 0x02, 0xf2, 0x01, 0x03, 0x1a, 0xdf, 0x01,
-    0x20, 0x44, 0x65, 0x63, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x66, 0x66,
-    0x65, 0x72, 0x2c, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x6f, 0x74, 0x65,
-    0x6e, 0x74, 0x69, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x0a,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x74,
-    0x6f, 0x20, 0x61, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x66, 0x72,
-    0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68,
-    0x61, 0x74, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x0a,
-    0x20, 0x61, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x20, 0x41,
-    0x63, 0x63, 0x65, 0x70, 0x74, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
-    0x6e, 0x6f, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2e, 0x20, 0x53,
-    0x65, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x0a, 0x20,
-    0x74, 0x6f, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x27, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x27, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x02, 0x01, 0x12, 0x04, 0xef, 0x01, 0x0a, 0x11, 0x0a, 0x0e,
-    0x0a, 0x06, 0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x12, 0x04, 0xf0, 0x01, 0x04, 0x23, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf0, 0x01, 0x04, 0x0c, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf0, 0x01, 0x0d, 0x14,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x15,
-    0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf0, 0x01,
-    0x21, 0x22, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf1, 0x01,
-    0x04, 0x21, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x04, 0x12, 0x04, 0xf1,
-    0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x06, 0x12, 0x04,
-    0xf1, 0x01, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0xf1, 0x01, 0x15, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x02, 0x02, 0x01, 0x03,
-    0x12, 0x04, 0xf1, 0x01, 0x1f, 0x20, 0x0a, 0x9e, 0x03, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x03, 0x12,
-    0x06, 0xfb, 0x01, 0x02, 0xfe, 0x01, 0x03, 0x1a, 0x8d, 0x03, 0x20, 0x4b, 0x69, 0x6c, 0x6c, 0x73,
-    0x20, 0x61, 0x20, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x74, 0x61, 0x73, 0x6b,
-    0x2e, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c,
-    0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20,
-    0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x2c, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b,
-    0x69, 0x6c, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x65, 0x64,
-    0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72,
-    0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x75, 0x70, 0x20, 0x74, 0x6f,
-    0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x74,
-    0x6f, 0x20, 0x6b, 0x69, 0x6c, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20,
-    0x61, 0x6e, 0x64, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x61, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f,
-    0x4b, 0x49, 0x4c, 0x4c, 0x45, 0x44, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f,
-    0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x29, 0x0a, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e,
-    0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73,
-    0x20, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
-    0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x74, 0x61,
-    0x73, 0x6b, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x74, 0x0a, 0x20, 0x72, 0x65, 0x63, 0x65,
-    0x69, 0x76, 0x65, 0x73, 0x20, 0x61, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x20,
-    0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x28, 0x53, 0x65, 0x65, 0x20, 0x54, 0x61, 0x73, 0x6b,
-    0x53, 0x74, 0x61, 0x74, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x6d, 0x65, 0x73, 0x6f, 0x73, 0x2e, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x29, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x20, 0x49,
-    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x69, 0x73, 0x20, 0x75, 0x6e,
-    0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x73,
-    0x74, 0x65, 0x72, 0x2c, 0x20, 0x61, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4c, 0x4f, 0x53, 0x54,
-    0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x67, 0x65, 0x6e, 0x65,
-    0x72, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x03, 0x01,
-    0x12, 0x04, 0xfb, 0x01, 0x0a, 0x0e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00,
-    0x12, 0x04, 0xfc, 0x01, 0x04, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x00,
-    0x04, 0x12, 0x04, 0xfc, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03, 0x02,
-    0x00, 0x06, 0x12, 0x04, 0xfc, 0x01, 0x0d, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x03,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x01, 0x14, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
-    0x03, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfc, 0x01, 0x1e, 0x1f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01,
-    0x03, 0x03, 0x02, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x22, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
-    0x03, 0x03, 0x02, 0x01, 0x04, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x03, 0x03, 0x02, 0x01, 0x06, 0x12, 0x04, 0xfd, 0x01, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x01, 0x03, 0x03, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x15, 0x1d, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x01, 0x03, 0x03, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfd, 0x01, 0x20, 0x21, 0x0a, 0xa8,
-    0x03, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x04, 0x12, 0x06, 0x87, 0x02, 0x02, 0x8a, 0x02, 0x03, 0x1a,
-    0x97, 0x03, 0x20, 0x53, 0x68, 0x75, 0x74, 0x73, 0x20, 0x64, 0x6f, 0x77, 0x6e, 0x20, 0x61, 0x20,
-    0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x2e,
-    0x20, 0x57, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74,
-    0x6f, 0x72, 0x20, 0x67, 0x65, 0x74, 0x73, 0x20, 0x61, 0x20, 0x73, 0x68, 0x75, 0x74, 0x64, 0x6f,
-    0x77, 0x6e, 0x0a, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73,
-    0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x6b, 0x69, 0x6c,
-    0x6c, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x20,
-    0x28, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4b,
-    0x49, 0x4c, 0x4c, 0x45, 0x44, 0x0a, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x29, 0x20,
-    0x61, 0x6e, 0x64, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x49,
-    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x64,
-    0x6f, 0x65, 0x73, 0x6e, 0xe2, 0x80, 0x99, 0x74, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61,
-    0x74, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x0a, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72,
-    0x74, 0x61, 0x69, 0x6e, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x20, 0x28, 0x63, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x76, 0x69, 0x61, 0x0a, 0x20,
-    0x27, 0x2d, 0x2d, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x5f, 0x73, 0x68, 0x75, 0x74,
-    0x64, 0x6f, 0x77, 0x6e, 0x5f, 0x67, 0x72, 0x61, 0x63, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x69, 0x6f,
-    0x64, 0x27, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x66, 0x6c, 0x61, 0x67, 0x29, 0x2c, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x0a, 0x20,
-    0x66, 0x6f, 0x72, 0x63, 0x65, 0x66, 0x75, 0x6c, 0x6c, 0x79, 0x20, 0x64, 0x65, 0x73, 0x74, 0x72,
-    0x6f, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x65, 0x72,
-    0x20, 0x28, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69,
-    0x74, 0x73, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x29, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x74,
-    0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x61, 0x63,
-    0x74, 0x69, 0x76, 0x65, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x54, 0x41,
-    0x53, 0x4b, 0x5f, 0x4c, 0x4f, 0x53, 0x54, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03,
-    0x04, 0x01, 0x12, 0x04, 0x87, 0x02, 0x0a, 0x12, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x04,
-    0x02, 0x00, 0x12, 0x04, 0x88, 0x02, 0x04, 0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04,
-    0x02, 0x00, 0x04, 0x12, 0x04, 0x88, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
-    0x04, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x02, 0x0d, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
-    0x03, 0x04, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x02, 0x18, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x03, 0x04, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x02, 0x26, 0x27, 0x0a, 0x0e, 0x0a, 0x06,
-    0x04, 0x01, 0x03, 0x04, 0x02, 0x01, 0x12, 0x04, 0x89, 0x02, 0x04, 0x22, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x01, 0x03, 0x04, 0x02, 0x01, 0x04, 0x12, 0x04, 0x89, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x01, 0x03, 0x04, 0x02, 0x01, 0x06, 0x12, 0x04, 0x89, 0x02, 0x0d, 0x14, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x01, 0x03, 0x04, 0x02, 0x01, 0x01, 0x12, 0x04, 0x89, 0x02, 0x15, 0x1d, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x04, 0x02, 0x01, 0x03, 0x12, 0x04, 0x89, 0x02, 0x20, 0x21,
-    0x0a, 0xa0, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x05, 0x12, 0x06, 0x91, 0x02, 0x02, 0x95, 0x02,
-    0x03, 0x1a, 0x8f, 0x02, 0x20, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65,
-    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x6f, 0x66,
-    0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20,
-    0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x0a, 0x20,
-    0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20,
-    0x65, 0x78, 0x70, 0x6c, 0x69, 0x63, 0x69, 0x74, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x6b, 0x6e, 0x6f,
-    0x77, 0x6c, 0x65, 0x64, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63,
-    0x65, 0x69, 0x70, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x0a, 0x20,
-    0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x68, 0x61, 0x76,
-    0x65, 0x20, 0x27, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x28, 0x29, 0x2e, 0x75, 0x75, 0x69, 0x64, 0x28, 0x29, 0x27, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
-    0x20, 0x73, 0x65, 0x74, 0x2e, 0x20, 0x53, 0x75, 0x63, 0x68, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75,
-    0x73, 0x0a, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72,
-    0x65, 0x74, 0x72, 0x69, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6c,
-    0x61, 0x76, 0x65, 0x20, 0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61,
-    0x72, 0x65, 0x20, 0x61, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x64, 0x20,
-    0x62, 0x79, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65,
-    0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x05, 0x01, 0x12, 0x04, 0x91, 0x02,
-    0x0a, 0x15, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x12, 0x04, 0x92, 0x02,
-    0x04, 0x22, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x04, 0x12, 0x04, 0x92,
-    0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x06, 0x12, 0x04,
-    0x92, 0x02, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x01, 0x12,
-    0x04, 0x92, 0x02, 0x15, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x03,
-    0x12, 0x04, 0x92, 0x02, 0x20, 0x21, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01,
-    0x12, 0x04, 0x93, 0x02, 0x04, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01,
-    0x04, 0x12, 0x04, 0x93, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02,
-    0x01, 0x06, 0x12, 0x04, 0x93, 0x02, 0x0d, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0x93, 0x02, 0x14, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
-    0x05, 0x02, 0x01, 0x03, 0x12, 0x04, 0x93, 0x02, 0x1e, 0x1f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01,
-    0x03, 0x05, 0x02, 0x02, 0x12, 0x04, 0x94, 0x02, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
-    0x03, 0x05, 0x02, 0x02, 0x04, 0x12, 0x04, 0x94, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x03, 0x05, 0x02, 0x02, 0x05, 0x12, 0x04, 0x94, 0x02, 0x0d, 0x12, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x01, 0x03, 0x05, 0x02, 0x02, 0x01, 0x12, 0x04, 0x94, 0x02, 0x13, 0x17, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x02, 0x03, 0x12, 0x04, 0x94, 0x02, 0x1a, 0x1b, 0x0a, 0xdf,
-    0x02, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x06, 0x12, 0x06, 0x9d, 0x02, 0x02, 0xa5, 0x02, 0x03, 0x1a,
-    0xce, 0x02, 0x20, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63,
-    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20,
-    0x6e, 0x6f, 0x6e, 0x2d, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x74, 0x61, 0x73,
-    0x6b, 0x73, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x63, 0x61, 0x75, 0x73, 0x65, 0x73,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x73,
-    0x65, 0x6e, 0x64, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74,
-    0x65, 0x73, 0x74, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20,
-    0x66, 0x6f, 0x72, 0x0a, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x69,
-    0x6e, 0x20, 0x27, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x27, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x70, 0x6f,
-    0x73, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x2e, 0x20, 0x54, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x74, 0x68,
-    0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x65, 0x72,
-    0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x0a, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x72, 0x65, 0x73,
-    0x75, 0x6c, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4c, 0x4f,
-    0x53, 0x54, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x27, 0x73,
-    0x74, 0x61, 0x74, 0x75, 0x73, 0x65, 0x73, 0x27, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6d, 0x70, 0x74,
-    0x79, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x73,
-    0x74, 0x65, 0x72, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x63, 0x75,
-    0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x0a, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2e, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x06, 0x01, 0x12, 0x04, 0x9d, 0x02, 0x0a, 0x13, 0x0a,
-    0x54, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x12, 0x06, 0x9f, 0x02, 0x04, 0xa2, 0x02,
-    0x05, 0x1a, 0x42, 0x20, 0x54, 0x4f, 0x44, 0x4f, 0x28, 0x76, 0x69, 0x6e, 0x6f, 0x64, 0x29, 0x3a,
-    0x20, 0x53, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x61, 0x72, 0x62, 0x69, 0x74, 0x72, 0x61,
-    0x72, 0x79, 0x20, 0x71, 0x75, 0x65, 0x72, 0x69, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20,
-    0x6a, 0x75, 0x73, 0x74, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x61,
-    0x73, 0x6b, 0x73, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x01,
-    0x12, 0x04, 0x9f, 0x02, 0x0c, 0x10, 0x0a, 0x10, 0x0a, 0x08, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00,
-    0x02, 0x00, 0x12, 0x04, 0xa0, 0x02, 0x06, 0x22, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06,
-    0x03, 0x00, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa0, 0x02, 0x06, 0x0e, 0x0a, 0x11, 0x0a, 0x09, 0x04,
-    0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa0, 0x02, 0x0f, 0x15, 0x0a, 0x11,
-    0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x16,
-    0x1d, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04,
-    0xa0, 0x02, 0x20, 0x21, 0x0a, 0x10, 0x0a, 0x08, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x01,
-    0x12, 0x04, 0xa1, 0x02, 0x06, 0x24, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00,
-    0x02, 0x01, 0x04, 0x12, 0x04, 0xa1, 0x02, 0x06, 0x0e, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03,
-    0x06, 0x03, 0x00, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa1, 0x02, 0x0f, 0x16, 0x0a, 0x11, 0x0a, 0x09,
-    0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x17, 0x1f, 0x0a,
-    0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa1, 0x02,
-    0x22, 0x23, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x12, 0x04, 0xa4, 0x02,
-    0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa4,
-    0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x06, 0x12, 0x04,
-    0xa4, 0x02, 0x0d, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x01, 0x12,
-    0x04, 0xa4, 0x02, 0x12, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x03,
-    0x12, 0x04, 0xa4, 0x02, 0x1a, 0x1b, 0x0a, 0xbb, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x07, 0x12,
-    0x06, 0xaa, 0x02, 0x02, 0xae, 0x02, 0x03, 0x1a, 0xaa, 0x01, 0x20, 0x53, 0x65, 0x6e, 0x64, 0x73,
-    0x20, 0x61, 0x72, 0x62, 0x69, 0x74, 0x72, 0x61, 0x72, 0x79, 0x20, 0x62, 0x69, 0x6e, 0x61, 0x72,
-    0x79, 0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78,
-    0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61,
-    0x74, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x0a, 0x20, 0x6e, 0x65, 0x69, 0x74, 0x68, 0x65, 0x72,
-    0x20, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x70, 0x72, 0x65, 0x74, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73,
-    0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x6e, 0x6f, 0x72, 0x20, 0x6d, 0x61, 0x6b, 0x65, 0x73, 0x20,
-    0x61, 0x6e, 0x79, 0x20, 0x67, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x65, 0x65, 0x73, 0x20, 0x61,
-    0x62, 0x6f, 0x75, 0x74, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x64, 0x65, 0x6c, 0x69, 0x76, 0x65,
-    0x72, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61,
-    0x67, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74,
-    0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x07, 0x01, 0x12, 0x04, 0xaa,
-    0x02, 0x0a, 0x11, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x12, 0x04, 0xab,
-    0x02, 0x04, 0x22, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x04, 0x12, 0x04,
-    0xab, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x06, 0x12,
-    0x04, 0xab, 0x02, 0x0d, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x01,
-    0x12, 0x04, 0xab, 0x02, 0x15, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0xab, 0x02, 0x20, 0x21, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x07, 0x02,
-    0x01, 0x12, 0x04, 0xac, 0x02, 0x04, 0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02,
-    0x01, 0x04, 0x12, 0x04, 0xac, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07,
-    0x02, 0x01, 0x06, 0x12, 0x04, 0xac, 0x02, 0x0d, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
-    0x07, 0x02, 0x01, 0x01, 0x12, 0x04, 0xac, 0x02, 0x18, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
-    0x03, 0x07, 0x02, 0x01, 0x03, 0x12, 0x04, 0xac, 0x02, 0x26, 0x27, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
-    0x01, 0x03, 0x07, 0x02, 0x02, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x01, 0x03, 0x07, 0x02, 0x02, 0x04, 0x12, 0x04, 0xad, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x01, 0x03, 0x07, 0x02, 0x02, 0x05, 0x12, 0x04, 0xad, 0x02, 0x0d, 0x12, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x02, 0x01, 0x12, 0x04, 0xad, 0x02, 0x13, 0x17, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x02, 0x03, 0x12, 0x04, 0xad, 0x02, 0x1a, 0x1b, 0x0a,
-    0xaf, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x08, 0x12, 0x06, 0xb6, 0x02, 0x02, 0xb8, 0x02, 0x03,
-    0x1a, 0x9e, 0x02, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x61, 0x20, 0x73,
-    0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72,
-    0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x4d, 0x65,
-    0x73, 0x6f, 0x73, 0x27, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x2e,
-    0x20, 0x49, 0x66, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74,
-    0x6f, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x66,
-    0x6f, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73, 0x2c, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x73, 0x20, 0x77, 0x69,
-    0x6c, 0x6c, 0x20, 0x62, 0x65, 0x0a, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x79, 0x6e,
-    0x63, 0x68, 0x72, 0x6f, 0x6e, 0x6f, 0x75, 0x73, 0x6c, 0x79, 0x20, 0x76, 0x69, 0x61, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x4f, 0x46, 0x46, 0x45, 0x52, 0x53, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x28,
-    0x73, 0x29, 0x2e, 0x0a, 0x0a, 0x20, 0x4e, 0x4f, 0x54, 0x45, 0x3a, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x62, 0x75, 0x69, 0x6c, 0x74, 0x2d, 0x69, 0x6e, 0x20, 0x68, 0x69, 0x65, 0x72, 0x61, 0x72, 0x63,
-    0x68, 0x69, 0x63, 0x61, 0x6c, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x20,
-    0x64, 0x6f, 0x65, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x73, 0x75, 0x70,
-    0x70, 0x6f, 0x72, 0x74, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63,
-    0x61, 0x6c, 0x6c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x73, 0x69,
-    0x6d, 0x70, 0x6c, 0x79, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x73, 0x20, 0x69, 0x74, 0x2e,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x08, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x0a, 0x11,
-    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x12, 0x04, 0xb7, 0x02, 0x04, 0x28,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb7, 0x02, 0x04,
-    0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb7, 0x02,
-    0x0d, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb7,
-    0x02, 0x1b, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x03, 0x12, 0x04,
-    0xb7, 0x02, 0x26, 0x27, 0x0a, 0xea, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x04, 0xc0,
-    0x02, 0x02, 0x28, 0x1a, 0xdb, 0x02, 0x20, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65,
-    0x73, 0x20, 0x77, 0x68, 0x6f, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20,
-    0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x20, 0x4d, 0x61, 0x73, 0x74, 0x65,
-    0x72, 0x20, 0x61, 0x73, 0x73, 0x69, 0x67, 0x6e, 0x73, 0x20, 0x61, 0x20, 0x66, 0x72, 0x61, 0x6d,
-    0x65, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64, 0x0a, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61,
-    0x20, 0x6e, 0x65, 0x77, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x73,
-    0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x2e, 0x20, 0x4f, 0x6e,
-    0x63, 0x65, 0x20, 0x61, 0x73, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x2c, 0x0a, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x6d, 0x75, 0x73, 0x74,
-    0x20, 0x73, 0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x27, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77,
-    0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x27, 0x20, 0x68, 0x65, 0x72, 0x65, 0x20, 0x61, 0x6e, 0x64,
-    0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x0a, 0x20, 0x46, 0x72, 0x61,
-    0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x28, 0x69, 0x6e, 0x20, 0x61,
-    0x6e, 0x79, 0x20, 0x66, 0x75, 0x72, 0x74, 0x68, 0x65, 0x72, 0x20, 0x27, 0x53, 0x75, 0x62, 0x73,
-    0x63, 0x72, 0x69, 0x62, 0x65, 0x27, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x29, 0x2e, 0x20, 0x54,
-    0x68, 0x69, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20,
-    0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69,
-    0x66, 0x79, 0x20, 0x61, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x63,
-    0x6f, 0x72, 0x72, 0x65, 0x63, 0x74, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20,
-    0x64, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2c, 0x0a,
-    0x20, 0x66, 0x61, 0x69, 0x6c, 0x6f, 0x76, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x65, 0x74, 0x63, 0x2e,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc0, 0x02, 0x0b, 0x16, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x17, 0x23, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x26, 0x27, 0x0a, 0xe0, 0x01,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x04, 0xc5, 0x02, 0x02, 0x19, 0x1a, 0xd1, 0x01, 0x20,
-    0x54, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c,
-    0x2c, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63,
-    0x68, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
-    0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65,
-    0x0a, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x61,
-    0x74, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x73,
-    0x74, 0x65, 0x64, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x64, 0x65, 0x66, 0x69,
-    0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x20, 0x53, 0x65, 0x65, 0x20, 0x63, 0x6f, 0x6d,
-    0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x20, 0x60, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x3a,
-    0x3a, 0x54, 0x79, 0x70, 0x65, 0x60, 0x20, 0x61, 0x62, 0x6f, 0x76, 0x65, 0x20, 0x6f, 0x6e, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x62, 0x65,
-    0x68, 0x69, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20,
-    0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2e, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x04, 0xc5, 0x02, 0x02, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x04, 0xc5, 0x02, 0x0b, 0x0f, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc5, 0x02, 0x10, 0x14, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc5, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x02, 0x12, 0x04, 0xc7, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x02, 0x04, 0x12, 0x04, 0xc7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x02, 0x06, 0x12, 0x04, 0xc7, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
-    0x01, 0x12, 0x04, 0xc7, 0x02, 0x15, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03,
-    0x12, 0x04, 0xc7, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x04,
-    0xc8, 0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc8,
-    0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x06, 0x12, 0x04, 0xc8, 0x02,
-    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x12,
-    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc8, 0x02, 0x1b, 0x1c,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x1f, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x04, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x04, 0x06, 0x12, 0x04, 0xc9, 0x02, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc9, 0x02, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc9, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x05, 0x12, 0x04, 0xca, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05,
-    0x04, 0x12, 0x04, 0xca, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x06,
-    0x12, 0x04, 0xca, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12,
-    0x04, 0xca, 0x02, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x04,
-    0xca, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x04, 0xcb, 0x02,
-    0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x04, 0x12, 0x04, 0xcb, 0x02, 0x02,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x06, 0x12, 0x04, 0xcb, 0x02, 0x0b, 0x13,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x14, 0x1c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x04, 0xcb, 0x02, 0x1f, 0x20, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x04, 0xcc, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x07, 0x04, 0x12, 0x04, 0xcc, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x07, 0x06, 0x12, 0x04, 0xcc, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x07, 0x01, 0x12, 0x04, 0xcc, 0x02, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x07, 0x03, 0x12, 0x04, 0xcc, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08,
-    0x12, 0x04, 0xcd, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x04, 0x12,
-    0x04, 0xcd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x06, 0x12, 0x04,
-    0xcd, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x04, 0xcd,
-    0x02, 0x15, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x04, 0xcd, 0x02,
-    0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x04, 0xce, 0x02, 0x02, 0x20,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x04, 0x12, 0x04, 0xce, 0x02, 0x02, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x06, 0x12, 0x04, 0xce, 0x02, 0x0b, 0x12, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x04, 0xce, 0x02, 0x13, 0x1a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x04, 0xce, 0x02, 0x1d, 0x1f, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x0a, 0x12, 0x04, 0xcf, 0x02, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x0a, 0x04, 0x12, 0x04, 0xcf, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x0a, 0x06, 0x12, 0x04, 0xcf, 0x02, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a,
-    0x01, 0x12, 0x04, 0xcf, 0x02, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03,
-    0x12, 0x04, 0xcf, 0x02, 0x1d, 0x1f,
+    0x67, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x0a, 0x20, 0x75, 0x70, 0x64, 0x61,
+    0x74, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x27, 0x55,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x28, 0x29, 0x2e, 0x75,
+    0x75, 0x69, 0x64, 0x28, 0x29, 0x27, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x73, 0x65, 0x74,
+    0x2e, 0x20, 0x53, 0x75, 0x63, 0x68, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x0a, 0x20, 0x75,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69,
+    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6c, 0x61, 0x76, 0x65, 0x20,
+    0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61, 0x72, 0x65, 0x20, 0x61,
+    0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x64, 0x20, 0x62, 0x79, 0x0a, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x05, 0x01, 0x12, 0x04, 0x91, 0x02, 0x0a, 0x15, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x12, 0x04, 0x92, 0x02, 0x04, 0x22, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x04, 0x12, 0x04, 0x92, 0x02, 0x04, 0x0c, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x06, 0x12, 0x04, 0x92, 0x02, 0x0d, 0x14,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x01, 0x12, 0x04, 0x92, 0x02, 0x15,
+    0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x00, 0x03, 0x12, 0x04, 0x92, 0x02,
+    0x20, 0x21, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01, 0x12, 0x04, 0x93, 0x02,
+    0x04, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01, 0x04, 0x12, 0x04, 0x93,
+    0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01, 0x06, 0x12, 0x04,
+    0x93, 0x02, 0x0d, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x93, 0x02, 0x14, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0x93, 0x02, 0x1e, 0x1f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x05, 0x02, 0x02,
+    0x12, 0x04, 0x94, 0x02, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0x94, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x94, 0x02, 0x0d, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x05,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0x94, 0x02, 0x13, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
+    0x05, 0x02, 0x02, 0x03, 0x12, 0x04, 0x94, 0x02, 0x1a, 0x1b, 0x0a, 0xdf, 0x02, 0x0a, 0x04, 0x04,
+    0x01, 0x03, 0x06, 0x12, 0x06, 0x9d, 0x02, 0x02, 0xa5, 0x02, 0x03, 0x1a, 0xce, 0x02, 0x20, 0x41,
+    0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75,
+    0x6c, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x6e, 0x2d,
+    0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x2e, 0x0a,
+    0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x63, 0x61, 0x75, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20,
+    0x62, 0x61, 0x63, 0x6b, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20,
+    0x74, 0x61, 0x73, 0x6b, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x0a,
+    0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x69, 0x6e, 0x20, 0x27, 0x74,
+    0x61, 0x73, 0x6b, 0x73, 0x27, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x70, 0x6f, 0x73, 0x73, 0x69, 0x62,
+    0x6c, 0x65, 0x2e, 0x20, 0x54, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61,
+    0x72, 0x65, 0x20, 0x6e, 0x6f, 0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x65, 0x72, 0x20, 0x6b, 0x6e, 0x6f,
+    0x77, 0x6e, 0x0a, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20,
+    0x69, 0x6e, 0x20, 0x61, 0x20, 0x54, 0x41, 0x53, 0x4b, 0x5f, 0x4c, 0x4f, 0x53, 0x54, 0x20, 0x75,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x27, 0x73, 0x74, 0x61, 0x74, 0x75,
+    0x73, 0x65, 0x73, 0x27, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x2c, 0x20, 0x74,
+    0x68, 0x65, 0x6e, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20,
+    0x77, 0x69, 0x6c, 0x6c, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61,
+    0x74, 0x65, 0x73, 0x74, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x65, 0x61, 0x63, 0x68, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e,
+    0x74, 0x6c, 0x79, 0x0a, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x01, 0x03, 0x06, 0x01, 0x12, 0x04, 0x9d, 0x02, 0x0a, 0x13, 0x0a, 0x54, 0x0a, 0x06, 0x04,
+    0x01, 0x03, 0x06, 0x03, 0x00, 0x12, 0x06, 0x9f, 0x02, 0x04, 0xa2, 0x02, 0x05, 0x1a, 0x42, 0x20,
+    0x54, 0x4f, 0x44, 0x4f, 0x28, 0x76, 0x69, 0x6e, 0x6f, 0x64, 0x29, 0x3a, 0x20, 0x53, 0x75, 0x70,
+    0x70, 0x6f, 0x72, 0x74, 0x20, 0x61, 0x72, 0x62, 0x69, 0x74, 0x72, 0x61, 0x72, 0x79, 0x20, 0x71,
+    0x75, 0x65, 0x72, 0x69, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x6a, 0x75, 0x73, 0x74,
+    0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x61, 0x73, 0x6b, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x01, 0x12, 0x04, 0x9f, 0x02,
+    0x0c, 0x10, 0x0a, 0x10, 0x0a, 0x08, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x00, 0x12, 0x04,
+    0xa0, 0x02, 0x06, 0x22, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xa0, 0x02, 0x06, 0x0e, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03,
+    0x00, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa0, 0x02, 0x0f, 0x15, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01,
+    0x03, 0x06, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x16, 0x1d, 0x0a, 0x11, 0x0a,
+    0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa0, 0x02, 0x20, 0x21,
+    0x0a, 0x10, 0x0a, 0x08, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x02,
+    0x06, 0x24, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x01, 0x04, 0x12,
+    0x04, 0xa1, 0x02, 0x06, 0x0e, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06, 0x03, 0x00, 0x02,
+    0x01, 0x06, 0x12, 0x04, 0xa1, 0x02, 0x0f, 0x16, 0x0a, 0x11, 0x0a, 0x09, 0x04, 0x01, 0x03, 0x06,
+    0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x17, 0x1f, 0x0a, 0x11, 0x0a, 0x09, 0x04,
+    0x01, 0x03, 0x06, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa1, 0x02, 0x22, 0x23, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x12, 0x04, 0xa4, 0x02, 0x04, 0x1c, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa4, 0x02, 0x04, 0x0c, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa4, 0x02, 0x0d, 0x11,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa4, 0x02, 0x12,
+    0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa4, 0x02,
+    0x1a, 0x1b, 0x0a, 0xbb, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x03, 0x07, 0x12, 0x06, 0xaa, 0x02, 0x02,
+    0xae, 0x02, 0x03, 0x1a, 0xaa, 0x01, 0x20, 0x53, 0x65, 0x6e, 0x64, 0x73, 0x20, 0x61, 0x72, 0x62,
+    0x69, 0x74, 0x72, 0x61, 0x72, 0x79, 0x20, 0x62, 0x69, 0x6e, 0x61, 0x72, 0x79, 0x20, 0x64, 0x61,
+    0x74, 0x61, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74,
+    0x6f, 0x72, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x4d, 0x65,
+    0x73, 0x6f, 0x73, 0x0a, 0x20, 0x6e, 0x65, 0x69, 0x74, 0x68, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x74,
+    0x65, 0x72, 0x70, 0x72, 0x65, 0x74, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x64, 0x61, 0x74,
+    0x61, 0x20, 0x6e, 0x6f, 0x72, 0x20, 0x6d, 0x61, 0x6b, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x79, 0x20,
+    0x67, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x65, 0x65, 0x73, 0x20, 0x61, 0x62, 0x6f, 0x75, 0x74,
+    0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x64, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x74,
+    0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x03, 0x07, 0x01, 0x12, 0x04, 0xaa, 0x02, 0x0a, 0x11, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x12, 0x04, 0xab, 0x02, 0x04, 0x22, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x04, 0x12, 0x04, 0xab, 0x02, 0x04, 0x0c,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x06, 0x12, 0x04, 0xab, 0x02, 0x0d,
+    0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x01, 0x12, 0x04, 0xab, 0x02,
+    0x15, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x00, 0x03, 0x12, 0x04, 0xab,
+    0x02, 0x20, 0x21, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x07, 0x02, 0x01, 0x12, 0x04, 0xac,
+    0x02, 0x04, 0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0xac, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0xac, 0x02, 0x0d, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xac, 0x02, 0x18, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xac, 0x02, 0x26, 0x27, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x01, 0x03, 0x07, 0x02,
+    0x02, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07, 0x02,
+    0x02, 0x04, 0x12, 0x04, 0xad, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x07,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xad, 0x02, 0x0d, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03,
+    0x07, 0x02, 0x02, 0x01, 0x12, 0x04, 0xad, 0x02, 0x13, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x01,
+    0x03, 0x07, 0x02, 0x02, 0x03, 0x12, 0x04, 0xad, 0x02, 0x1a, 0x1b, 0x0a, 0xaf, 0x02, 0x0a, 0x04,
+    0x04, 0x01, 0x03, 0x08, 0x12, 0x06, 0xb6, 0x02, 0x02, 0xb8, 0x02, 0x03, 0x1a, 0x9e, 0x02, 0x20,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x61, 0x20, 0x73, 0x70, 0x65, 0x63, 0x69,
+    0x66, 0x69, 0x63, 0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x75,
+    0x72, 0x63, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x4d, 0x65, 0x73, 0x6f, 0x73, 0x27,
+    0x73, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x2e, 0x20, 0x49, 0x66, 0x0a,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x68,
+    0x61, 0x73, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74,
+    0x68, 0x69, 0x73, 0x2c, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x69,
+    0x6e, 0x67, 0x20, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x73, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62,
+    0x65, 0x0a, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x79, 0x6e, 0x63, 0x68, 0x72, 0x6f,
+    0x6e, 0x6f, 0x75, 0x73, 0x6c, 0x79, 0x20, 0x76, 0x69, 0x61, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4f,
+    0x46, 0x46, 0x45, 0x52, 0x53, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x28, 0x73, 0x29, 0x2e, 0x0a,
+    0x0a, 0x20, 0x4e, 0x4f, 0x54, 0x45, 0x3a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x75, 0x69, 0x6c,
+    0x74, 0x2d, 0x69, 0x6e, 0x20, 0x68, 0x69, 0x65, 0x72, 0x61, 0x72, 0x63, 0x68, 0x69, 0x63, 0x61,
+    0x6c, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x64, 0x6f, 0x65, 0x73,
+    0x6e, 0x27, 0x74, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74,
+    0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x68, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x73, 0x69, 0x6d, 0x70, 0x6c, 0x79,
+    0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x73, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x01, 0x03, 0x08, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x0a, 0x11, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x12, 0x04, 0xb7, 0x02, 0x04, 0x28, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb7, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb7, 0x02, 0x0d, 0x1a, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb7, 0x02, 0x1b, 0x23, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x01, 0x03, 0x08, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb7, 0x02, 0x26, 0x27,
+    0x0a, 0xea, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x28, 0x1a,
+    0xdb, 0x02, 0x20, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68,
+    0x6f, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x20, 0x4d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x20, 0x61, 0x73,
+    0x73, 0x69, 0x67, 0x6e, 0x73, 0x20, 0x61, 0x20, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72,
+    0x6b, 0x20, 0x69, 0x64, 0x0a, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x77,
+    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x73, 0x75, 0x62, 0x73, 0x63,
+    0x72, 0x69, 0x62, 0x65, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69,
+    0x72, 0x73, 0x74, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x2e, 0x20, 0x4f, 0x6e, 0x63, 0x65, 0x20, 0x61,
+    0x73, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x2c, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63,
+    0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x73, 0x65, 0x74,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x27, 0x66, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f, 0x72, 0x6b, 0x5f,
+    0x69, 0x64, 0x27, 0x20, 0x68, 0x65, 0x72, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x0a, 0x20, 0x46, 0x72, 0x61, 0x6d, 0x65, 0x77, 0x6f,
+    0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x28, 0x69, 0x6e, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x66,
+    0x75, 0x72, 0x74, 0x68, 0x65, 0x72, 0x20, 0x27, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62,
+    0x65, 0x27, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x29, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20,
+    0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x6d, 0x61, 0x73, 0x74,
+    0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x79, 0x20, 0x61,
+    0x20, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65,
+    0x63, 0x74, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x64, 0x69, 0x73, 0x63,
+    0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2c, 0x0a, 0x20, 0x66, 0x61, 0x69,
+    0x6c, 0x6f, 0x76, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x65, 0x74, 0x63, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc0, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x17, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x26, 0x27, 0x0a, 0xe0, 0x01, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x01, 0x12, 0x04, 0xc5, 0x02, 0x02, 0x19, 0x1a, 0xd1, 0x01, 0x20, 0x54, 0x79, 0x70, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2c, 0x20, 0x69, 0x6e,
+    0x64, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6f, 0x70,
+    0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x6c,
+    0x6f, 0x77, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x0a, 0x20, 0x70, 0x72,
+    0x65, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x79,
+    0x70, 0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x20, 0x53, 0x65, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74,
+    0x73, 0x20, 0x6f, 0x6e, 0x20, 0x60, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x3a, 0x3a, 0x54, 0x79, 0x70,
+    0x65, 0x60, 0x20, 0x61, 0x62, 0x6f, 0x76, 0x65, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x62, 0x65, 0x68, 0x69, 0x6e, 0x64,
+    0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x69, 0x6e,
+    0x67, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x04, 0xc5, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x06, 0x12, 0x04, 0xc5, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xc5, 0x02, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xc5, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02,
+    0x12, 0x04, 0xc7, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12,
+    0x04, 0xc7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x06, 0x12, 0x04,
+    0xc7, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc7,
+    0x02, 0x15, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc7, 0x02,
+    0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x06, 0x12, 0x04, 0xc8, 0x02, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x12, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc8, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x04, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x04, 0x04, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x04, 0x06, 0x12, 0x04, 0xc9, 0x02, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0xc9, 0x02, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03,
+    0x12, 0x04, 0xc9, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x04,
+    0xca, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x04, 0x12, 0x04, 0xca,
+    0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x06, 0x12, 0x04, 0xca, 0x02,
+    0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x04, 0xca, 0x02, 0x10,
+    0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x04, 0xca, 0x02, 0x17, 0x18,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x21, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x04, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x06, 0x06, 0x12, 0x04, 0xcb, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x06, 0x03, 0x12, 0x04, 0xcb, 0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x07, 0x12, 0x04, 0xcc, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07,
+    0x04, 0x12, 0x04, 0xcc, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x06,
+    0x12, 0x04, 0xcc, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12,
+    0x04, 0xcc, 0x02, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x04,
+    0xcc, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x04, 0xcd, 0x02,
+    0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x04, 0x12, 0x04, 0xcd, 0x02, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x06, 0x12, 0x04, 0xcd, 0x02, 0x0b, 0x14,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x04, 0xcd, 0x02, 0x15, 0x1e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x04, 0xcd, 0x02, 0x21, 0x22, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x04, 0xce, 0x02, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x09, 0x04, 0x12, 0x04, 0xce, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x09, 0x06, 0x12, 0x04, 0xce, 0x02, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x09, 0x01, 0x12, 0x04, 0xce, 0x02, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x09, 0x03, 0x12, 0x04, 0xce, 0x02, 0x1d, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a,
+    0x12, 0x04, 0xcf, 0x02, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x04, 0x12,
+    0x04, 0xcf, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x06, 0x12, 0x04,
+    0xcf, 0x02, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xcf,
+    0x02, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x04, 0xcf, 0x02,
+    0x1d, 0x1f,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -0,0 +1,203 @@
+use std::cmp;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use proto::mesos::{TaskID, TaskState, TaskStatus, TaskStatus_Reason};
+use proto::scheduler::Call_Reconcile_Task;
+use task_registry::TaskRegistry;
+
+/// Where the reconciler is in the algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Phase {
+    Idle,
+    Explicit,
+    Implicit,
+    Complete,
+}
+
+/// A reconciliation call the router should send on the reconciler's
+/// behalf.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Step {
+    Explicit(Vec<Call_Reconcile_Task>),
+    Implicit,
+}
+
+/// Drives the reconciliation algorithm described in the Mesos docs:
+/// explicitly reconcile every non-terminal task, retrying with
+/// exponential backoff until each one has received a status update,
+/// then finish with an implicit reconciliation.
+///
+/// `ProtobufCallbackRouter` restarts it on every SUBSCRIBED event, feeds
+/// it every UPDATE, and sends whatever `poll` asks for.  This version of
+/// Mesos answers for tasks it no longer knows with TASK_LOST; those
+/// tasks are handed to `Scheduler::reconciled` once the run completes.
+///
+/// Independently of runs, tasks reported lost outside of reconciliation
+/// are `suspect`: their agent may only be cut off and come back with
+/// them.  They are reconciled every `initial_backoff` until Mesos either
+/// confirms the loss or reports them in another state.
+pub struct Reconciler {
+    phase: Phase,
+    remaining: BTreeMap<String, Call_Reconcile_Task>,
+    lost: Vec<TaskID>,
+    suspects: BTreeMap<String, (Call_Reconcile_Task, Instant)>,
+    attempts: u32,
+    next_attempt: Option<Instant>,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for Reconciler {
+    fn default() -> Reconciler {
+        Reconciler::new(Duration::from_secs(5), Duration::from_secs(300))
+    }
+}
+
+impl Reconciler {
+    pub fn new(initial_backoff: Duration, max_backoff: Duration) -> Reconciler {
+        Reconciler {
+            phase: Phase::Idle,
+            remaining: BTreeMap::new(),
+            lost: vec![],
+            suspects: BTreeMap::new(),
+            attempts: 0,
+            next_attempt: None,
+            initial_backoff: initial_backoff,
+            max_backoff: max_backoff,
+        }
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.phase == Phase::Complete
+    }
+
+    /// Number of explicit reconciliation calls made in the current run.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Tasks still waiting for a status update.
+    pub fn remaining(&self) -> Vec<&TaskID> {
+        self.remaining.values().map(|t| t.get_task_id()).collect()
+    }
+
+    /// Begins a new run covering every non-terminal task in `registry`,
+    /// abandoning any run in progress.
+    pub fn start(&mut self, registry: &TaskRegistry, now: Instant) {
+        self.remaining.clear();
+        self.lost.clear();
+        for task in registry.non_terminal() {
+            let mut reconcile = Call_Reconcile_Task::new();
+            reconcile.set_task_id(task.task_id.clone());
+            if let Some(ref slave_id) = task.slave_id {
+                reconcile.set_slave_id(slave_id.clone());
+            }
+            self.remaining
+                .insert(task.task_id.get_value().to_string(), reconcile);
+        }
+        self.phase = Phase::Explicit;
+        self.attempts = 0;
+        self.next_attempt = Some(now);
+    }
+
+    /// Records that a status update arrived for a task.
+    pub fn observe(&mut self, status: &TaskStatus) {
+        let task_id = status.get_task_id();
+        if status.get_state() != TaskState::TASK_LOST ||
+           confirms_loss(status) {
+            self.suspects.remove(task_id.get_value());
+        }
+        if self.phase != Phase::Explicit {
+            return;
+        }
+        if self.remaining.remove(task_id.get_value()).is_some() &&
+           status.get_state() == TaskState::TASK_LOST {
+            self.lost.push(task_id.clone());
+        }
+    }
+
+    /// Starts confirming the loss of the task `status` reports lost.
+    pub fn suspect(&mut self, status: &TaskStatus, now: Instant) {
+        let mut reconcile = Call_Reconcile_Task::new();
+        reconcile.set_task_id(status.get_task_id().clone());
+        if status.has_slave_id() {
+            reconcile.set_slave_id(status.get_slave_id().clone());
+        }
+        self.suspects
+            .insert(status.get_task_id().get_value().to_string(),
+                    (reconcile, now));
+    }
+
+    /// Tasks whose loss is not confirmed yet.
+    pub fn suspects(&self) -> Vec<&TaskID> {
+        self.suspects.values().map(|&(ref t, _)| t.get_task_id()).collect()
+    }
+
+    /// Returns the suspect tasks to reconcile now.
+    pub fn poll_suspects(&mut self, now: Instant) -> Vec<Call_Reconcile_Task> {
+        let interval = self.initial_backoff;
+        self.suspects
+            .values_mut()
+            .filter(|&&mut (_, at)| at <= now)
+            .map(|&mut (ref task, ref mut at)| {
+                *at = now + interval;
+                task.clone()
+            })
+            .collect()
+    }
+
+    /// Returns the call to make now, if any.
+    pub fn poll(&mut self, now: Instant) -> Option<Step> {
+        if self.phase == Phase::Explicit && self.remaining.is_empty() {
+            self.phase = Phase::Implicit;
+            self.next_attempt = Some(now);
+        }
+
+        match self.next_attempt {
+            Some(at) if at <= now => {}
+            _ => return None,
+        }
+
+        let backoff = self.backoff();
+        self.next_attempt = Some(now + backoff);
+
+        match self.phase {
+            Phase::Explicit => {
+                self.attempts += 1;
+                Some(Step::Explicit(self.remaining.values().cloned().collect()))
+            }
+            Phase::Implicit => Some(Step::Implicit),
+            _ => None,
+        }
+    }
+
+    /// Marks the run finished once the implicit reconciliation has been
+    /// sent, returning the tasks Mesos reported as lost while explicitly
+    /// reconciling.  Answers to the implicit reconciliation arrive later
+    /// as ordinary updates.
+    pub fn complete(&mut self) -> Vec<TaskID> {
+        self.phase = Phase::Complete;
+        self.next_attempt = None;
+        self.lost.drain(..).collect()
+    }
+
+    fn backoff(&self) -> Duration {
+        let factor = 1u32 << cmp::min(self.attempts, 16);
+        let backoff = self.initial_backoff
+                          .checked_mul(factor)
+                          .unwrap_or(self.max_backoff);
+        cmp::min(backoff, self.max_backoff)
+    }
+}
+
+/// Returns true if `status` is the master confirming, in answer to
+/// reconciliation, that it no longer knows the task.
+pub fn confirms_loss(status: &TaskStatus) -> bool {
+    status.get_state() == TaskState::TASK_LOST &&
+    status.get_reason() == TaskStatus_Reason::REASON_RECONCILIATION
+}
@@ -0,0 +1,108 @@
+use proto::mesos::{Label, Offer, Offer_Operation, Resource,
+                   Resource_ReservationInfo};
+use resources::Resources;
+use util;
+
+/// Describes a dynamic reservation made by this framework: the role the
+/// resources are reserved for, the framework's principal, and labels
+/// that tell its reservations apart (e.g. which service they belong to).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reservation {
+    pub role: String,
+    pub principal: String,
+    pub labels: Vec<(String, String)>,
+}
+
+impl Reservation {
+    pub fn new(role: &str, principal: &str) -> Reservation {
+        Reservation {
+            role: role.to_string(),
+            principal: principal.to_string(),
+            labels: vec![],
+        }
+    }
+
+    pub fn label(mut self, key: &str, value: &str) -> Reservation {
+        self.labels.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn reservation_info(&self) -> Resource_ReservationInfo {
+        let pairs: Vec<(&str, &str)> = self.labels
+                                           .iter()
+                                           .map(|&(ref k, ref v)| {
+                                               (&k[..], &v[..])
+                                           })
+                                           .collect();
+        let mut info = Resource_ReservationInfo::new();
+        info.set_principal(self.principal.clone());
+        if !pairs.is_empty() {
+            info.set_labels(util::labels(&pairs));
+        }
+        info
+    }
+
+    /// Returns `resource` as it will look once reserved.
+    pub fn reserved(&self, resource: &Resource) -> Resource {
+        let mut reserved = resource.clone();
+        reserved.set_role(self.role.clone());
+        reserved.set_reservation(self.reservation_info());
+        reserved
+    }
+
+    /// Builds the RESERVE operation for unreserved `resources`.
+    pub fn reserve_operation(&self, resources: &[Resource]) -> Offer_Operation {
+        util::reserve_operation(resources.iter()
+                                         .map(|r| self.reserved(r))
+                                         .collect())
+    }
+
+    /// Builds the UNRESERVE operation for every resource in `offers`
+    /// that carries this reservation.  Persistent volumes are left out:
+    /// Mesos refuses to unreserve them, so they have to be destroyed
+    /// first, see `SchedulerClient::destroy_volumes`.
+    pub fn unreserve_operation(&self, offers: &[&Offer]) -> Offer_Operation {
+        util::unreserve_operation(self.find(offers)
+                                      .into_vec()
+                                      .into_iter()
+                                      .filter(|r| {
+                                          !(r.has_disk() &&
+                                            r.get_disk().has_persistence())
+                                      })
+                                      .collect())
+    }
+
+    /// Returns true if `resource` was reserved by this framework for this
+    /// reservation: same role, same principal, and carrying every label.
+    pub fn matches(&self, resource: &Resource) -> bool {
+        if resource.get_role() != self.role || !resource.has_reservation() {
+            return false;
+        }
+        let info = resource.get_reservation();
+        if info.get_principal() != self.principal {
+            return false;
+        }
+        let labels: &[Label] = info.get_labels().get_labels();
+        self.labels.iter().all(|&(ref key, ref value)| {
+            labels.iter()
+                  .any(|l| l.get_key() == key && l.get_value() == value)
+        })
+    }
+
+    /// The resources in `offers` that carry this reservation.
+    pub fn find(&self, offers: &[&Offer]) -> Resources {
+        Resources::from_vec(offers.iter()
+                                  .flat_map(|o| o.get_resources())
+                                  .filter(|r| self.matches(r))
+                                  .cloned()
+                                  .collect())
+    }
+
+    /// The offers that contain resources carrying this reservation.
+    pub fn offers<'a>(&self, offers: &[&'a Offer]) -> Vec<&'a Offer> {
+        offers.iter()
+              .filter(|o| o.get_resources().iter().any(|r| self.matches(r)))
+              .cloned()
+              .collect()
+    }
+}
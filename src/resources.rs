@@ -0,0 +1,685 @@
+use std::cmp;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use protobuf::RepeatedField;
+
+use proto::mesos::{Resource, Resource_DiskInfo_Source,
+                   Resource_DiskInfo_Source_Mount,
+                   Resource_DiskInfo_Source_Path,
+                   Resource_DiskInfo_Source_Type, Value_Range, Value_Ranges,
+                   Value_Set, Value_Type};
+
+/// Scalars are compared and combined in Mesos' fixed point format,
+/// which keeps three decimal digits.
+fn to_fixed(value: f64) -> i64 {
+    (value * 1000f64).round() as i64
+}
+
+fn from_fixed(value: i64) -> f64 {
+    value as f64 / 1000f64
+}
+
+/// Rounds a scalar to the precision Mesos keeps.
+pub fn round_scalar(value: f64) -> f64 {
+    from_fixed(to_fixed(value))
+}
+
+type Intervals = Vec<(u64, u64)>;
+
+fn intervals(ranges: &Value_Ranges) -> Intervals {
+    merge(ranges.get_range()
+                .iter()
+                .map(|r| (r.get_begin(), r.get_end()))
+                .collect())
+}
+
+// Sorts intervals and merges the ones that overlap or touch.
+fn merge(mut raw: Intervals) -> Intervals {
+    raw.retain(|&(begin, end)| begin <= end);
+    raw.sort();
+    let mut merged: Intervals = vec![];
+    for (begin, end) in raw {
+        if let Some(last) = merged.last_mut() {
+            if begin <= last.1.saturating_add(1) {
+                last.1 = cmp::max(last.1, end);
+                continue;
+            }
+        }
+        merged.push((begin, end));
+    }
+    merged
+}
+
+/// The sorted, merged `(begin, end)` intervals of a RANGES resource.
+pub fn range_intervals(resource: &Resource) -> Vec<(u64, u64)> {
+    intervals(resource.get_ranges())
+}
+
+fn to_ranges(intervals: &Intervals) -> Value_Ranges {
+    let mut ranges = Value_Ranges::new();
+    for &(begin, end) in intervals {
+        let mut range = Value_Range::new();
+        range.set_begin(begin);
+        range.set_end(end);
+        ranges.mut_range().push(range);
+    }
+    ranges
+}
+
+fn union(a: &Intervals, b: &Intervals) -> Intervals {
+    merge(a.iter().chain(b.iter()).cloned().collect())
+}
+
+fn difference(a: &Intervals, b: &Intervals) -> Intervals {
+    let mut out = vec![];
+    for &(begin, end) in a {
+        let mut pieces = vec![(begin, end)];
+        for &(b_begin, b_end) in b {
+            let mut next = vec![];
+            for (p_begin, p_end) in pieces {
+                if b_end < p_begin || b_begin > p_end {
+                    next.push((p_begin, p_end));
+                    continue;
+                }
+                if b_begin > p_begin {
+                    next.push((p_begin, b_begin - 1));
+                }
+                if b_end < p_end {
+                    next.push((b_end + 1, p_end));
+                }
+            }
+            pieces = next;
+        }
+        out.extend(pieces);
+    }
+    out
+}
+
+fn intersection(a: &Intervals, b: &Intervals) -> Intervals {
+    difference(a, &difference(a, b))
+}
+
+fn items(set: &Value_Set) -> Vec<String> {
+    let mut items = set.get_item().to_vec();
+    items.sort();
+    items.dedup();
+    items
+}
+
+fn to_set(items: Vec<String>) -> Value_Set {
+    let mut set = Value_Set::new();
+    set.set_item(RepeatedField::from_vec(items));
+    set
+}
+
+/// Two resources are of the same kind, and can be combined, when they
+/// agree on everything but their value.
+pub fn same_kind(a: &Resource, b: &Resource) -> bool {
+    a.get_name() == b.get_name() &&
+    a.get_field_type() == b.get_field_type() &&
+    a.get_role() == b.get_role() &&
+    a.has_reservation() == b.has_reservation() &&
+    a.get_reservation() == b.get_reservation() &&
+    a.has_disk() == b.has_disk() &&
+    a.get_disk() == b.get_disk() &&
+    a.has_revocable() == b.has_revocable()
+}
+
+/// The persistence id of `resource` if it is a persistent volume.
+pub fn persistence_id(resource: &Resource) -> Option<&str> {
+    let disk = resource.get_disk();
+    if disk.has_persistence() {
+        Some(disk.get_persistence().get_id())
+    } else {
+        None
+    }
+}
+
+/// Where a disk resource comes from: the agent's root work directory,
+/// or an extra PATH or MOUNT disk with the given root.  PATH disks can
+/// be shared out like root disk; a MOUNT disk is a dedicated volume
+/// that has to be used whole.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DiskSource {
+    Root,
+    Path(String),
+    Mount(String),
+}
+
+impl DiskSource {
+    pub fn of(resource: &Resource) -> DiskSource {
+        let disk = resource.get_disk();
+        if !disk.has_source() {
+            return DiskSource::Root;
+        }
+        let source = disk.get_source();
+        match source.get_field_type() {
+            Resource_DiskInfo_Source_Type::PATH => {
+                DiskSource::Path(source.get_path().get_root().to_string())
+            }
+            Resource_DiskInfo_Source_Type::MOUNT => {
+                DiskSource::Mount(source.get_mount().get_root().to_string())
+            }
+        }
+    }
+
+    /// The `DiskInfo.source` for this source; None for root disk.
+    pub fn source_info(&self) -> Option<Resource_DiskInfo_Source> {
+        let mut source = Resource_DiskInfo_Source::new();
+        match *self {
+            DiskSource::Root => return None,
+            DiskSource::Path(ref root) => {
+                let mut path = Resource_DiskInfo_Source_Path::new();
+                path.set_root(root.clone());
+                source.set_field_type(Resource_DiskInfo_Source_Type::PATH);
+                source.set_path(path);
+            }
+            DiskSource::Mount(ref root) => {
+                let mut mount = Resource_DiskInfo_Source_Mount::new();
+                mount.set_root(root.clone());
+                source.set_field_type(Resource_DiskInfo_Source_Type::MOUNT);
+                source.set_mount(mount);
+            }
+        }
+        Some(source)
+    }
+
+    pub fn is_mount(&self) -> bool {
+        match *self {
+            DiskSource::Mount(_) => true,
+            _ => false,
+        }
+    }
+}
+
+// Persistent volumes and MOUNT disks cannot be partially consumed.
+fn is_indivisible(resource: &Resource) -> bool {
+    persistence_id(resource).is_some() || DiskSource::of(resource).is_mount()
+}
+
+/// Returns true if `resource` is in the default `*` role, i.e. not
+/// reserved for any role.
+pub fn is_unreserved(resource: &Resource) -> bool {
+    resource.get_role() == "*" || resource.get_role().is_empty()
+}
+
+fn is_empty(resource: &Resource) -> bool {
+    match resource.get_field_type() {
+        Value_Type::SCALAR => to_fixed(resource.get_scalar().get_value()) <= 0,
+        Value_Type::RANGES => intervals(resource.get_ranges()).is_empty(),
+        Value_Type::SET => resource.get_set().get_item().is_empty(),
+        Value_Type::TEXT => true,
+    }
+}
+
+// Returns `resource` in canonical form: rounded scalar, merged and
+// sorted ranges, sorted and deduplicated set items.
+fn normalized(resource: &Resource) -> Resource {
+    let mut out = resource.clone();
+    match resource.get_field_type() {
+        Value_Type::SCALAR => {
+            let value = round_scalar(resource.get_scalar().get_value());
+            out.mut_scalar().set_value(value);
+        }
+        Value_Type::RANGES => {
+            out.set_ranges(to_ranges(&intervals(resource.get_ranges())));
+        }
+        Value_Type::SET => out.set_set(to_set(items(resource.get_set()))),
+        Value_Type::TEXT => {}
+    }
+    out
+}
+
+// Combines `b` into `a`, which must be of the same kind.
+fn combine(a: &mut Resource, b: &Resource) {
+    match a.get_field_type() {
+        Value_Type::SCALAR => {
+            let sum = to_fixed(a.get_scalar().get_value()) +
+                      to_fixed(b.get_scalar().get_value());
+            a.mut_scalar().set_value(from_fixed(sum));
+        }
+        Value_Type::RANGES => {
+            let sum = union(&intervals(a.get_ranges()),
+                            &intervals(b.get_ranges()));
+            a.set_ranges(to_ranges(&sum));
+        }
+        Value_Type::SET => {
+            let mut sum = items(a.get_set());
+            sum.extend(items(b.get_set()));
+            sum.sort();
+            sum.dedup();
+            a.set_set(to_set(sum));
+        }
+        Value_Type::TEXT => {}
+    }
+}
+
+// Removes `b` from `a`, which must be of the same kind.
+fn remove(a: &mut Resource, b: &Resource) {
+    match a.get_field_type() {
+        Value_Type::SCALAR => {
+            let left = to_fixed(a.get_scalar().get_value()) -
+                       to_fixed(b.get_scalar().get_value());
+            a.mut_scalar().set_value(from_fixed(cmp::max(left, 0)));
+        }
+        Value_Type::RANGES => {
+            let left = difference(&intervals(a.get_ranges()),
+                                  &intervals(b.get_ranges()));
+            a.set_ranges(to_ranges(&left));
+        }
+        Value_Type::SET => {
+            let drop = items(b.get_set());
+            let left = items(a.get_set())
+                           .into_iter()
+                           .filter(|i| !drop.contains(i))
+                           .collect();
+            a.set_set(to_set(left));
+        }
+        Value_Type::TEXT => {}
+    }
+}
+
+// Returns true if `a` holds at least `b`, which must be of the same kind.
+fn covers(a: &Resource, b: &Resource) -> bool {
+    match a.get_field_type() {
+        Value_Type::SCALAR => {
+            to_fixed(a.get_scalar().get_value()) >=
+            to_fixed(b.get_scalar().get_value())
+        }
+        Value_Type::RANGES => {
+            difference(&intervals(b.get_ranges()), &intervals(a.get_ranges()))
+                .is_empty()
+        }
+        Value_Type::SET => {
+            let have = items(a.get_set());
+            items(b.get_set()).iter().all(|i| have.contains(i))
+        }
+        Value_Type::TEXT => false,
+    }
+}
+
+/// A set of resources kept in canonical form: one entry per kind of
+/// resource (name, type, role, reservation, disk and revocability),
+/// with SCALAR values in Mesos' fixed point precision, RANGES merged
+/// and SET items deduplicated.
+///
+/// RANGES and SETs add up as sets, not multisets: a port or item in
+/// both operands is held once, so `(a + b) - b == a` only holds if the
+/// ranges and set items of `a` and `b` are disjoint, as those of
+/// different offers or tasks are.  Subtraction ignores what is not
+/// there and never goes below empty; `checked_sub` refuses instead.
+#[derive(Clone, Default)]
+pub struct Resources {
+    resources: Vec<Resource>,
+}
+
+impl Resources {
+    pub fn new() -> Resources {
+        Resources { resources: vec![] }
+    }
+
+    pub fn from_vec(resources: Vec<Resource>) -> Resources {
+        let mut out = Resources::new();
+        for resource in resources.iter() {
+            out.add_resource(resource);
+        }
+        out
+    }
+
+    pub fn into_vec(self) -> Vec<Resource> {
+        self.resources
+    }
+
+    pub fn as_slice(&self) -> &[Resource] {
+        &self.resources
+    }
+
+    pub fn iter<'a>(&'a self) -> ::std::slice::Iter<'a, Resource> {
+        self.resources.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    pub fn add_resource(&mut self, resource: &Resource) {
+        if is_empty(resource) {
+            return;
+        }
+        match self.resources.iter_mut().find(|r| same_kind(r, resource)) {
+            Some(existing) => {
+                combine(existing, resource);
+                return;
+            }
+            None => {}
+        }
+        self.resources.push(normalized(resource));
+    }
+
+    /// Removes `resource`, or as much of it as is here.
+    pub fn subtract_resource(&mut self, resource: &Resource) {
+        if let Some(existing) = self.resources
+                                    .iter_mut()
+                                    .find(|r| same_kind(r, resource)) {
+            remove(existing, resource);
+        }
+        self.resources.retain(|r| !is_empty(r));
+    }
+
+    /// These resources less `other`, or None if `other` is not entirely
+    /// contained in them.
+    pub fn checked_sub(&self, other: &Resources) -> Option<Resources> {
+        if self.contains(other) {
+            Some(self.clone() - other)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if every resource in `other` is present here, in
+    /// the same kind and at least the same amount.
+    pub fn contains(&self, other: &Resources) -> bool {
+        other.iter().all(|wanted| {
+            self.resources
+                .iter()
+                .find(|r| same_kind(r, wanted))
+                .map_or(false, |r| covers(r, wanted))
+        })
+    }
+
+    /// Moves every resource to `role`, dropping dynamic reservations, so
+    /// amounts can be compared regardless of how they are reserved.
+    pub fn flatten(&self, role: &str) -> Resources {
+        let mut out = Resources::new();
+        for resource in self.resources.iter() {
+            let mut flat = resource.clone();
+            flat.set_role(role.to_string());
+            flat.clear_reservation();
+            out.add_resource(&flat);
+        }
+        out
+    }
+
+    pub fn filter<F>(&self, predicate: F) -> Resources
+        where F: Fn(&Resource) -> bool
+    {
+        Resources {
+            resources: self.resources
+                           .iter()
+                           .filter(|r| predicate(r))
+                           .cloned()
+                           .collect(),
+        }
+    }
+
+    pub fn filter_by_role(&self, role: &str) -> Resources {
+        self.filter(|r| r.get_role() == role)
+    }
+
+    /// The roles these resources are in, in order.
+    pub fn roles(&self) -> Vec<String> {
+        let mut roles: Vec<String> = self.resources
+                                         .iter()
+                                         .map(|r| r.get_role().to_string())
+                                         .collect();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+
+    /// These resources split up by role, in role order.
+    pub fn by_role(&self) -> Vec<(String, Resources)> {
+        self.roles()
+            .into_iter()
+            .map(|role| {
+                let resources = self.filter_by_role(&role);
+                (role, resources)
+            })
+            .collect()
+    }
+
+    pub fn filter_by_name(&self, name: &str) -> Resources {
+        self.filter(|r| r.get_name() == name)
+    }
+
+    /// Oversubscribed resources, which the agent may take back at any
+    /// time by killing the tasks using them.
+    pub fn revocable(&self) -> Resources {
+        self.filter(|r| r.has_revocable())
+    }
+
+    pub fn non_revocable(&self) -> Resources {
+        self.filter(|r| !r.has_revocable())
+    }
+
+    /// The total of the scalar resource `name` across all roles.
+    pub fn scalar(&self, name: &str) -> f64 {
+        let total = self.resources
+                        .iter()
+                        .filter(|r| r.get_name() == name)
+                        .filter(|r| r.get_field_type() == Value_Type::SCALAR)
+                        .map(|r| to_fixed(r.get_scalar().get_value()))
+                        .fold(0, |acc, v| acc + v);
+        from_fixed(total)
+    }
+
+    /// The total `disk` per disk source, persistent volumes included,
+    /// ordered by source.
+    pub fn disk_capacity(&self) -> Vec<(DiskSource, f64)> {
+        let mut capacity: Vec<(DiskSource, i64)> = vec![];
+        for disk in self.resources.iter().filter(|r| r.get_name() == "disk") {
+            let source = DiskSource::of(disk);
+            let amount = to_fixed(disk.get_scalar().get_value());
+            match capacity.iter().position(|&(ref s, _)| *s == source) {
+                Some(idx) => capacity[idx].1 += amount,
+                None => capacity.push((source, amount)),
+            }
+        }
+        capacity.sort();
+        capacity.into_iter()
+                .map(|(source, amount)| (source, from_fixed(amount)))
+                .collect()
+    }
+
+    /// Carves `wanted` out of these resources by name and type.  A wanted
+    /// resource in the `*` role takes from any role, reserved resources
+    /// first; one in another role only from that role.  Reservations in
+    /// `wanted` are ignored.  The returned pieces keep the role,
+    /// reservation and other metadata of the resources they were taken
+    /// from, so they can be used to launch a task.  Persistent volumes
+    /// are only matched by a wanted resource with the same persistence
+    /// id, and a wanted disk with a source only by disk from that
+    /// source.  Persistent volumes and MOUNT disks are taken whole, and
+    /// a disk request is met by a single MOUNT disk only if the other
+    /// disk cannot cover it.
+    /// Revocable and regular resources are taken alike, so `self`
+    /// should hold only one or the other of each resource; a task that
+    /// mixes them is rejected.  Returns None if not everything could be
+    /// found.
+    pub fn find(&self, wanted: &Resources) -> Option<Resources> {
+        let mut left = self.clone();
+        let mut found = Resources::new();
+        for want in wanted.iter() {
+            let pieces = match left.take(want) {
+                Some(pieces) => pieces,
+                None => return None,
+            };
+            for piece in pieces.iter() {
+                left.subtract_resource(piece);
+                found.add_resource(piece);
+            }
+        }
+        Some(found)
+    }
+
+    fn take(&self, want: &Resource) -> Option<Vec<Resource>> {
+        let mut candidates: Vec<&Resource> =
+            self.resources
+                .iter()
+                .filter(|r| {
+                    r.get_name() == want.get_name() &&
+                    r.get_field_type() == want.get_field_type() &&
+                    persistence_id(r) == persistence_id(want) &&
+                    (is_unreserved(want) || r.get_role() == want.get_role()) &&
+                    (!want.get_disk().has_source() ||
+                     DiskSource::of(r) == DiskSource::of(want))
+                })
+                .collect();
+        // whole MOUNT disks are the last resort for unspecific requests,
+        // and resources reserved for the framework's roles are used
+        // before shared ones
+        candidates.sort_by_key(|r| {
+            (DiskSource::of(r).is_mount(), is_unreserved(r))
+        });
+        let mut pieces = vec![];
+        match want.get_field_type() {
+            Value_Type::SCALAR => {
+                let size = |r: &Resource| to_fixed(r.get_scalar().get_value());
+                let mut needed = size(want);
+                // a disk request is served either by divisible disk or by
+                // the smallest MOUNT disk that is big enough, never both
+                let (mounts, divisible): (Vec<&Resource>, Vec<&Resource>) =
+                    candidates.into_iter()
+                              .partition(|r| DiskSource::of(r).is_mount());
+                let total = divisible.iter().fold(0, |acc, r| acc + size(r));
+                if total < needed {
+                    let mount = mounts.into_iter()
+                                      .filter(|r| size(r) >= needed)
+                                      .min_by_key(|r| size(r));
+                    return mount.map(|r| vec![r.clone()]);
+                }
+                for have in divisible {
+                    if needed <= 0 {
+                        break;
+                    }
+                    let taken = if is_indivisible(have) {
+                        size(have)
+                    } else {
+                        cmp::min(needed, size(have))
+                    };
+                    let mut piece = have.clone();
+                    piece.mut_scalar().set_value(from_fixed(taken));
+                    pieces.push(piece);
+                    needed -= taken;
+                }
+            }
+            Value_Type::RANGES => {
+                let mut needed = intervals(want.get_ranges());
+                for have in candidates {
+                    let overlap = intersection(&needed,
+                                               &intervals(have.get_ranges()));
+                    if overlap.is_empty() {
+                        continue;
+                    }
+                    needed = difference(&needed, &overlap);
+                    let mut piece = have.clone();
+                    piece.set_ranges(to_ranges(&overlap));
+                    pieces.push(piece);
+                }
+                if !needed.is_empty() {
+                    return None;
+                }
+            }
+            Value_Type::SET => {
+                let mut needed = items(want.get_set());
+                for have in candidates {
+                    let available = items(have.get_set());
+                    let (overlap, rest): (Vec<String>, Vec<String>) =
+                        needed.into_iter()
+                              .partition(|i| available.contains(i));
+                    needed = rest;
+                    if overlap.is_empty() {
+                        continue;
+                    }
+                    let mut piece = have.clone();
+                    piece.set_set(to_set(overlap));
+                    pieces.push(piece);
+                }
+                if !needed.is_empty() {
+                    return None;
+                }
+            }
+            Value_Type::TEXT => return None,
+        }
+        Some(pieces)
+    }
+}
+
+impl From<Vec<Resource>> for Resources {
+    fn from(resources: Vec<Resource>) -> Resources {
+        Resources::from_vec(resources)
+    }
+}
+
+impl From<Resources> for Vec<Resource> {
+    fn from(resources: Resources) -> Vec<Resource> {
+        resources.into_vec()
+    }
+}
+
+impl PartialEq for Resources {
+    fn eq(&self, other: &Resources) -> bool {
+        self.contains(other) && other.contains(self)
+    }
+}
+
+impl fmt::Debug for Resources {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.resources.iter()).finish()
+    }
+}
+
+impl<'a> AddAssign<&'a Resources> for Resources {
+    fn add_assign(&mut self, other: &'a Resources) {
+        for resource in other.iter() {
+            self.add_resource(resource);
+        }
+    }
+}
+
+impl<'a> SubAssign<&'a Resources> for Resources {
+    fn sub_assign(&mut self, other: &'a Resources) {
+        for resource in other.iter() {
+            self.subtract_resource(resource);
+        }
+    }
+}
+
+impl<'a> Add<&'a Resources> for Resources {
+    type Output = Resources;
+
+    fn add(mut self, other: &'a Resources) -> Resources {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Sub<&'a Resources> for Resources {
+    type Output = Resources;
+
+    fn sub(mut self, other: &'a Resources) -> Resources {
+        self -= other;
+        self
+    }
+}
+
+impl Add for Resources {
+    type Output = Resources;
+
+    fn add(self, other: Resources) -> Resources {
+        self + &other
+    }
+}
+
+impl Sub for Resources {
+    type Output = Resources;
+
+    fn sub(self, other: Resources) -> Resources {
+        self - &other
+    }
+}
@@ -1,3 +1,4 @@
+use kill_tracker::KillProgress;
 use scheduler_client::SchedulerClient;
 use proto::*;
 
@@ -12,6 +13,9 @@ pub trait Scheduler {
                       inverse_offers: Vec<&InverseOffer>);
     fn rescind(&mut self, client: &SchedulerClient, offer_id: &OfferID);
     fn update(&mut self, client: &SchedulerClient, status: &TaskStatus);
+    /// Called with a framework message from an executor.  Responses to
+    /// `SchedulerClient::call_executor` go to their `Reply` instead;
+    /// other typed messages are read with `Envelope::from_bytes`.
     fn message(&mut self,
                client: &SchedulerClient,
                slave_id: &SlaveID,
@@ -25,6 +29,31 @@ pub trait Scheduler {
     fn error(&mut self, client: &SchedulerClient, message: String);
     fn heartbeat(&mut self, client: &SchedulerClient);
     fn disconnected(&mut self);
+
+    /// Called when a reconciliation run started by (re)subscribing has
+    /// completed, with the tasks Mesos reported as lost along the way.
+    /// This happens as soon as the final implicit reconciliation has
+    /// been sent; its answers only arrive afterwards, through `update`.
+    fn reconciled(&mut self, _client: &SchedulerClient, _lost: Vec<&TaskID>) {
+    }
+
+    /// Called after `update` when a task killed with
+    /// `SchedulerClient::kill_gracefully` starts shutting down or has
+    /// terminated.
+    fn kill_updated(&mut self,
+                    _client: &SchedulerClient,
+                    _task_id: &TaskID,
+                    _progress: &KillProgress) {
+    }
+
+    /// Called after `update` when `status` reports the task's health
+    /// check turning healthy or unhealthy.  What else happens to
+    /// unhealthy tasks is up to the client's `HealthMonitor`.
+    fn task_health_changed(&mut self,
+                           _client: &SchedulerClient,
+                           _status: &TaskStatus,
+                           _healthy: bool) {
+    }
 }
 
 #[derive(Clone)]
@@ -1,16 +1,32 @@
+use std::io;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use hyper;
 use hyper::Client;
 use hyper::client::response::Response;
 use protobuf::{self, Message};
 
-use proto::scheduler::{Call, Call_Accept, Call_Acknowledge, Call_Decline,
-                       Call_Kill, Call_Message, Call_Reconcile,
-                       Call_Reconcile_Task, Call_Request, Call_Shutdown,
-                       Call_Subscribe, Call_Type};
-use proto::mesos::{ExecutorID, Filters, FrameworkID, FrameworkInfo, OfferID,
-                   Offer_Operation, Request, SlaveID, TaskID, TaskInfo};
+use proto::scheduler::{Call, Call_Accept, Call_AcceptInverseOffers,
+                       Call_Acknowledge, Call_Decline,
+                       Call_DeclineInverseOffers, Call_Kill, Call_Message,
+                       Call_Reconcile, Call_Reconcile_Task, Call_Request,
+                       Call_Shutdown, Call_Subscribe, Call_Type};
+use proto::mesos::{ExecutorID, Filters, FrameworkID, FrameworkInfo,
+                   InverseOffer, KillPolicy, Offer, OfferID, Offer_Operation,
+                   Offer_Operation_Type, Request, Resource, SlaveID, TaskID,
+                   TaskInfo};
+use filters::FilterPolicy;
+use health_check::HealthMonitor;
+use kill_tracker::KillTracker;
+use maintenance::Maintenance;
+use message_channel::{Codec, Envelope, MessageChannel, MessageError, Reply};
+use offer_pool::OfferPool;
+use reconciler::Reconciler;
+use reservation::Reservation;
+use task_builder::{self, TaskError};
+use task_queue::{TaskQueue, TaskSpec};
+use task_registry::TaskRegistry;
 use util;
 
 pub struct SchedulerClient {
@@ -18,6 +34,15 @@ pub struct SchedulerClient {
     pub framework_id: Arc<Mutex<Option<FrameworkID>>>,
     pub stream_id: String,
     pub client: Client,
+    pub registry: Arc<Mutex<TaskRegistry>>,
+    pub reconciler: Arc<Mutex<Reconciler>>,
+    pub offer_pool: Arc<Mutex<OfferPool>>,
+    pub task_queue: Arc<Mutex<TaskQueue>>,
+    pub health: Arc<Mutex<HealthMonitor>>,
+    pub kills: Arc<Mutex<KillTracker>>,
+    pub messages: Arc<Mutex<MessageChannel>>,
+    pub maintenance: Arc<Mutex<Maintenance>>,
+    pub filter_policy: Arc<Mutex<FilterPolicy>>,
 }
 
 impl Clone for SchedulerClient {
@@ -27,6 +52,15 @@ impl Clone for SchedulerClient {
             framework_id: Arc::new(Mutex::new(self.get_framework_id())),
             stream_id: self.stream_id.clone(),
             client: Client::new(),
+            registry: self.registry.clone(),
+            reconciler: self.reconciler.clone(),
+            offer_pool: self.offer_pool.clone(),
+            task_queue: self.task_queue.clone(),
+            health: self.health.clone(),
+            kills: self.kills.clone(),
+            messages: self.messages.clone(),
+            maintenance: self.maintenance.clone(),
+            filter_policy: self.filter_policy.clone(),
         }
     }
 }
@@ -39,6 +73,15 @@ impl SchedulerClient {
             framework_id: Arc::new(Mutex::new(framework_id)),
             stream_id: "".to_string(),
             client: Client::new(),
+            registry: Arc::new(Mutex::new(TaskRegistry::new())),
+            reconciler: Arc::new(Mutex::new(Reconciler::default())),
+            offer_pool: Arc::new(Mutex::new(OfferPool::default())),
+            task_queue: Arc::new(Mutex::new(TaskQueue::new())),
+            health: Arc::new(Mutex::new(HealthMonitor::default())),
+            kills: Arc::new(Mutex::new(KillTracker::default())),
+            messages: Arc::new(Mutex::new(MessageChannel::default())),
+            maintenance: Arc::new(Mutex::new(Maintenance::default())),
+            filter_policy: Arc::new(Mutex::new(FilterPolicy::default())),
         }
     }
 
@@ -88,19 +131,203 @@ impl SchedulerClient {
 
     pub fn launch(&self,
                   offer_ids: Vec<OfferID>,
-                  tasks: Vec<TaskInfo>,
+                  mut tasks: Vec<TaskInfo>,
                   filters: Option<Filters>)
                   -> hyper::Result<Response> {
+        if let Some(framework_id) = self.get_framework_id() {
+            for task in tasks.iter_mut().filter(|t| t.has_executor()) {
+                let executor = task.mut_executor();
+                if !executor.has_framework_id() {
+                    executor.set_framework_id(framework_id.clone());
+                }
+            }
+        }
         let operation = util::launch_operation(tasks);
         self.accept(offer_ids, vec![operation], filters)
     }
 
+    /// Queues a task to be launched on a future offer, reviving offers
+    /// if the queue had suppressed them or they were recently declined
+    /// for long.
+    pub fn enqueue(&self, spec: TaskSpec) -> hyper::Result<()> {
+        let suppressed = self.task_queue.lock().unwrap().push(spec);
+        let revive = suppressed ||
+                     self.filter_policy
+                         .lock()
+                         .unwrap()
+                         .needs_revive(Instant::now());
+        if revive {
+            self.revive()?;
+        }
+        Ok(())
+    }
+
+    /// Launches queued tasks on `offers` and suppresses offers once the
+    /// queue is empty.  Offers nothing was placed on are left alone:
+    /// those held in the `OfferPool` stay there until they expire or are
+    /// taken, any others are the caller's to decline.  Nothing is placed
+    /// on agents being drained for maintenance.  The tasks of a launch
+    /// that fails to be sent are queued again, while those reusing the
+    /// id of a live task are dropped; either way the launch's offers are
+    /// declined (see `decline_offers`).  The first error is returned once
+    /// the rest are done.
+    pub fn launch_queued(&self, offers: Vec<&Offer>) -> hyper::Result<()> {
+        let usable: Vec<&Offer> = {
+            let maintenance = self.maintenance.lock().unwrap();
+            offers.iter()
+                  .cloned()
+                  .filter(|o| !maintenance.is_draining(o.get_slave_id()))
+                  .collect()
+        };
+        let launches = {
+            let registry = self.registry.lock().unwrap();
+            let mut task_queue = self.task_queue.lock().unwrap();
+            // tasks reusing the id of a live task could never launch
+            task_queue.remove_pending(|spec| {
+                spec.task_id
+                    .as_ref()
+                    .and_then(|id| registry.get(id))
+                    .map_or(false, |t| !t.is_terminal())
+            });
+            task_queue.match_offers(usable)
+        };
+
+        let mut failed = vec![];
+        let mut error = None;
+        for launch in launches {
+            let slave_id = launch.tasks[0].get_slave_id().clone();
+            let task_ids: Vec<TaskID> =
+                launch.tasks.iter().map(|t| t.get_task_id().clone()).collect();
+            let offer_ids = launch.offer_ids;
+            let unique = task_builder::validate_unique(&launch.tasks,
+                                                       &self.registry
+                                                            .lock()
+                                                            .unwrap());
+            let launched = match unique {
+                Ok(()) => {
+                    let sent = self.launch(offer_ids.clone(),
+                                           launch.tasks,
+                                           None);
+                    if sent.is_err() {
+                        self.requeue(&task_ids);
+                    }
+                    sent.map(|_| ())
+                }
+                Err(e) => {
+                    self.drop_queued(&task_ids);
+                    Err(invalid_task(e))
+                }
+            };
+            match launched {
+                Ok(()) => self.filter_policy.lock().unwrap().used(&slave_id),
+                Err(e) => {
+                    failed.extend(offers.iter()
+                                        .cloned()
+                                        .filter(|o| {
+                                            offer_ids.contains(o.get_id())
+                                        }));
+                    error = error.or(Some(e));
+                }
+            }
+        }
+        if let Err(e) = self.decline_offers(failed) {
+            error = error.or(Some(e));
+        }
+        if let Some(e) = error {
+            return Err(e);
+        }
+        if self.task_queue.lock().unwrap().should_suppress() {
+            self.suppress()?;
+        }
+        Ok(())
+    }
+
+    /// Puts the tasks of a launch that failed back on the queue.
+    fn requeue(&self, task_ids: &[TaskID]) {
+        let mut task_queue = self.task_queue.lock().unwrap();
+        for task_id in task_ids {
+            if let Some(spec) = task_queue.forget(task_id) {
+                task_queue.push(spec);
+            }
+        }
+    }
+
+    /// Gives up on the tasks of a launch that can never succeed.
+    fn drop_queued(&self, task_ids: &[TaskID]) {
+        let mut task_queue = self.task_queue.lock().unwrap();
+        for task_id in task_ids {
+            task_queue.forget(task_id);
+        }
+    }
+
+    /// Dynamically reserves `resources` from the given offers for
+    /// `reservation`.
+    pub fn reserve(&self,
+                   offer_ids: Vec<OfferID>,
+                   reservation: &Reservation,
+                   resources: Vec<Resource>,
+                   filters: Option<Filters>)
+                   -> hyper::Result<Response> {
+        let operation = reservation.reserve_operation(&resources);
+        self.accept(offer_ids, vec![operation], filters)
+    }
+
+    /// Releases the reserved `resources` offered in the given offers.
+    pub fn unreserve(&self,
+                     offer_ids: Vec<OfferID>,
+                     resources: Vec<Resource>,
+                     filters: Option<Filters>)
+                     -> hyper::Result<Response> {
+        let operation = util::unreserve_operation(resources);
+        self.accept(offer_ids, vec![operation], filters)
+    }
+
+    /// Creates the persistent `volumes` (see `PersistentVolume::create`)
+    /// on reserved disk from the given offers.
+    pub fn create_volumes(&self,
+                          offer_ids: Vec<OfferID>,
+                          volumes: Vec<Resource>,
+                          filters: Option<Filters>)
+                          -> hyper::Result<Response> {
+        let operation = util::create_operation(volumes);
+        self.accept(offer_ids, vec![operation], filters)
+    }
+
+    /// Destroys the persistent `volumes` offered in the given offers,
+    /// deleting their data.  The disk stays reserved.
+    pub fn destroy_volumes(&self,
+                           offer_ids: Vec<OfferID>,
+                           volumes: Vec<Resource>,
+                           filters: Option<Filters>)
+                           -> hyper::Result<Response> {
+        let operation = util::destroy_operation(volumes);
+        self.accept(offer_ids, vec![operation], filters)
+    }
+
+    /// Applies `operations` to the given offers.  Tasks launched reusing
+    /// the id of another task being launched or still running fail with
+    /// `TaskError::DuplicateTaskId` before anything is sent, leaving the
+    /// offers outstanding.
     pub fn accept(&self,
                   offer_ids: Vec<OfferID>,
                   operations: Vec<Offer_Operation>,
                   filters: Option<Filters>)
                   -> hyper::Result<Response> {
 
+        let launched: Vec<TaskInfo> =
+            operations.iter()
+                      .filter(|o| {
+                          o.get_field_type() == Offer_Operation_Type::LAUNCH
+                      })
+                      .flat_map(|o| o.get_launch().get_task_infos())
+                      .cloned()
+                      .collect();
+        task_builder::validate_unique(&launched,
+                                      &self.registry.lock().unwrap())
+            .map_err(invalid_task)?;
+
+        self.release_offers(&offer_ids);
+
         let mut accept = Call_Accept::new();
         accept.set_offer_ids(protobuf::RepeatedField::from_vec(offer_ids));
         accept.set_operations(protobuf::RepeatedField::from_vec(operations));
@@ -112,13 +339,23 @@ impl SchedulerClient {
         call.set_field_type(Call_Type::ACCEPT);
         call.set_accept(accept);
 
-        self.post(&mut call)
+        let response = self.post(&mut call);
+        if response.is_ok() {
+            let mut registry = self.registry.lock().unwrap();
+            for task_info in launched.iter() {
+                registry.register(task_info);
+            }
+        }
+
+        response
     }
 
     pub fn decline(&self,
                    offer_ids: Vec<OfferID>,
                    filters: Option<Filters>)
                    -> hyper::Result<Response> {
+        self.release_offers(&offer_ids);
+
         let mut decline = Call_Decline::new();
         decline.set_offer_ids(protobuf::RepeatedField::from_vec(offer_ids));
         if filters.is_some() {
@@ -132,22 +369,121 @@ impl SchedulerClient {
         self.post(&mut call)
     }
 
+    /// Declines `offers` with the `Filters` the `FilterPolicy` picks for
+    /// their agents: short refusals, backing off per agent, while the
+    /// task queue has work, and long ones when it is empty.  Every
+    /// agent's offers are declined even if one call fails; the first
+    /// error is returned.
+    pub fn decline_offers(&self, offers: Vec<&Offer>) -> hyper::Result<()> {
+        let mut by_slave: Vec<(&SlaveID, Vec<OfferID>)> = vec![];
+        for offer in offers {
+            let slave_id = offer.get_slave_id();
+            match by_slave.iter().position(|&(s, _)| s == slave_id) {
+                Some(idx) => by_slave[idx].1.push(offer.get_id().clone()),
+                None => by_slave.push((slave_id, vec![offer.get_id().clone()])),
+            }
+        }
+
+        let pending = !self.task_queue.lock().unwrap().is_empty();
+        let now = Instant::now();
+        let mut result = Ok(());
+        for (slave_id, offer_ids) in by_slave {
+            let filters = self.filter_policy
+                              .lock()
+                              .unwrap()
+                              .decline(slave_id, pending, now);
+            if let Err(e) = self.decline(offer_ids, Some(filters)) {
+                result = result.and(Err(e));
+            }
+        }
+        result
+    }
+
+    /// Accepts inverse offers, agreeing to have the agents' resources
+    /// taken away for maintenance.
+    pub fn accept_inverse_offers(&self,
+                                 inverse_offer_ids: Vec<OfferID>,
+                                 filters: Option<Filters>)
+                                 -> hyper::Result<Response> {
+        let mut accept = Call_AcceptInverseOffers::new();
+        let ids = protobuf::RepeatedField::from_vec(inverse_offer_ids);
+        accept.set_inverse_offer_ids(ids);
+        if let Some(filters) = filters {
+            accept.set_filters(filters);
+        }
+
+        let mut call = Call::new();
+        call.set_field_type(Call_Type::ACCEPT_INVERSE_OFFERS);
+        call.set_accept_inverse_offers(accept);
+
+        self.post(&mut call)
+    }
+
+    /// Declines inverse offers, signaling that the agents' resources
+    /// cannot be given up in time for their maintenance.
+    pub fn decline_inverse_offers(&self,
+                                  inverse_offer_ids: Vec<OfferID>,
+                                  filters: Option<Filters>)
+                                  -> hyper::Result<Response> {
+        let mut decline = Call_DeclineInverseOffers::new();
+        let ids = protobuf::RepeatedField::from_vec(inverse_offer_ids);
+        decline.set_inverse_offer_ids(ids);
+        if let Some(filters) = filters {
+            decline.set_filters(filters);
+        }
+
+        let mut call = Call::new();
+        call.set_field_type(Call_Type::DECLINE_INVERSE_OFFERS);
+        call.set_decline_inverse_offers(decline);
+
+        self.post(&mut call)
+    }
+
+    /// Drains the agent of `inverse_offer` before its maintenance and
+    /// then accepts it; see `Maintenance`.  Inverse offers for no agent
+    /// in particular are declined.
+    pub fn drain(&self, inverse_offer: &InverseOffer) -> hyper::Result<()> {
+        let planned = self.maintenance.lock().unwrap().drain(inverse_offer);
+        if !planned {
+            self.decline_inverse_offers(vec![inverse_offer.get_id().clone()],
+                                        None)?;
+        }
+        Ok(())
+    }
+
+    /// Asks for offers again, clearing every filter set when declining.
     pub fn revive(&self) -> hyper::Result<Response> {
         let mut call = Call::new();
         call.set_field_type(Call_Type::REVIVE);
 
-        self.post(&mut call)
+        let response = self.post(&mut call);
+        if response.is_ok() {
+            self.filter_policy.lock().unwrap().revived();
+        }
+        response
     }
 
     pub fn kill(&self,
                 task_id: TaskID,
                 slave_id: Option<SlaveID>)
                 -> hyper::Result<Response> {
+        self.kill_with_policy(task_id, slave_id, None)
+    }
+
+    /// Kills a task, overriding the `KillPolicy` it was launched with.
+    pub fn kill_with_policy(&self,
+                            task_id: TaskID,
+                            slave_id: Option<SlaveID>,
+                            kill_policy: Option<KillPolicy>)
+                            -> hyper::Result<Response> {
         let mut kill = Call_Kill::new();
         kill.set_task_id(task_id);
         if slave_id.is_some() {
             kill.set_slave_id(slave_id.unwrap());
         }
+        if let Some(kill_policy) = kill_policy {
+            kill.set_kill_policy(kill_policy);
+        }
 
         let mut call = Call::new();
         call.set_field_type(Call_Type::KILL);
@@ -156,6 +492,30 @@ impl SchedulerClient {
         self.post(&mut call)
     }
 
+    /// Kills a task, giving it `grace_period` (or the one it was launched
+    /// with) to shut down, and keeps killing it until a terminal update
+    /// arrives; see `KillTracker`.  The agent is looked up in the
+    /// registry if not given.
+    pub fn kill_gracefully(&self,
+                           task_id: TaskID,
+                           slave_id: Option<SlaveID>,
+                           grace_period: Option<Duration>)
+                           -> hyper::Result<Response> {
+        let (slave_id, given) = {
+            let registry = self.registry.lock().unwrap();
+            let task = registry.get(&task_id);
+            (slave_id.or_else(|| task.and_then(|t| t.slave_id.clone())),
+             grace_period.or_else(|| task.and_then(|t| t.grace_period)))
+        };
+        self.kills
+            .lock()
+            .unwrap()
+            .start(&task_id, slave_id.clone(), given, Instant::now());
+        self.kill_with_policy(task_id,
+                              slave_id,
+                              grace_period.map(util::kill_policy))
+    }
+
     pub fn shutdown(&self,
                     executor_id: ExecutorID,
                     slave_id: SlaveID)
@@ -232,6 +592,73 @@ impl SchedulerClient {
         self.post(&mut call)
     }
 
+    /// Sends `data` to a custom executor, on the agent the registry has
+    /// it running on.
+    pub fn message_executor(&self,
+                            executor_id: &ExecutorID,
+                            data: Vec<u8>)
+                            -> hyper::Result<Response> {
+        let slave_id = self.registry
+                           .lock()
+                           .unwrap()
+                           .executor_agent(executor_id)
+                           .cloned();
+        match slave_id {
+            Some(slave_id) => self.message(slave_id, executor_id.clone(), data),
+            None => {
+                let message = format!("executor {} is not running any task",
+                                      executor_id.get_value());
+                Err(hyper::Error::Io(io::Error::new(io::ErrorKind::NotFound,
+                                                    message)))
+            }
+        }
+    }
+
+    /// Sends `message` to an executor in a one-way `Envelope`.
+    pub fn send_message<M: Codec>(&self,
+                                  slave_id: SlaveID,
+                                  executor_id: ExecutorID,
+                                  message: &M)
+                                  -> hyper::Result<Response> {
+        let envelope = Envelope::one_way(message).map_err(invalid_message)?;
+        self.message(slave_id, executor_id, envelope.to_bytes())
+    }
+
+    /// Sends `message` to an executor as a request, returning the
+    /// `Reply` to wait on for its response.
+    pub fn call_executor<M: Codec, R: Codec>(&self,
+                                             slave_id: SlaveID,
+                                             executor_id: ExecutorID,
+                                             message: &M)
+                                             -> hyper::Result<Reply<R>> {
+        let (envelope, reply) = self.messages
+                                    .lock()
+                                    .unwrap()
+                                    .request(&slave_id,
+                                             &executor_id,
+                                             message,
+                                             Instant::now())
+                                    .map_err(invalid_message)?;
+        let sent = self.message(slave_id, executor_id, envelope.to_bytes());
+        if let Err(e) = sent {
+            self.messages.lock().unwrap().cancel(reply.correlation_id());
+            return Err(e);
+        }
+        Ok(reply)
+    }
+
+    /// Answers a request an executor sent with `correlation_id`.
+    pub fn reply<M: Codec>(&self,
+                           slave_id: SlaveID,
+                           executor_id: ExecutorID,
+                           correlation_id: u64,
+                           message: &M)
+                           -> hyper::Result<Response> {
+        let envelope = Envelope::response(correlation_id, message)
+                           .map_err(invalid_message)?;
+        self.message(slave_id, executor_id, envelope.to_bytes())
+    }
+
     pub fn request(&self, requests: Vec<Request>) -> hyper::Result<Response> {
         let mut request = Call_Request::new();
         request.set_requests(protobuf::RepeatedField::from_vec(requests));
@@ -250,6 +677,15 @@ impl SchedulerClient {
         self.post(&mut call)
     }
 
+    // Offers answered with an accept or decline must not be declined
+    // again when their hold in the pool runs out.
+    fn release_offers(&self, offer_ids: &[OfferID]) {
+        let mut offer_pool = self.offer_pool.lock().unwrap();
+        for offer_id in offer_ids {
+            offer_pool.remove(offer_id);
+        }
+    }
+
     fn post(&self, call: &mut Call) -> hyper::Result<Response> {
         match self.get_framework_id() {
             Some(fwid) => call.set_framework_id(fwid),
@@ -265,3 +701,13 @@ impl SchedulerClient {
             .send()
     }
 }
+
+fn invalid_task(e: TaskError) -> hyper::Error {
+    hyper::Error::Io(io::Error::new(io::ErrorKind::InvalidInput,
+                                    e.to_string()))
+}
+
+fn invalid_message(e: MessageError) -> hyper::Error {
+    hyper::Error::Io(io::Error::new(io::ErrorKind::InvalidInput,
+                                    e.to_string()))
+}
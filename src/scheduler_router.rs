@@ -1,12 +1,22 @@
 use std::io;
-use std::sync::mpsc::Receiver;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant, SystemTime};
 
 use itertools::Itertools;
 
+use health_check;
+use maintenance::DrainStep;
+use reconciler::{self, Step};
 use scheduler_client::SchedulerClient;
+use proto::mesos::{Offer, OfferID, TaskState};
 use proto::scheduler::*;
+use task_registry;
 use {Scheduler, SchedulerConf};
 
+// How often time-driven work (reconciliation retries and the like) is
+// checked when no events arrive.
+const TICK_INTERVAL_MS: u64 = 1000;
+
 pub trait SchedulerRouter {
     fn run(&mut self,
            rx: Receiver<io::Result<Event>>,
@@ -33,13 +43,84 @@ pub struct ProtobufCallbackRouter<'a> {
     pub conf: SchedulerConf,
 }
 
+impl<'a> ProtobufCallbackRouter<'a> {
+    fn tick(&mut self, client: &SchedulerClient) {
+        let now = Instant::now();
+
+        let expired = client.offer_pool.lock().unwrap().expire_offers(now);
+        let _ = client.decline_offers(expired.iter().collect());
+
+        let step = client.reconciler.lock().unwrap().poll(now);
+        match step {
+            Some(Step::Explicit(tasks)) => {
+                // a failed call is simply retried after the next backoff
+                let _ = client.reconcile(tasks);
+            }
+            Some(Step::Implicit) => {
+                if client.reconcile(vec![]).is_ok() {
+                    let lost = client.reconciler.lock().unwrap().complete();
+                    self.scheduler.reconciled(client, lost.iter().collect());
+                }
+            }
+            None => {}
+        }
+        let suspects = client.reconciler.lock().unwrap().poll_suspects(now);
+        if !suspects.is_empty() {
+            let _ = client.reconcile(suspects);
+        }
+
+        client.messages.lock().unwrap().expire(now);
+
+        let steps = {
+            let registry = client.registry.lock().unwrap();
+            client.maintenance
+                  .lock()
+                  .unwrap()
+                  .poll(&registry, SystemTime::now())
+        };
+        for step in steps {
+            match step {
+                DrainStep::Kill(task_id, slave_id) => {
+                    client.task_queue.lock().unwrap().replace(&task_id);
+                    let _ = client.kill_gracefully(task_id,
+                                                   Some(slave_id),
+                                                   None);
+                }
+                DrainStep::Accept(inverse_offer_id) => {
+                    let ids = vec![inverse_offer_id];
+                    let _ = client.accept_inverse_offers(ids, None);
+                }
+            }
+        }
+
+        let retries = client.kills.lock().unwrap().poll(now);
+        for kill in retries {
+            let kill_policy = kill.kill_policy();
+            let _ = client.kill_with_policy(kill.task_id,
+                                            kill.slave_id,
+                                            kill_policy);
+        }
+    }
+}
+
 impl<'a> SchedulerRouter for ProtobufCallbackRouter<'a> {
     fn run(&mut self,
            rx: Receiver<io::Result<Event>>,
            client: SchedulerClient,
            _: SchedulerConf) {
         let mut state = State::Connected;
-        for e in rx {
+        let tick_interval = Duration::from_millis(TICK_INTERVAL_MS);
+        loop {
+            let e = match rx.recv_timeout(tick_interval) {
+                Ok(e) => e,
+                Err(RecvTimeoutError::Timeout) => {
+                    if state.is_connected() {
+                        self.tick(&client);
+                    }
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
             if e.is_err() {
                 if state.is_connected() {
                     state = State::Disconnected;
@@ -69,12 +150,28 @@ impl<'a> SchedulerRouter for ProtobufCallbackRouter<'a> {
                             Some(subscribed.get_heartbeat_interval_seconds())
                         };
 
+                    // offers made to an earlier subscription are void
+                    client.offer_pool.lock().unwrap().clear();
+
+                    {
+                        let registry = client.registry.lock().unwrap();
+                        client.reconciler
+                              .lock()
+                              .unwrap()
+                              .start(&registry, Instant::now());
+                    }
+
                     self.scheduler.subscribed(&client,
                                               subscribed.get_framework_id(),
                                               heartbeat_interval_seconds)
                 }
                 Event_Type::OFFERS => {
                     let offers = event.get_offers();
+                    client.offer_pool
+                          .lock()
+                          .unwrap()
+                          .add(offers.get_offers().iter().collect(),
+                               Instant::now());
 
                     // Split offers per-slave to save users the time of
                     // doing so.
@@ -83,6 +180,27 @@ impl<'a> SchedulerRouter for ProtobufCallbackRouter<'a> {
                                              .group_by(|o| o.get_slave_id()) {
                         self.scheduler.offers(&client, offers.to_vec());
                     }
+
+                    // The task queue, if it has work, gets to place it on
+                    // whatever the scheduler left unused; offers it does
+                    // not use stay in the pool.
+                    if !client.task_queue.lock().unwrap().is_empty() {
+                        let ids: Vec<&OfferID> = offers.get_offers()
+                                                       .iter()
+                                                       .map(|o| o.get_id())
+                                                       .collect();
+                        let unused: Vec<Offer> =
+                            client.offer_pool
+                                  .lock()
+                                  .unwrap()
+                                  .offers()
+                                  .into_iter()
+                                  .filter(|o| ids.contains(&o.get_id()))
+                                  .cloned()
+                                  .collect();
+                        let _ = client.launch_queued(unused.iter().collect());
+                    }
+
                     for (_, inverse_offers) in offers.get_inverse_offers()
                                                      .iter()
                                                      .group_by(|o| {
@@ -94,12 +212,74 @@ impl<'a> SchedulerRouter for ProtobufCallbackRouter<'a> {
 
                 }
                 Event_Type::RESCIND => {
-                    self.scheduler
-                        .rescind(&client, event.get_rescind().get_offer_id())
+                    let offer_id = event.get_rescind().get_offer_id();
+                    client.offer_pool.lock().unwrap().rescind(offer_id);
+                    client.maintenance.lock().unwrap().rescind(offer_id);
+                    self.scheduler.rescind(&client, offer_id)
                 }
                 Event_Type::UPDATE => {
                     let status = event.get_update().get_status();
+                    let task_id = status.get_task_id();
+                    let health_change = {
+                        let mut registry = client.registry.lock().unwrap();
+                        let previous = registry.get(task_id)
+                                               .and_then(|t| t.healthy);
+                        registry.update(status);
+                        health_check::health_change(previous, status)
+                    };
+                    client.reconciler.lock().unwrap().observe(status);
+                    let kill_progress = client.kills
+                                              .lock()
+                                              .unwrap()
+                                              .observe(status);
+                    // a lost task may come back once its agent does, so
+                    // it is only let go of once its loss is confirmed
+                    let lost = status.get_state() == TaskState::TASK_LOST &&
+                               !reconciler::confirms_loss(status);
+                    if lost {
+                        client.reconciler
+                              .lock()
+                              .unwrap()
+                              .suspect(status, Instant::now());
+                    } else if task_registry::is_terminal(status.get_state()) {
+                        let replacement = client.task_queue
+                                                .lock()
+                                                .unwrap()
+                                                .terminated(task_id);
+                        client.maintenance.lock().unwrap().terminated(task_id);
+                        if let Some(spec) = replacement {
+                            let _ = client.enqueue(spec);
+                        }
+                    }
                     self.scheduler.update(&client, status);
+                    if let Some(progress) = kill_progress {
+                        self.scheduler
+                            .kill_updated(&client, task_id, &progress);
+                    }
+                    if let Some(healthy) = health_change {
+                        self.scheduler
+                            .task_health_changed(&client, status, healthy);
+                        let (kill, replace) = {
+                            let health = client.health.lock().unwrap();
+                            (!healthy && health.kills(), health.replaces())
+                        };
+                        if kill {
+                            if replace {
+                                client.task_queue
+                                      .lock()
+                                      .unwrap()
+                                      .replace(task_id);
+                            }
+                            let slave_id = if status.has_slave_id() {
+                                Some(status.get_slave_id().clone())
+                            } else {
+                                None
+                            };
+                            let _ = client.kill_gracefully(task_id.clone(),
+                                                           slave_id,
+                                                           None);
+                        }
+                    }
                     if self.conf.implicit_acknowledgements {
                         client.acknowledge(status.get_slave_id().clone(),
                                            status.get_task_id().clone(),
@@ -109,10 +289,18 @@ impl<'a> SchedulerRouter for ProtobufCallbackRouter<'a> {
                 }
                 Event_Type::MESSAGE => {
                     let message = event.get_message();
-                    self.scheduler.message(&client,
-                                           message.get_slave_id(),
-                                           message.get_executor_id(),
-                                           message.get_data().to_vec())
+                    let is_reply = client.messages
+                                         .lock()
+                                         .unwrap()
+                                         .receive(message.get_slave_id(),
+                                                  message.get_executor_id(),
+                                                  message.get_data());
+                    if !is_reply {
+                        self.scheduler.message(&client,
+                                               message.get_slave_id(),
+                                               message.get_executor_id(),
+                                               message.get_data().to_vec())
+                    }
                 }
                 Event_Type::FAILURE => {
                     let failure = event.get_failure();
@@ -143,6 +331,8 @@ impl<'a> SchedulerRouter for ProtobufCallbackRouter<'a> {
                 Event_Type::HEARTBEAT => self.scheduler.heartbeat(&client),
                 Event_Type::UNKNOWN => {}
             }
+
+            self.tick(&client);
         }
     }
 }
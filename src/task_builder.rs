@@ -0,0 +1,281 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use protobuf::RepeatedField;
+
+use proto::mesos::{CommandInfo, ContainerInfo, DiscoveryInfo, ExecutorInfo,
+                   HealthCheck, KillPolicy, Label, Labels, Resource, SlaveID,
+                   TaskID, TaskInfo, Value_Type};
+use resources;
+use task_registry::TaskRegistry;
+use util;
+
+/// Why a task would be rejected by the master.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TaskError {
+    MissingName,
+    MissingTaskId,
+    /// The task id is not a valid Mesos id, for the given reason.
+    InvalidTaskId(String, &'static str),
+    /// Another task with this id is being launched or still running.
+    DuplicateTaskId(String),
+    MissingSlaveId,
+    NoResources,
+    /// The named resource is malformed, for the given reason.
+    InvalidResource(String, String),
+    /// Neither a command nor an executor was given.
+    NothingToRun,
+    /// Both a command and an executor were given.
+    CommandAndExecutor,
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TaskError::MissingName => write!(f, "task has no name"),
+            TaskError::MissingTaskId => write!(f, "task has no id"),
+            TaskError::InvalidTaskId(ref id, reason) => {
+                write!(f, "invalid task id {:?}: {}", id, reason)
+            }
+            TaskError::DuplicateTaskId(ref id) => {
+                write!(f, "task id {:?} is already in use", id)
+            }
+            TaskError::MissingSlaveId => write!(f, "task has no agent"),
+            TaskError::NoResources => write!(f, "task has no resources"),
+            TaskError::InvalidResource(ref name, ref reason) => {
+                write!(f, "invalid resource {:?}: {}", name, reason)
+            }
+            TaskError::NothingToRun => {
+                write!(f, "task needs either a command or an executor")
+            }
+            TaskError::CommandAndExecutor => {
+                write!(f, "task cannot have both a command and an executor")
+            }
+        }
+    }
+}
+
+impl Error for TaskError {
+    fn description(&self) -> &str {
+        "invalid task"
+    }
+}
+
+/// Builds a `TaskInfo`, checking up front what the master would
+/// otherwise reject asynchronously with TASK_ERROR.
+#[derive(Clone, Debug, Default)]
+pub struct TaskBuilder {
+    name: String,
+    task_id: Option<TaskID>,
+    slave_id: Option<SlaveID>,
+    resources: Vec<Resource>,
+    command: Option<CommandInfo>,
+    executor: Option<ExecutorInfo>,
+    container: Option<ContainerInfo>,
+    health_check: Option<HealthCheck>,
+    kill_policy: Option<KillPolicy>,
+    data: Option<Vec<u8>>,
+    labels: Labels,
+    discovery: Option<DiscoveryInfo>,
+}
+
+impl TaskBuilder {
+    pub fn new(name: &str) -> TaskBuilder {
+        TaskBuilder { name: name.to_string(), ..TaskBuilder::default() }
+    }
+
+    pub fn task_id(mut self, task_id: TaskID) -> TaskBuilder {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    pub fn id(self, id: &str) -> TaskBuilder {
+        self.task_id(util::task_id(id))
+    }
+
+    pub fn slave_id(mut self, slave_id: &SlaveID) -> TaskBuilder {
+        self.slave_id = Some(slave_id.clone());
+        self
+    }
+
+    pub fn resources(mut self, resources: Vec<Resource>) -> TaskBuilder {
+        self.resources.extend(resources);
+        self
+    }
+
+    pub fn resource(mut self, resource: Resource) -> TaskBuilder {
+        self.resources.push(resource);
+        self
+    }
+
+    pub fn command(mut self, command: CommandInfo) -> TaskBuilder {
+        self.command = Some(command);
+        self
+    }
+
+    pub fn executor(mut self, executor: ExecutorInfo) -> TaskBuilder {
+        self.executor = Some(executor);
+        self
+    }
+
+    pub fn container(mut self, container: ContainerInfo) -> TaskBuilder {
+        self.container = Some(container);
+        self
+    }
+
+    pub fn health_check(mut self, health_check: HealthCheck) -> TaskBuilder {
+        self.health_check = Some(health_check);
+        self
+    }
+
+    pub fn kill_policy(mut self, kill_policy: KillPolicy) -> TaskBuilder {
+        self.kill_policy = Some(kill_policy);
+        self
+    }
+
+    pub fn data(mut self, data: Vec<u8>) -> TaskBuilder {
+        self.data = Some(data);
+        self
+    }
+
+    pub fn label(mut self, key: &str, value: &str) -> TaskBuilder {
+        let mut label = Label::new();
+        label.set_key(key.to_string());
+        label.set_value(value.to_string());
+        self.labels.mut_labels().push(label);
+        self
+    }
+
+    pub fn discovery(mut self, discovery: DiscoveryInfo) -> TaskBuilder {
+        self.discovery = Some(discovery);
+        self
+    }
+
+    pub fn build(self) -> Result<TaskInfo, TaskError> {
+        if self.name.is_empty() {
+            return Err(TaskError::MissingName);
+        }
+        let task_id = match self.task_id {
+            Some(task_id) => task_id,
+            None => return Err(TaskError::MissingTaskId),
+        };
+        validate_id(task_id.get_value())?;
+        let slave_id = match self.slave_id {
+            Some(slave_id) => slave_id,
+            None => return Err(TaskError::MissingSlaveId),
+        };
+        if self.resources.is_empty() {
+            return Err(TaskError::NoResources);
+        }
+        for resource in self.resources.iter() {
+            validate_resource(resource)?;
+        }
+        match (self.command.is_some(), self.executor.is_some()) {
+            (false, false) => return Err(TaskError::NothingToRun),
+            (true, true) => return Err(TaskError::CommandAndExecutor),
+            _ => {}
+        }
+
+        let mut task_info = TaskInfo::new();
+        task_info.set_name(self.name);
+        task_info.set_task_id(task_id);
+        task_info.set_slave_id(slave_id);
+        task_info.set_resources(RepeatedField::from_vec(self.resources));
+        if let Some(command) = self.command {
+            task_info.set_command(command);
+        }
+        if let Some(executor) = self.executor {
+            task_info.set_executor(executor);
+        }
+        if let Some(container) = self.container {
+            task_info.set_container(container);
+        }
+        if let Some(health_check) = self.health_check {
+            task_info.set_health_check(health_check);
+        }
+        if let Some(kill_policy) = self.kill_policy {
+            task_info.set_kill_policy(kill_policy);
+        }
+        if let Some(data) = self.data {
+            task_info.set_data(data);
+        }
+        if !self.labels.get_labels().is_empty() {
+            task_info.set_labels(self.labels);
+        }
+        if let Some(discovery) = self.discovery {
+            task_info.set_discovery(discovery);
+        }
+        Ok(task_info)
+    }
+}
+
+/// Checks that `tasks`, about to be launched together, neither share a
+/// task id nor reuse the id of a task `registry` still tracks as
+/// non-terminal.
+pub fn validate_unique(tasks: &[TaskInfo],
+                       registry: &TaskRegistry)
+                       -> Result<(), TaskError> {
+    let mut seen = HashSet::new();
+    for task in tasks {
+        let id = task.get_task_id();
+        let live = registry.get(id).map_or(false, |t| !t.is_terminal());
+        if live || !seen.insert(id.get_value()) {
+            return Err(TaskError::DuplicateTaskId(id.get_value()
+                                                    .to_string()));
+        }
+    }
+    Ok(())
+}
+
+fn validate_id(id: &str) -> Result<(), TaskError> {
+    util::check_id(id).map_err(|reason| {
+        TaskError::InvalidTaskId(id.to_string(), reason)
+    })
+}
+
+fn validate_resource(resource: &Resource) -> Result<(), TaskError> {
+    let name = resource.get_name();
+    let invalid = |reason: String| {
+        Err(TaskError::InvalidResource(name.to_string(), reason))
+    };
+    if name.is_empty() {
+        return invalid("resources need a name".to_string());
+    }
+    let expected = match name {
+        "cpus" | "mem" | "disk" | "gpus" => Some(Value_Type::SCALAR),
+        "ports" => Some(Value_Type::RANGES),
+        _ => None,
+    };
+    let actual = resource.get_field_type();
+    if let Some(expected) = expected {
+        if actual != expected {
+            return invalid(format!("expected {:?}, got {:?}",
+                                   expected,
+                                   actual));
+        }
+    }
+    match actual {
+        Value_Type::SCALAR => {
+            let value = resource.get_scalar().get_value();
+            if !value.is_finite() || value <= 0f64 {
+                return invalid(format!("{} is not a positive amount",
+                                       value));
+            }
+        }
+        Value_Type::RANGES => {
+            if resources::range_intervals(resource).is_empty() {
+                return invalid("no ranges".to_string());
+            }
+        }
+        Value_Type::SET => {
+            if resource.get_set().get_item().is_empty() {
+                return invalid("empty set".to_string());
+            }
+        }
+        Value_Type::TEXT => {
+            return invalid("TEXT is not a resource type".to_string());
+        }
+    }
+    Ok(())
+}
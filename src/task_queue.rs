@@ -0,0 +1,584 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use itertools::Itertools;
+
+use constraints::{Constraint, Placements};
+use executor;
+use maintenance::fits_before_maintenance;
+use placement::{Candidate, FirstFit, PlacementStrategy};
+use ports::{PortAllocation, PortAllocator};
+use proto::mesos::{CommandInfo, ContainerInfo,
+                   ContainerInfo_DockerInfo_Network, ExecutorInfo,
+                   HealthCheck, Offer, OfferID, Resource, SlaveID, TaskID,
+                   TaskInfo};
+use resources::Resources;
+use util;
+
+/// A task waiting to be placed.  `resources` are matched by name, type
+/// and amount (see `Resources::find`); the `TaskInfo` that is built
+/// carries the matching offer resources, so reservations and roles are
+/// preserved.
+#[derive(Clone, Debug)]
+pub struct TaskSpec {
+    pub name: String,
+    pub task_id: Option<TaskID>,
+    pub resources: Vec<Resource>,
+    /// How many ports to allocate from the offer's `ports` resource; see
+    /// `PortAllocation::apply` for how the task learns them.
+    pub ports: usize,
+    /// The container port each allocated port is mapped to, in order,
+    /// when the task runs in a bridged Docker container; see
+    /// `PortAllocation::port_mappings`.
+    pub container_ports: Vec<u32>,
+    /// Names for the allocated ports, in order.  If there are any, the
+    /// task announces its ports in a `DiscoveryInfo`.
+    pub port_names: Vec<String>,
+    /// Constraints on the agents the task may run on, evaluated
+    /// against the other tasks with the same `name`.
+    pub constraints: Vec<Constraint>,
+    /// Whether the task may run on revocable resources.  Best-effort
+    /// work should opt in; it then uses revocable resources first and
+    /// risks being killed when the agent reclaims them.
+    pub revocable: bool,
+    /// `command` and `container` are ignored if the task runs on
+    /// `executor`, which has its own.
+    pub command: CommandInfo,
+    pub container: Option<ContainerInfo>,
+    /// The custom executor to run the task on instead of the command
+    /// executor.  Its resources are taken from the offer as well when
+    /// it is not yet running on the agent.
+    pub executor: Option<ExecutorInfo>,
+    /// Checked by the executor; see `HealthCheckBuilder`.
+    pub health_check: Option<HealthCheck>,
+    /// How long the task is expected to run, or None if it runs until
+    /// it is stopped.  It is not placed on agents whose announced
+    /// maintenance it would run into.
+    pub expected_runtime: Option<Duration>,
+}
+
+impl TaskSpec {
+    pub fn new(name: &str,
+               command: CommandInfo,
+               resources: Vec<Resource>)
+               -> TaskSpec {
+        TaskSpec {
+            name: name.to_string(),
+            task_id: None,
+            resources: resources,
+            ports: 0,
+            container_ports: vec![],
+            port_names: vec![],
+            constraints: vec![],
+            revocable: false,
+            command: command,
+            container: None,
+            executor: None,
+            health_check: None,
+            expected_runtime: None,
+        }
+    }
+
+    /// A task run by `executor`.
+    pub fn for_executor(name: &str,
+                        executor: ExecutorInfo,
+                        resources: Vec<Resource>)
+                        -> TaskSpec {
+        TaskSpec {
+            executor: Some(executor),
+            ..TaskSpec::new(name, CommandInfo::new(), resources)
+        }
+    }
+}
+
+/// The tasks placed on one agent, ready for `SchedulerClient::launch`.
+#[derive(Clone, Debug)]
+pub struct Launch {
+    pub offer_ids: Vec<OfferID>,
+    pub tasks: Vec<TaskInfo>,
+}
+
+/// What a task placed by the queue holds on to until it terminates.
+struct Launched {
+    spec: TaskSpec,
+    slave_id: SlaveID,
+    /// Whether the spec is queued again once the task has terminated.
+    replace: bool,
+}
+
+/// A custom executor the queue launched on an agent, as it was launched
+/// and with the number of its tasks still running.
+struct RunningExecutor {
+    executor_info: ExecutorInfo,
+    tasks: usize,
+}
+
+/// What remains of one agent's offers while tasks are carved out of it.
+struct OfferGroup<'a> {
+    offers: Vec<&'a Offer>,
+    remaining: Resources,
+    tasks: Vec<TaskInfo>,
+}
+
+/// A FIFO queue of `TaskSpec`s that are matched against incoming offers.
+///
+/// `ProtobufCallbackRouter` hands it offers the scheduler left unused
+/// whenever work is pending.  Once the queue drains it asks for offers
+/// to be suppressed, and new work asks for them to be revived.  Which
+/// agent a task lands on is up to the `PlacementStrategy`, first-fit by
+/// default.
+pub struct TaskQueue {
+    pending: VecDeque<TaskSpec>,
+    strategy: Box<PlacementStrategy + Send>,
+    placements: Placements,
+    launched: HashMap<String, Launched>,
+    executors: HashMap<(String, String), RunningExecutor>,
+    suppressed: bool,
+    active: bool,
+    next_id: u64,
+}
+
+impl Default for TaskQueue {
+    fn default() -> TaskQueue {
+        TaskQueue::new()
+    }
+}
+
+impl TaskQueue {
+    pub fn new() -> TaskQueue {
+        TaskQueue::with_strategy(Box::new(FirstFit))
+    }
+
+    pub fn with_strategy(strategy: Box<PlacementStrategy + Send>)
+                         -> TaskQueue {
+        TaskQueue {
+            pending: VecDeque::new(),
+            strategy: strategy,
+            placements: Placements::new(),
+            launched: HashMap::new(),
+            executors: HashMap::new(),
+            suppressed: false,
+            active: false,
+            next_id: 0,
+        }
+    }
+
+    pub fn set_strategy(&mut self, strategy: Box<PlacementStrategy + Send>) {
+        self.strategy = strategy;
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn pending(&self) -> Vec<&TaskSpec> {
+        self.pending.iter().collect()
+    }
+
+    /// Removes and returns the pending specs accepted by `predicate`.
+    pub fn remove_pending<F>(&mut self, predicate: F) -> Vec<TaskSpec>
+        where F: Fn(&TaskSpec) -> bool
+    {
+        let (removed, kept): (VecDeque<TaskSpec>, VecDeque<TaskSpec>) =
+            self.pending.drain(..).partition(|s| predicate(s));
+        self.pending = kept;
+        removed.into_iter().collect()
+    }
+
+    /// Where the tasks placed by this queue run, for constraints.
+    pub fn placements(&self) -> &Placements {
+        &self.placements
+    }
+
+    /// Records a task placed elsewhere, e.g. one that was running before
+    /// a failover, so that constraints take it into account.
+    pub fn record_placement(&mut self,
+                            name: &str,
+                            task_id: &TaskID,
+                            offer: &Offer) {
+        self.placements.record(name, task_id, offer);
+    }
+
+    /// Stops counting a task towards constraints and the placement
+    /// strategy, once it has terminated, and returns the spec it was
+    /// launched from, if it was placed by this queue.
+    pub fn forget(&mut self, task_id: &TaskID) -> Option<TaskSpec> {
+        self.placements.forget(task_id);
+        let launched = match self.launched.remove(task_id.get_value()) {
+            Some(launched) => launched,
+            None => return None,
+        };
+        self.strategy.released(&launched.spec, &launched.slave_id);
+        if let Some(key) = executor_key(&launched.spec, &launched.slave_id) {
+            let done = match self.executors.get_mut(&key) {
+                Some(running) => {
+                    running.tasks -= 1;
+                    running.tasks == 0
+                }
+                None => false,
+            };
+            if done {
+                self.executors.remove(&key);
+            }
+        }
+        Some(launched.spec)
+    }
+
+    /// Has the spec `task_id` was launched from queued again once the
+    /// task has terminated, e.g. because it is being killed to move it
+    /// elsewhere.  Returns false if the task was not placed by this
+    /// queue.
+    pub fn replace(&mut self, task_id: &TaskID) -> bool {
+        match self.launched.get_mut(task_id.get_value()) {
+            Some(launched) => {
+                launched.replace = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forgets `task_id` once it has terminated, like `forget`, and
+    /// returns the spec to queue again, with a fresh task id, if it was
+    /// to be replaced.
+    pub fn terminated(&mut self, task_id: &TaskID) -> Option<TaskSpec> {
+        let replace = self.launched
+                          .get(task_id.get_value())
+                          .map_or(false, |l| l.replace);
+        match self.forget(task_id) {
+            Some(mut spec) if replace => {
+                spec.task_id = None;
+                Some(spec)
+            }
+            _ => None,
+        }
+    }
+
+    /// The custom executor `spec` runs on, if it is already running on
+    /// `slave_id`.
+    fn running_executor(&self,
+                        spec: &TaskSpec,
+                        slave_id: &SlaveID)
+                        -> Option<&ExecutorInfo> {
+        executor_key(spec, slave_id).and_then(|key| {
+            self.executors.get(&key).map(|r| &r.executor_info)
+        })
+    }
+
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed
+    }
+
+    /// Queues a task.  Returns true if offers are currently suppressed
+    /// and need to be revived.
+    pub fn push(&mut self, spec: TaskSpec) -> bool {
+        self.pending.push_back(spec);
+        self.active = true;
+        if self.suppressed {
+            self.suppressed = false;
+            return true;
+        }
+        false
+    }
+
+    /// Returns true, once, after the queue has drained, meaning offers
+    /// should be suppressed until more work arrives.
+    pub fn should_suppress(&mut self) -> bool {
+        if self.active && !self.suppressed && self.pending.is_empty() {
+            self.active = false;
+            self.suppressed = true;
+            return true;
+        }
+        false
+    }
+
+    /// Places as many pending tasks as fit on `offers`, in queue order,
+    /// and removes them from the queue.
+    pub fn match_offers(&mut self, offers: Vec<&Offer>) -> Vec<Launch> {
+        let mut groups: Vec<OfferGroup> = vec![];
+        for (_, offers) in offers.into_iter()
+                                 .group_by(|o| o.get_slave_id().clone()) {
+            let remaining = offers.iter()
+                                  .flat_map(|o| o.get_resources())
+                                  .cloned()
+                                  .collect::<Vec<Resource>>()
+                                  .into();
+            groups.push(OfferGroup {
+                offers: offers,
+                remaining: remaining,
+                tasks: vec![],
+            });
+        }
+
+        let now = SystemTime::now();
+        let mut unplaced = VecDeque::new();
+        while let Some(spec) = self.pending.pop_front() {
+            let fitting: Vec<usize> =
+                groups.iter()
+                      .enumerate()
+                      .filter(|&(_, g)| {
+                          fits_before_maintenance(g.offers[0],
+                                                  spec.expected_runtime,
+                                                  now)
+                      })
+                      .filter(|&(_, g)| {
+                          self.placements
+                              .allows(&spec.name,
+                                      &spec.constraints,
+                                      g.offers[0])
+                      })
+                      .filter(|&(_, g)| {
+                          let slave_id = g.offers[0].get_slave_id();
+                          let executor = self.running_executor(&spec,
+                                                               slave_id);
+                          allocate(&mut g.remaining.clone(),
+                                   &spec,
+                                   executor.is_none())
+                              .is_some()
+                      })
+                      .map(|(idx, _)| idx)
+                      .collect();
+
+            let selected = if fitting.is_empty() {
+                None
+            } else {
+                let candidates: Vec<Candidate> =
+                    fitting.iter()
+                           .map(|&idx| {
+                               let group = &groups[idx];
+                               Candidate {
+                                   slave_id: group.offers[0].get_slave_id(),
+                                   offers: &group.offers,
+                                   remaining: group.remaining.as_slice(),
+                               }
+                           })
+                           .collect();
+                self.strategy
+                    .select(&spec, &candidates)
+                    .and_then(|c| fitting.get(c).cloned())
+            };
+
+            match selected {
+                Some(idx) => {
+                    let group = &mut groups[idx];
+                    let slave_id = group.offers[0].get_slave_id().clone();
+                    let running = self.running_executor(&spec, &slave_id)
+                                      .cloned();
+                    let (resources, executor_resources, ports) =
+                        allocate(&mut group.remaining,
+                                 &spec,
+                                 running.is_none())
+                            .unwrap();
+                    self.strategy.placed(&spec, group.offers[0]);
+                    let executor_info = match (running, &spec.executor) {
+                        (Some(running), _) => Some(running),
+                        (None, &Some(ref executor)) => {
+                            Some(executor::with_resources(executor,
+                                                          executor_resources))
+                        }
+                        (None, &None) => None,
+                    };
+                    let mut task_info = self.task_info(&spec,
+                                                       group.offers[0],
+                                                       resources,
+                                                       executor_info);
+                    apply_ports(&spec, &ports, &mut task_info);
+                    self.placements.record(&spec.name,
+                                           task_info.get_task_id(),
+                                           group.offers[0]);
+                    if let Some(key) = executor_key(&spec, &slave_id) {
+                        let executor_info = task_info.get_executor().clone();
+                        self.executors
+                            .entry(key)
+                            .or_insert(RunningExecutor {
+                                executor_info: executor_info,
+                                tasks: 0,
+                            })
+                            .tasks += 1;
+                    }
+                    self.launched.insert(task_info.get_task_id()
+                                                  .get_value()
+                                                  .to_string(),
+                                         Launched {
+                                             spec: spec,
+                                             slave_id: slave_id,
+                                             replace: false,
+                                         });
+                    group.tasks.push(task_info);
+                }
+                None => unplaced.push_back(spec),
+            }
+        }
+        self.pending = unplaced;
+
+        groups.into_iter()
+              .filter(|g| !g.tasks.is_empty())
+              .map(|g| {
+                  Launch {
+                      offer_ids: g.offers
+                                  .iter()
+                                  .map(|o| o.get_id().clone())
+                                  .collect(),
+                      tasks: g.tasks,
+                  }
+              })
+              .collect()
+    }
+
+    fn task_info(&mut self,
+                 spec: &TaskSpec,
+                 offer: &Offer,
+                 resources: Vec<Resource>,
+                 executor_info: Option<ExecutorInfo>)
+                 -> TaskInfo {
+        let task_id = match spec.task_id {
+            Some(ref task_id) => task_id.clone(),
+            None => self.generate_task_id(&spec.name),
+        };
+        let mut task_info = match (executor_info, &spec.container) {
+            (Some(executor_info), _) => {
+                util::task_info_for_executor(&spec.name,
+                                             &task_id,
+                                             offer.get_slave_id(),
+                                             &executor_info,
+                                             resources)
+            }
+            (None, &Some(ref container)) => {
+                util::task_info_for_container(&spec.name,
+                                              &task_id,
+                                              offer.get_slave_id(),
+                                              &spec.command,
+                                              container,
+                                              resources)
+            }
+            (None, &None) => {
+                util::task_info(&spec.name,
+                                &task_id,
+                                offer.get_slave_id(),
+                                &spec.command,
+                                resources)
+            }
+        };
+        if let Some(ref health_check) = spec.health_check {
+            task_info.set_health_check(health_check.clone());
+        }
+        task_info
+    }
+
+    // Task ids must stay unique across scheduler restarts, so they are
+    // based on the wall clock as well as a counter.
+    fn generate_task_id(&mut self, name: &str) -> TaskID {
+        self.next_id += 1;
+        let since_epoch = SystemTime::now()
+                              .duration_since(UNIX_EPOCH)
+                              .unwrap_or_default();
+        util::task_id(&format!("{}.{}{:09}.{}",
+                               name,
+                               since_epoch.as_secs(),
+                               since_epoch.subsec_nanos(),
+                               self.next_id))
+    }
+}
+
+// The key of the executor `spec` runs on in `TaskQueue::executors`.
+fn executor_key(spec: &TaskSpec,
+                slave_id: &SlaveID)
+                -> Option<(String, String)> {
+    spec.executor.as_ref().map(|executor| {
+        (slave_id.get_value().to_string(),
+         executor.get_executor_id().get_value().to_string())
+    })
+}
+
+// Hands the ports allocated for `spec` to its task: as resources and
+// environment, as port mappings if it runs in a bridged Docker
+// container, and in its DiscoveryInfo if `spec` names them.
+fn apply_ports(spec: &TaskSpec,
+               ports: &PortAllocation,
+               task_info: &mut TaskInfo) {
+    ports.apply(task_info);
+    if task_info.has_container() && task_info.get_container().has_docker() &&
+       task_info.get_container().get_docker().get_network() ==
+       ContainerInfo_DockerInfo_Network::BRIDGE {
+        let docker = task_info.mut_container().mut_docker();
+        for mapping in ports.port_mappings(&spec.container_ports, "tcp") {
+            docker.mut_port_mappings().push(mapping);
+        }
+    }
+    if !spec.port_names.is_empty() {
+        let names: Vec<&str> = spec.port_names
+                                   .iter()
+                                   .map(|name| name.as_str())
+                                   .collect();
+        ports.apply_discovery(task_info, &names, "tcp");
+    }
+}
+
+// Carves the resources and ports `spec` asks for out of `available`,
+// along with those of its executor if it has to be launched, returning
+// the pieces taken with the role and reservation of the offered
+// resources they came from.  Revocable resources are only used if the
+// task opted in, and never mixed with regular ones of the same name,
+// which the master rejects: the task and its executor are placed on
+// the revocable resources offered, plus regular ones of other names,
+// if they fit there, and on regular resources alone otherwise.
+// `available` is left untouched if anything is missing.
+fn allocate(available: &mut Resources,
+            spec: &TaskSpec,
+            launch_executor: bool)
+            -> Option<(Vec<Resource>, Vec<Resource>, PortAllocation)> {
+    let regular = available.non_revocable();
+    if spec.revocable {
+        let revocable = available.revocable();
+        let names: Vec<&str> = revocable.iter()
+                                        .map(|r| r.get_name())
+                                        .collect();
+        let usable = regular.filter(|r| !names.contains(&r.get_name())) +
+                     &revocable;
+        let allocated = allocate_from(available, usable, spec, launch_executor);
+        if allocated.is_some() {
+            return allocated;
+        }
+    }
+    allocate_from(available, regular, spec, launch_executor)
+}
+
+// Allocates like `allocate`, taking only from `usable`.
+fn allocate_from(available: &mut Resources,
+                 mut usable: Resources,
+                 spec: &TaskSpec,
+                 launch_executor: bool)
+                 -> Option<(Vec<Resource>, Vec<Resource>, PortAllocation)> {
+    let executor_found = match spec.executor {
+        Some(ref executor) if launch_executor => {
+            let wanted = Resources::from_vec(executor.get_resources()
+                                                     .to_vec());
+            match usable.find(&wanted) {
+                Some(found) => found,
+                None => return None,
+            }
+        }
+        _ => Resources::new(),
+    };
+    usable -= &executor_found;
+    let wanted = Resources::from_vec(spec.resources.clone());
+    let found = match usable.find(&wanted) {
+        Some(found) => found,
+        None => return None,
+    };
+    let ports = match PortAllocator::from_resources(&(usable - &found))
+                          .allocate(spec.ports) {
+        Some(ports) => ports,
+        None => return None,
+    };
+    *available -= &executor_found;
+    *available -= &found;
+    for resource in ports.resources.iter() {
+        available.subtract_resource(resource);
+    }
+    Some((found.into_vec(), executor_found.into_vec(), ports))
+}
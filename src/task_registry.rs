@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use network;
+use proto::mesos::{ExecutorID, SlaveID, TaskID, TaskInfo, TaskState,
+                   TaskStatus, TaskStatus_Reason};
+use util;
+
+/// Returns true if Mesos considers `state` final: the task is gone and
+/// its resources have been (or will be) released.
+pub fn is_terminal(state: TaskState) -> bool {
+    match state {
+        TaskState::TASK_FINISHED |
+        TaskState::TASK_FAILED |
+        TaskState::TASK_KILLED |
+        TaskState::TASK_LOST |
+        TaskState::TASK_ERROR => true,
+        _ => false,
+    }
+}
+
+/// Returns true if `reason` means the task was killed because the
+/// revocable resources it ran on were reclaimed, e.g. by the agent's QoS
+/// controller.  Such tasks did nothing wrong and can be relaunched.
+pub fn is_revocation(reason: TaskStatus_Reason) -> bool {
+    reason == TaskStatus_Reason::REASON_CONTAINER_PREEMPTED
+}
+
+// Position of a state in the lifecycle; tasks only ever move forward.
+fn stage(state: TaskState) -> u8 {
+    match state {
+        TaskState::TASK_STAGING => 0,
+        TaskState::TASK_STARTING => 1,
+        TaskState::TASK_RUNNING => 2,
+        TaskState::TASK_KILLING => 3,
+        _ => 4,
+    }
+}
+
+/// Returns true if a task may move from `from` to `to`.  Re-delivery
+/// of the current state is not a transition and is reported as a
+/// duplicate by the registry instead.
+///
+/// TASK_LOST is the one terminal state that can be left, for any
+/// other: tasks on an agent cut off from the master are reported lost,
+/// and reported again with their actual state, running or finished,
+/// once the agent re-registers.
+pub fn is_valid_transition(from: TaskState, to: TaskState) -> bool {
+    if from == TaskState::TASK_LOST {
+        return to != TaskState::TASK_LOST;
+    }
+    !is_terminal(from) && stage(to) > stage(from)
+}
+
+/// The registry's view of a single task.
+#[derive(Clone, Debug)]
+pub struct TrackedTask {
+    pub task_id: TaskID,
+    pub name: Option<String>,
+    pub state: TaskState,
+    pub slave_id: Option<SlaveID>,
+    pub executor_id: Option<ExecutorID>,
+    /// Whether the task was launched with revocable resources.
+    pub revocable: bool,
+    /// The grace period of the `KillPolicy` the task was launched with.
+    pub grace_period: Option<Duration>,
+    pub reason: Option<TaskStatus_Reason>,
+    pub message: Option<String>,
+    /// The container's addresses as `(network name, address)`, taken
+    /// from the last TASK_RUNNING update that reported any; see
+    /// `network::assigned_ips`.
+    pub ip_addresses: Vec<(String, String)>,
+    /// The task's health as last reported by its health check, if it
+    /// has one.
+    pub healthy: Option<bool>,
+    /// The `timestamp` of the last accepted `TaskStatus`, in seconds
+    /// since the epoch as reported by Mesos.
+    pub status_timestamp: Option<f64>,
+    pub registered_at: Instant,
+    pub updated_at: Instant,
+}
+
+impl TrackedTask {
+    fn new(task_id: TaskID, state: TaskState) -> TrackedTask {
+        let now = Instant::now();
+        TrackedTask {
+            task_id: task_id,
+            name: None,
+            state: state,
+            slave_id: None,
+            executor_id: None,
+            revocable: false,
+            grace_period: None,
+            reason: None,
+            message: None,
+            ip_addresses: vec![],
+            healthy: None,
+            status_timestamp: None,
+            registered_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        is_terminal(self.state)
+    }
+
+    /// Returns true if the task ended because its resources were
+    /// revoked.
+    pub fn was_revoked(&self) -> bool {
+        self.is_terminal() && self.reason.map_or(false, is_revocation)
+    }
+
+    /// The container's addresses on the named network.
+    pub fn ips_on(&self, network: &str) -> Vec<&str> {
+        self.ip_addresses
+            .iter()
+            .filter(|&&(ref name, _)| name == network)
+            .map(|&(_, ref ip)| &ip[..])
+            .collect()
+    }
+
+    fn record(&mut self, status: &TaskStatus) {
+        self.state = status.get_state();
+        if status.has_slave_id() {
+            self.slave_id = Some(status.get_slave_id().clone());
+        }
+        if status.has_executor_id() {
+            self.executor_id = Some(status.get_executor_id().clone());
+        }
+        self.reason = if status.has_reason() {
+            Some(status.get_reason())
+        } else {
+            None
+        };
+        self.message = if status.has_message() {
+            Some(status.get_message().to_string())
+        } else {
+            None
+        };
+        if status.get_state() == TaskState::TASK_RUNNING &&
+           status.has_container_status() {
+            let ips = network::assigned_ips(status);
+            if !ips.is_empty() {
+                self.ip_addresses = ips;
+            }
+        }
+        if status.has_healthy() {
+            self.healthy = Some(status.get_healthy());
+        }
+        if status.has_timestamp() {
+            self.status_timestamp = Some(status.get_timestamp());
+        }
+        self.updated_at = Instant::now();
+    }
+}
+
+/// The outcome of feeding a `TaskStatus` into the registry.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Transition {
+    /// The task moved from the contained state to the one in the status.
+    Changed(TaskState),
+    /// The status repeated the task's current state.
+    Duplicate,
+    /// The task was not known before, e.g. after a failover.
+    Discovered,
+    /// The status would be an illegal move from the contained state and
+    /// was ignored.
+    Invalid(TaskState),
+}
+
+/// Tracks every task the framework knows about through the `TaskState`
+/// lifecycle.  `SchedulerClient` registers launched tasks as STAGING and
+/// `ProtobufCallbackRouter` feeds it every UPDATE event.
+#[derive(Default)]
+pub struct TaskRegistry {
+    tasks: HashMap<String, TrackedTask>,
+}
+
+impl TaskRegistry {
+    pub fn new() -> TaskRegistry {
+        TaskRegistry { tasks: HashMap::new() }
+    }
+
+    /// Registers a task that is about to be launched as TASK_STAGING.
+    pub fn register(&mut self, task_info: &TaskInfo) {
+        let mut task = TrackedTask::new(task_info.get_task_id().clone(),
+                                        TaskState::TASK_STAGING);
+        task.name = Some(task_info.get_name().to_string());
+        task.slave_id = Some(task_info.get_slave_id().clone());
+        if task_info.has_executor() {
+            let executor = task_info.get_executor();
+            task.executor_id = Some(executor.get_executor_id().clone());
+        }
+        task.revocable = task_info.get_resources()
+                                  .iter()
+                                  .any(|r| r.has_revocable());
+        let kill_policy = task_info.get_kill_policy();
+        if kill_policy.has_grace_period() {
+            let grace_period = kill_policy.get_grace_period();
+            task.grace_period = Some(util::duration(grace_period));
+        }
+        self.tasks.insert(task_info.get_task_id().get_value().to_string(),
+                          task);
+    }
+
+    /// Applies a status update, returning how it affected the task.
+    pub fn update(&mut self, status: &TaskStatus) -> Transition {
+        let key = status.get_task_id().get_value();
+        let to = status.get_state();
+
+        if let Some(task) = self.tasks.get_mut(key) {
+            let from = task.state;
+            if from == to {
+                task.record(status);
+                return Transition::Duplicate;
+            }
+            if !is_valid_transition(from, to) {
+                return Transition::Invalid(from);
+            }
+            task.record(status);
+            return Transition::Changed(from);
+        }
+
+        let mut task = TrackedTask::new(status.get_task_id().clone(), to);
+        task.record(status);
+        self.tasks.insert(key.to_string(), task);
+        Transition::Discovered
+    }
+
+    pub fn get(&self, task_id: &TaskID) -> Option<&TrackedTask> {
+        self.tasks.get(task_id.get_value())
+    }
+
+    /// Forgets a task, typically once a terminal state has been handled.
+    pub fn remove(&mut self, task_id: &TaskID) -> Option<TrackedTask> {
+        self.tasks.remove(task_id.get_value())
+    }
+
+    /// Forgets every task in a terminal state.
+    pub fn remove_terminal(&mut self) -> Vec<TrackedTask> {
+        let terminal: Vec<String> = self.tasks
+                                        .iter()
+                                        .filter(|&(_, t)| t.is_terminal())
+                                        .map(|(k, _)| k.clone())
+                                        .collect();
+        terminal.iter()
+                .filter_map(|k| self.tasks.remove(k))
+                .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    pub fn tasks(&self) -> Vec<&TrackedTask> {
+        self.tasks.values().collect()
+    }
+
+    pub fn in_state(&self, state: TaskState) -> Vec<&TrackedTask> {
+        self.tasks.values().filter(|t| t.state == state).collect()
+    }
+
+    /// Tasks that have been launched but not yet reported by Mesos.
+    pub fn pending(&self) -> Vec<&TrackedTask> {
+        self.in_state(TaskState::TASK_STAGING)
+    }
+
+    pub fn running(&self) -> Vec<&TrackedTask> {
+        self.in_state(TaskState::TASK_RUNNING)
+    }
+
+    pub fn running_on(&self, slave_id: &SlaveID) -> Vec<&TrackedTask> {
+        self.tasks
+            .values()
+            .filter(|t| t.state == TaskState::TASK_RUNNING)
+            .filter(|t| t.slave_id.as_ref() == Some(slave_id))
+            .collect()
+    }
+
+    pub fn non_terminal(&self) -> Vec<&TrackedTask> {
+        self.tasks.values().filter(|t| !t.is_terminal()).collect()
+    }
+
+    pub fn terminal(&self) -> Vec<&TrackedTask> {
+        self.tasks.values().filter(|t| t.is_terminal()).collect()
+    }
+
+    /// The custom executors running non-terminal tasks, with their
+    /// agents.
+    pub fn executors(&self) -> Vec<(&ExecutorID, &SlaveID)> {
+        let mut executors: Vec<(&ExecutorID, &SlaveID)> = vec![];
+        for task in self.tasks.values().filter(|t| !t.is_terminal()) {
+            if let (Some(executor_id), Some(slave_id)) =
+                   (task.executor_id.as_ref(), task.slave_id.as_ref()) {
+                if !executors.contains(&(executor_id, slave_id)) {
+                    executors.push((executor_id, slave_id));
+                }
+            }
+        }
+        executors
+    }
+
+    /// The agent running `executor_id`, if it runs any non-terminal task.
+    pub fn executor_agent(&self, executor_id: &ExecutorID) -> Option<&SlaveID> {
+        self.executors()
+            .into_iter()
+            .find(|&(id, _)| id == executor_id)
+            .map(|(_, slave_id)| slave_id)
+    }
+
+    /// Tasks that ended because their revocable resources were reclaimed.
+    pub fn revoked(&self) -> Vec<&TrackedTask> {
+        self.tasks.values().filter(|t| t.was_revoked()).collect()
+    }
+}
@@ -1,8 +1,11 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use hyper::header::{Accept, ContentType, Headers, qitem};
 use hyper::mime::{Mime, SubLevel, TopLevel};
 use protobuf;
 
 use proto::mesos::*;
+use resources::{DiskSource, Resources};
 
 header! { (MesosStreamId, "Mesos-Stream-Id") => [String] }
 
@@ -49,6 +52,28 @@ pub fn task_id<'a>(id: &'a str) -> TaskID {
     task_id
 }
 
+pub fn executor_id<'a>(id: &'a str) -> ExecutorID {
+    let mut executor_id = ExecutorID::new();
+    executor_id.set_value(id.to_string());
+    executor_id
+}
+
+/// Checks `id` against the rules Mesos applies to the ids of tasks,
+/// executors and the like, returning why it is invalid.
+pub fn check_id(id: &str) -> Result<(), &'static str> {
+    if id.is_empty() {
+        Err("ids cannot be empty")
+    } else if id == "." || id == ".." {
+        Err("ids cannot be '.' or '..'")
+    } else if id.contains('/') || id.contains('\\') {
+        Err("ids cannot contain slashes")
+    } else if id.chars().any(|c| c.is_control()) {
+        Err("ids cannot contain control characters")
+    } else {
+        Ok(())
+    }
+}
+
 pub fn task_info<'a>(name: &'a str,
                      task_id: &TaskID,
                      slave_id: &SlaveID,
@@ -82,6 +107,24 @@ pub fn task_info_for_container<'a>(name: &'a str,
     task_info
 }
 
+/// A `TaskInfo` for a task run by `executor` rather than the built-in
+/// command executor.  Every task given the same executor on an agent
+/// shares one instance of it.
+pub fn task_info_for_executor<'a>(name: &'a str,
+                                  task_id: &TaskID,
+                                  slave_id: &SlaveID,
+                                  executor: &ExecutorInfo,
+                                  resources: Vec<Resource>)
+                                  -> TaskInfo {
+    let mut task_info = TaskInfo::new();
+    task_info.set_name(name.to_string());
+    task_info.set_task_id(task_id.clone());
+    task_info.set_slave_id(slave_id.clone());
+    task_info.set_executor(executor.clone());
+    task_info.set_resources(protobuf::RepeatedField::from_vec(resources));
+    task_info
+}
+
 pub fn launch_operation(task_infos: Vec<TaskInfo>) -> Offer_Operation {
     let mut launch = Offer_Operation_Launch::new();
     launch.set_task_infos(protobuf::RepeatedField::from_vec(task_infos));
@@ -92,6 +135,121 @@ pub fn launch_operation(task_infos: Vec<TaskInfo>) -> Offer_Operation {
     operation
 }
 
+pub fn reserve_operation(resources: Vec<Resource>) -> Offer_Operation {
+    let mut reserve = Offer_Operation_Reserve::new();
+    reserve.set_resources(protobuf::RepeatedField::from_vec(resources));
+
+    let mut operation = Offer_Operation::new();
+    operation.set_field_type(Offer_Operation_Type::RESERVE);
+    operation.set_reserve(reserve);
+    operation
+}
+
+pub fn unreserve_operation(resources: Vec<Resource>) -> Offer_Operation {
+    let mut unreserve = Offer_Operation_Unreserve::new();
+    unreserve.set_resources(protobuf::RepeatedField::from_vec(resources));
+
+    let mut operation = Offer_Operation::new();
+    operation.set_field_type(Offer_Operation_Type::UNRESERVE);
+    operation.set_unreserve(unreserve);
+    operation
+}
+
+pub fn create_operation(volumes: Vec<Resource>) -> Offer_Operation {
+    let mut create = Offer_Operation_Create::new();
+    create.set_volumes(protobuf::RepeatedField::from_vec(volumes));
+
+    let mut operation = Offer_Operation::new();
+    operation.set_field_type(Offer_Operation_Type::CREATE);
+    operation.set_create(create);
+    operation
+}
+
+pub fn destroy_operation(volumes: Vec<Resource>) -> Offer_Operation {
+    let mut destroy = Offer_Operation_Destroy::new();
+    destroy.set_volumes(protobuf::RepeatedField::from_vec(volumes));
+
+    let mut operation = Offer_Operation::new();
+    operation.set_field_type(Offer_Operation_Type::DESTROY);
+    operation.set_destroy(destroy);
+    operation
+}
+
+pub fn labels<'a>(pairs: &[(&'a str, &'a str)]) -> Labels {
+    let mut labels = Labels::new();
+    for &(key, value) in pairs {
+        let mut label = Label::new();
+        label.set_key(key.to_string());
+        label.set_value(value.to_string());
+        labels.mut_labels().push(label);
+    }
+    labels
+}
+
+pub fn duration_info(duration: Duration) -> DurationInfo {
+    let mut duration_info = DurationInfo::new();
+    duration_info.set_nanoseconds(nanoseconds(duration));
+    duration_info
+}
+
+/// The `Duration` of a `DurationInfo`; negative ones are zero.
+pub fn duration(duration_info: &DurationInfo) -> Duration {
+    from_nanoseconds(duration_info.get_nanoseconds().max(0))
+}
+
+/// A point in time as Mesos represents it, in nanoseconds since the
+/// epoch.
+pub fn time_info(time: SystemTime) -> TimeInfo {
+    let nanos = match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => nanoseconds(since),
+        Err(e) => -nanoseconds(e.duration()),
+    };
+    let mut time_info = TimeInfo::new();
+    time_info.set_nanoseconds(nanos);
+    time_info
+}
+
+pub fn system_time(time_info: &TimeInfo) -> SystemTime {
+    let nanos = time_info.get_nanoseconds();
+    if nanos < 0 {
+        UNIX_EPOCH - from_nanoseconds(nanos.saturating_neg())
+    } else {
+        UNIX_EPOCH + from_nanoseconds(nanos)
+    }
+}
+
+/// An `Unavailability` from `start` lasting `duration`, or forever.
+pub fn unavailability(start: SystemTime,
+                      duration: Option<Duration>)
+                      -> Unavailability {
+    let mut unavailability = Unavailability::new();
+    unavailability.set_start(time_info(start));
+    if let Some(duration) = duration {
+        unavailability.set_duration(duration_info(duration));
+    }
+    unavailability
+}
+
+// Saturates at i64::MAX nanoseconds, some 292 years.
+fn nanoseconds(duration: Duration) -> i64 {
+    (duration.as_secs().min(i64::MAX as u64) as i64)
+        .saturating_mul(1_000_000_000)
+        .saturating_add(duration.subsec_nanos() as i64)
+}
+
+fn from_nanoseconds(nanos: i64) -> Duration {
+    Duration::new((nanos / 1_000_000_000) as u64,
+                  (nanos % 1_000_000_000) as u32)
+}
+
+/// A `KillPolicy` giving a task `grace_period` between being asked to
+/// stop and being killed forcibly.
+pub fn kill_policy(grace_period: Duration) -> KillPolicy {
+    let mut kill_policy = KillPolicy::new();
+    kill_policy.set_grace_period(duration_info(grace_period));
+    kill_policy
+}
+
 pub fn scalar<'a>(name: &'a str, role: &'a str, value: f64) -> Resource {
     let mut scalar = Value_Scalar::new();
     scalar.set_value(value);
@@ -104,10 +262,135 @@ pub fn scalar<'a>(name: &'a str, role: &'a str, value: f64) -> Resource {
     res
 }
 
+/// A `disk` resource of `size` MB from `source`.
+pub fn disk<'a>(role: &'a str, size: f64, source: &DiskSource) -> Resource {
+    let mut res = scalar("disk", role, size);
+    if let Some(info) = source.source_info() {
+        res.mut_disk().set_source(info);
+    }
+    res
+}
+
+pub fn ranges<'a>(name: &'a str,
+                  role: &'a str,
+                  ranges: &[(u64, u64)])
+                  -> Resource {
+    let mut value = Value_Ranges::new();
+    for &(begin, end) in ranges {
+        let mut range = Value_Range::new();
+        range.set_begin(begin);
+        range.set_end(end);
+        value.mut_range().push(range);
+    }
+
+    let mut res = Resource::new();
+    res.set_name(name.to_string());
+    res.set_role(role.to_string());
+    res.set_field_type(Value_Type::RANGES);
+    res.set_ranges(value);
+    res
+}
+
+pub fn set<'a>(name: &'a str, role: &'a str, items: &[&'a str]) -> Resource {
+    let mut value = Value_Set::new();
+    for item in items {
+        value.mut_item().push(item.to_string());
+    }
+
+    let mut res = Resource::new();
+    res.set_name(name.to_string());
+    res.set_role(role.to_string());
+    res.set_field_type(Value_Type::SET);
+    res.set_set(value);
+    res
+}
+
+/// Splits `offers` into copies holding only their revocable resources
+/// and copies holding only the rest, e.g. to place best-effort work on
+/// the former.  Offers without resources of a kind are left out of that
+/// side.  The copies keep their offer ids, so either can be accepted.
+pub fn split_revocable(offers: &[&Offer]) -> (Vec<Offer>, Vec<Offer>) {
+    let restrict = |offer: &Offer, revocable: bool| {
+        let resources: Vec<Resource> = offer.get_resources()
+                                            .iter()
+                                            .filter(|r| {
+                                                r.has_revocable() == revocable
+                                            })
+                                            .cloned()
+                                            .collect();
+        if resources.is_empty() {
+            return None;
+        }
+        let mut copy = offer.clone();
+        copy.set_resources(protobuf::RepeatedField::from_vec(resources));
+        Some(copy)
+    };
+    (offers.iter().filter_map(|o| restrict(o, true)).collect(),
+     offers.iter().filter_map(|o| restrict(o, false)).collect())
+}
+
+/// The resources in `offers` per role, in role order, e.g. to see how
+/// much is reserved for the framework's roles and how much is shared.
+pub fn role_breakdown(offers: &[&Offer]) -> Vec<(String, Resources)> {
+    let resources: Vec<Resource> = offers.iter()
+                                         .flat_map(|o| o.get_resources())
+                                         .cloned()
+                                         .collect();
+    Resources::from_vec(resources).by_role()
+}
+
+/// The total of the scalar resource `name` in `offers`, reserved and
+/// revocable resources included.  Tasks asking for unreserved (`*`)
+/// resources cannot use all of it; see `role_breakdown` and
+/// `Resources::non_revocable` to count only what they can.
 pub fn get_scalar_resource_sum<'a>(name: &'a str, offers: Vec<&Offer>) -> f64 {
     offers.iter()
-          .flat_map(|o| o.get_resources())
-          .filter(|r| r.get_name() == name)
-          .map(|c| c.get_scalar())
-          .fold(0f64, |acc, mem_res| acc + mem_res.get_value())
+          .map(|o| sum_scalar_resources(name, o.get_resources()))
+          .fold(0f64, |acc, sum| acc + sum)
+}
+
+/// The disk offered in `offer` per disk source.  Unlike
+/// `get_scalar_resource_sum`, this keeps MOUNT disks, which can only be
+/// used whole, apart from the rest.
+pub fn disk_capacity(offer: &Offer) -> Vec<(DiskSource, f64)> {
+    Resources::from_vec(offer.get_resources().to_vec()).disk_capacity()
+}
+
+pub fn sum_scalar_resources<'a>(name: &'a str, resources: &[Resource]) -> f64 {
+    resources.iter()
+             .filter(|r| r.get_name() == name)
+             .map(|c| c.get_scalar())
+             .fold(0f64, |acc, res| acc + res.get_value())
+}
+
+/// Renders the value of the agent attribute `name` from an offer as a
+/// string: TEXT verbatim, SCALAR as a number, RANGES as `[a-b,c-d]` and
+/// SET as `{a,b}`.
+pub fn get_attribute_value<'a>(offer: &Offer, name: &'a str) -> Option<String> {
+    offer.get_attributes()
+         .iter()
+         .find(|a| a.get_name() == name)
+         .map(|a| {
+             match a.get_field_type() {
+                 Value_Type::TEXT => a.get_text().get_value().to_string(),
+                 Value_Type::SCALAR => {
+                     format!("{}", a.get_scalar().get_value())
+                 }
+                 Value_Type::RANGES => {
+                     let ranges: Vec<String> = a.get_ranges()
+                                                .get_range()
+                                                .iter()
+                                                .map(|r| {
+                                                    format!("{}-{}",
+                                                            r.get_begin(),
+                                                            r.get_end())
+                                                })
+                                                .collect();
+                     format!("[{}]", ranges.join(","))
+                 }
+                 Value_Type::SET => {
+                     format!("{{{}}}", a.get_set().get_item().join(","))
+                 }
+             }
+         })
 }
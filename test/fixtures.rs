@@ -0,0 +1,44 @@
+use mesos::proto::*;
+use mesos::util;
+
+pub fn slave_id(id: &str) -> SlaveID {
+    let mut slave_id = SlaveID::new();
+    slave_id.set_value(id.to_string());
+    slave_id
+}
+
+pub fn offer_id(id: &str) -> OfferID {
+    let mut offer_id = OfferID::new();
+    offer_id.set_value(id.to_string());
+    offer_id
+}
+
+pub fn offer(id: &str, slave: &str, resources: Vec<Resource>) -> Offer {
+    let mut offer = Offer::new();
+    offer.set_id(offer_id(id));
+    offer.set_framework_id(util::framework_id("framework"));
+    offer.set_slave_id(slave_id(slave));
+    offer.set_hostname(format!("{}.example.com", slave));
+    for resource in resources {
+        offer.mut_resources().push(resource);
+    }
+    offer
+}
+
+pub fn status(id: &str, state: TaskState) -> TaskStatus {
+    let mut status = TaskStatus::new();
+    status.set_task_id(util::task_id(id));
+    status.set_state(state);
+    status
+}
+
+pub fn text_attribute(name: &str, value: &str) -> Attribute {
+    let mut text = Value_Text::new();
+    text.set_value(value.to_string());
+
+    let mut attribute = Attribute::new();
+    attribute.set_name(name.to_string());
+    attribute.set_field_type(Value_Type::TEXT);
+    attribute.set_text(text);
+    attribute
+}
@@ -1,3 +1,25 @@
 extern crate mesos;
+extern crate protobuf;
 
+mod fixtures;
+mod test_command;
+mod test_constraints;
+mod test_container;
+mod test_executor;
+mod test_filters;
+mod test_health_check;
+mod test_kill_tracker;
+mod test_maintenance;
+mod test_message_channel;
+mod test_network;
+mod test_offer_pool;
+mod test_persistent_volume;
+mod test_placement;
+mod test_ports;
+mod test_reconciler;
+mod test_reservation;
+mod test_resources;
 mod test_scheduler;
+mod test_task_builder;
+mod test_task_queue;
+mod test_task_registry;
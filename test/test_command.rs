@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use mesos::command::{self, CommandError, FetchUri, Secret};
+use mesos::CommandBuilder;
+
+#[test]
+fn command_builder_builds_shell_and_exec_commands() {
+    let shell = CommandBuilder::shell("env && sleep 10").build().unwrap();
+    assert!(shell.get_shell());
+    assert_eq!(shell.get_value(), "env && sleep 10");
+    assert!(shell.get_arguments().is_empty());
+
+    let exec = CommandBuilder::exec("/usr/bin/redis-server")
+                   .args(&["--port", "6379"])
+                   .user("redis")
+                   .build()
+                   .unwrap();
+    assert!(exec.has_shell() && !exec.get_shell());
+    assert_eq!(exec.get_value(), "/usr/bin/redis-server");
+    assert_eq!(exec.get_arguments(),
+               &["/usr/bin/redis-server".to_string(),
+                 "--port".to_string(),
+                 "6379".to_string()]);
+    assert_eq!(exec.get_user(), "redis");
+}
+
+#[test]
+fn command_builder_sets_environment_and_fetcher_uris() {
+    let mut vars = HashMap::new();
+    vars.insert("B".to_string(), "2".to_string());
+    vars.insert("A".to_string(), "1".to_string());
+    let builder = CommandBuilder::shell("./app")
+                      .envs(&vars)
+                      .secret("TOKEN", Secret::new("hunter2"))
+                      .uri("http://example.com/config.json")
+                      .fetch(FetchUri::new("http://example.com/app.tgz")
+                                 .cache()
+                                 .extract(false)
+                                 .filename("app.tgz"));
+    assert!(!format!("{:?}", builder).contains("hunter2"));
+    assert_eq!(builder.secret_names(), vec!["TOKEN"]);
+    let secrets = builder.secret_names()
+                         .iter()
+                         .map(|s| s.to_string())
+                         .collect::<Vec<String>>();
+
+    let command = builder.build().unwrap();
+    let variables: Vec<(&str, &str)> = command.get_environment()
+                                              .get_variables()
+                                              .iter()
+                                              .map(|v| {
+                                                  (v.get_name(),
+                                                   v.get_value())
+                                              })
+                                              .collect();
+    assert_eq!(variables,
+               vec![("A", "1"), ("B", "2"), ("TOKEN", "hunter2")]);
+    let uris = command.get_uris();
+    assert!(uris[0].get_extract() && !uris[0].get_cache());
+    assert!(uris[1].get_cache() && !uris[1].get_extract());
+    assert_eq!(uris[1].get_filename(), "app.tgz");
+
+    let secrets: Vec<&str> = secrets.iter().map(|s| &s[..]).collect();
+    let redacted = command::redact(&command, &secrets);
+    assert!(!format!("{:?}", redacted).contains("hunter2"));
+    assert_eq!(redacted.get_environment().get_variables()[2].get_value(),
+               command::REDACTED);
+}
+
+#[test]
+fn command_builder_rejects_what_cannot_run() {
+    assert_eq!(CommandBuilder::shell("").build(),
+               Err(CommandError::MissingValue));
+    assert_eq!(CommandBuilder::shell("echo").arg("hi").build(),
+               Err(CommandError::ArgumentsWithShell));
+    assert_eq!(CommandBuilder::exec("echo").env("A=B", "c").build(),
+               Err(CommandError::InvalidVariableName("A=B".to_string())));
+    assert_eq!(CommandBuilder::exec("echo")
+                   .env("A", "1")
+                   .secret("A", Secret::new("2"))
+                   .build(),
+               Err(CommandError::DuplicateVariable("A".to_string())));
+    assert_eq!(CommandBuilder::exec("echo").uri("").build(),
+               Err(CommandError::EmptyUri));
+    assert_eq!(CommandBuilder::exec("echo")
+                   .fetch(FetchUri::new("http://x/y").filename("../y"))
+                   .build(),
+               Err(CommandError::InvalidFilename("../y".to_string())));
+}
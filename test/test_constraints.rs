@@ -0,0 +1,101 @@
+use mesos::Constraint;
+use mesos::constraints::{ConstraintError, Operator, Placements};
+use mesos::proto::*;
+use mesos::util;
+
+use fixtures::{offer, text_attribute};
+
+fn agent(slave: &str, rack: &str) -> Offer {
+    let mut offer = offer(slave, slave, vec![util::scalar("cpus", "*", 1f64)]);
+    offer.mut_attributes().push(text_attribute("rack", rack));
+    offer
+}
+
+fn parse(expression: &str) -> Constraint {
+    expression.parse().unwrap()
+}
+
+#[test]
+fn constraints_parse_and_report_errors() {
+    assert_eq!(parse("hostname:unique"),
+               Constraint::new("hostname", Operator::Unique));
+    assert_eq!(parse("rack:GROUP_BY"),
+               Constraint::new("rack", Operator::GroupBy(None)));
+    assert_eq!(parse("rack:MAX_PER:2").to_string(), "rack:MAX_PER:2");
+    // the value of LIKE may itself contain colons
+    assert_eq!(parse("zone:LIKE:a:b|c").to_string(), "zone:LIKE:a:b|c");
+    assert_eq!(Constraint::from_parts(&["rack", "CLUSTER", "r1"]).unwrap(),
+               parse("rack:CLUSTER:r1"));
+
+    let error = |expression: &str| {
+        expression.parse::<Constraint>().unwrap_err()
+    };
+    assert_eq!(error(":UNIQUE"), ConstraintError::MissingField);
+    assert_eq!(error("rack"),
+               ConstraintError::MissingOperator("rack".to_string()));
+    assert_eq!(error("rack:NEAR").to_string(),
+               "unknown constraint operator NEAR, expected one of UNIQUE, \
+                CLUSTER, GROUP_BY, LIKE, UNLIKE, MAX_PER");
+    assert_eq!(error("rack:UNIQUE:r1"),
+               ConstraintError::UnexpectedValue("UNIQUE".to_string(),
+                                                "r1".to_string()));
+    assert_eq!(error("rack:MAX_PER"),
+               ConstraintError::MissingValue("MAX_PER".to_string()));
+    assert_eq!(error("rack:GROUP_BY:0").to_string(),
+               "GROUP_BY needs a positive number, got \"0\"");
+    match error("rack:LIKE:r[") {
+        ConstraintError::InvalidRegex(ref pattern, _) => {
+            assert_eq!(pattern, "r[")
+        }
+        other => panic!("expected an invalid regex, got {:?}", other),
+    }
+}
+
+#[test]
+fn constraints_evaluate_against_running_tasks() {
+    let r1 = agent("s1", "r1");
+    let r2 = agent("s2", "r2");
+    let bare = offer("s3", "s3", vec![]);
+    let placed = vec!["r1".to_string(), "r1".to_string()];
+
+    let like = parse("rack:LIKE:r[0-1]");
+    assert!(like.matches(&r1, &[]));
+    assert!(!like.matches(&r2, &[]));
+    assert!(!like.matches(&bare, &[]));
+    // patterns have to match the whole value
+    assert!(!parse("rack:LIKE:r").matches(&r1, &[]));
+    assert!(parse("rack:UNLIKE:r1").matches(&bare, &[]));
+
+    assert!(!parse("rack:UNIQUE").matches(&r1, &placed));
+    assert!(parse("rack:UNIQUE").matches(&r2, &placed));
+    assert!(parse("rack:CLUSTER").matches(&r2, &[]));
+    assert!(!parse("rack:CLUSTER").matches(&r2, &placed));
+    assert!(parse("rack:MAX_PER:3").matches(&r1, &placed));
+    assert!(!parse("rack:MAX_PER:2").matches(&r1, &placed));
+
+    let spread = vec!["r1".to_string(), "r2".to_string()];
+    assert!(parse("rack:GROUP_BY").matches(&r1, &spread));
+    let uneven = vec!["r1".to_string(), "r1".to_string(), "r2".to_string()];
+    assert!(!parse("rack:GROUP_BY").matches(&r1, &uneven));
+    assert!(parse("rack:GROUP_BY").matches(&r2, &uneven));
+    // a third rack has yet to receive a task
+    assert!(!parse("rack:GROUP_BY:3").matches(&r1, &spread));
+}
+
+#[test]
+fn placements_track_tasks_by_group() {
+    let r1 = agent("s1", "r1");
+    let r2 = agent("s2", "r2");
+    let mut placements = Placements::new();
+    placements.record("web", &util::task_id("web-1"), &r1);
+    placements.record("db", &util::task_id("db-1"), &r2);
+
+    let unique = vec![parse("rack:UNIQUE")];
+    assert!(!placements.allows("web", &unique, &r1));
+    assert!(placements.allows("web", &unique, &r2));
+    assert_eq!(placements.values("web", "hostname"),
+               vec!["s1.example.com".to_string()]);
+
+    placements.forget(&util::task_id("web-1"));
+    assert!(placements.allows("web", &unique, &r1));
+}
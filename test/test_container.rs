@@ -0,0 +1,108 @@
+use mesos::container::{self, ContainerError, DockerNetwork};
+use mesos::proto::*;
+use mesos::{DockerBuilder, MesosBuilder, PortAllocator, util};
+
+use fixtures::offer;
+
+#[test]
+fn docker_builder_maps_allocated_ports() {
+    let a = offer("a",
+                  "s1",
+                  vec![util::ranges("ports", "*", &[(31000, 31001)])]);
+    let ports = PortAllocator::new(&[&a]).allocate(2).unwrap();
+
+    let container = DockerBuilder::new("nginx:1.11")
+                        .network(DockerNetwork::Bridge)
+                        .ports(&ports, &[80, 443], "tcp")
+                        .parameter("label", "tier=web")
+                        .volume("/var/log/nginx", "/logs", Volume_Mode::RW)
+                        .hostname("web")
+                        .build()
+                        .unwrap();
+    assert_eq!(container.get_field_type(), ContainerInfo_Type::DOCKER);
+    let docker = container.get_docker();
+    assert_eq!(docker.get_network(), ContainerInfo_DockerInfo_Network::BRIDGE);
+    let mappings: Vec<(u32, u32)> = docker.get_port_mappings()
+                                          .iter()
+                                          .map(|m| {
+                                              (m.get_host_port(),
+                                               m.get_container_port())
+                                          })
+                                          .collect();
+    assert_eq!(mappings, vec![(31000, 80), (31001, 443)]);
+    assert_eq!(docker.get_parameters()[0].get_value(), "tier=web");
+    assert_eq!(container.get_volumes()[0].get_host_path(), "/var/log/nginx");
+
+    let user = DockerBuilder::new("nginx")
+                   .network(DockerNetwork::User("overlay".to_string()))
+                   .port_mapping(31000, 80, "tcp")
+                   .build()
+                   .unwrap();
+    assert_eq!(user.get_docker().get_network(),
+               ContainerInfo_DockerInfo_Network::USER);
+    assert_eq!(user.get_network_infos()[0].get_name(), "overlay");
+    assert!(format!("{:?}", user).contains("network: USER"));
+}
+
+#[test]
+fn docker_builder_rejects_incompatible_settings() {
+    assert_eq!(DockerBuilder::new("nginx")
+                   .port_mapping(31000, 80, "tcp")
+                   .build()
+                   .unwrap_err()
+                   .to_string(),
+               "port mappings need a BRIDGE or USER network, not HOST");
+    assert_eq!(DockerBuilder::new("nginx").hostname("web").build(),
+               Err(ContainerError::HostnameOnHostNetwork));
+    assert_eq!(DockerBuilder::new("nginx")
+                   .network(DockerNetwork::Bridge)
+                   .port_mapping(31000, 80, "tcp")
+                   .port_mapping(31000, 443, "tcp")
+                   .build(),
+               Err(ContainerError::DuplicateHostPort(31000)));
+    assert_eq!(DockerBuilder::new("nginx")
+                   .network(DockerNetwork::User(String::new()))
+                   .build(),
+               Err(ContainerError::MissingNetworkName));
+    assert_eq!(DockerBuilder::new("").build(),
+               Err(ContainerError::MissingImage));
+}
+
+#[test]
+fn mesos_builder_provisions_images() {
+    let mut network = NetworkInfo::new();
+    network.set_name("calico".to_string());
+
+    let container = MesosBuilder::new()
+                        .docker_image("registry.local/app:2")
+                        .credential("deploy", "s3cret")
+                        .image_volume(container::appc_image("tools", None),
+                                      "/opt/tools",
+                                      Volume_Mode::RO)
+                        .network(network)
+                        .build()
+                        .unwrap();
+    assert_eq!(container.get_field_type(), ContainerInfo_Type::MESOS);
+    assert!(!container.has_docker());
+    let image = container.get_mesos().get_image();
+    assert_eq!(image.get_field_type(), Image_Type::DOCKER);
+    assert_eq!(image.get_docker().get_credential().get_principal(), "deploy");
+    let volume = &container.get_volumes()[0];
+    assert_eq!(volume.get_image().get_appc().get_name(), "tools");
+    assert_eq!(container.get_network_infos()[0].get_name(), "calico");
+
+    assert_eq!(MesosBuilder::new()
+                   .appc_image("app")
+                   .credential("deploy", "s3cret")
+                   .build(),
+               Err(ContainerError::CredentialWithoutDockerImage));
+
+    let mut both = container.clone();
+    both.set_docker(DockerBuilder::new("app")
+                        .build()
+                        .unwrap()
+                        .get_docker()
+                        .clone());
+    assert_eq!(container::validate(&both).unwrap_err().to_string(),
+               "container cannot set both docker and mesos");
+}
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use mesos::command::CommandError;
+use mesos::executor::ExecutorError;
+use mesos::proto::*;
+use mesos::{CommandBuilder, ExecutorBuilder, TaskQueue, TaskRegistry,
+            TaskSpec, util};
+
+use fixtures::{offer, slave_id};
+
+fn executor(id: &str) -> ExecutorInfo {
+    let command = CommandBuilder::exec("./executor").build().unwrap();
+    ExecutorBuilder::new(id, command)
+        .name("worker executor")
+        .source("worker")
+        .resource(util::scalar("cpus", "*", 0.5f64))
+        .shutdown_grace_period(Duration::from_secs(5))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn executor_builder_builds_and_validates() {
+    let executor = executor("worker");
+    assert_eq!(executor.get_executor_id().get_value(), "worker");
+    assert_eq!(executor.get_source(), "worker");
+    assert_eq!(executor.get_shutdown_grace_period().get_nanoseconds(),
+               5_000_000_000);
+
+    let command = CommandBuilder::exec("./executor").build().unwrap();
+    assert_eq!(ExecutorBuilder::new("a/b", command).build().unwrap_err(),
+               ExecutorError::InvalidExecutorId("a/b".to_string(),
+                                                "ids cannot contain \
+                                                 slashes"));
+    let mut empty = CommandInfo::new();
+    empty.set_shell(true);
+    assert_eq!(ExecutorBuilder::new("x", empty).build().unwrap_err(),
+               ExecutorError::InvalidCommand(CommandError::MissingValue));
+}
+
+#[test]
+fn task_queue_accounts_for_executor_resources_once_per_agent() {
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 2.5f64)]);
+    let cpus = vec![util::scalar("cpus", "*", 1f64)];
+
+    let mut queue = TaskQueue::new();
+    queue.push(TaskSpec::for_executor("w", executor("worker"), cpus.clone()));
+    queue.push(TaskSpec::for_executor("w", executor("worker"), cpus.clone()));
+    queue.push(TaskSpec::for_executor("w", executor("worker"), cpus.clone()));
+    let launches = queue.match_offers(vec![&a]);
+    let tasks = &launches[0].tasks;
+    // 0.5 for the executor and 1 for each of two tasks
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(queue.len(), 1);
+    assert!(!tasks[0].has_command());
+    assert_eq!(tasks[0].get_executor(), tasks[1].get_executor());
+    assert_eq!(util::sum_scalar_resources("cpus",
+                                          tasks[0].get_executor()
+                                                  .get_resources()),
+               0.5f64);
+
+    // while one task runs the executor stays up and needs nothing more
+    queue.forget(tasks[0].get_task_id());
+    let b = offer("b", "s1", vec![util::scalar("cpus", "*", 1f64)]);
+    assert_eq!(queue.match_offers(vec![&b]).len(), 1);
+
+    let mut registry = TaskRegistry::new();
+    registry.register(&tasks[1]);
+    assert_eq!(registry.executor_agent(&util::executor_id("worker")),
+               Some(&slave_id("s1")));
+    assert_eq!(registry.executors().len(), 1);
+    assert_eq!(registry.executor_agent(&util::executor_id("other")), None);
+}
@@ -0,0 +1,58 @@
+use std::f64;
+use std::time::{Duration, Instant};
+
+use mesos::filters::FilterError;
+use mesos::{FilterPolicy, FiltersBuilder};
+
+use fixtures::slave_id;
+
+#[test]
+fn filters_builder_sets_refuse_seconds() {
+    let filters = FiltersBuilder::new()
+                      .refuse(Duration::from_millis(1500))
+                      .build()
+                      .unwrap();
+    assert_eq!(filters.get_refuse_seconds(), 1.5f64);
+    assert!(!FiltersBuilder::new().build().unwrap().has_refuse_seconds());
+
+    assert_eq!(FiltersBuilder::new().refuse_seconds(-1f64).build(),
+               Err(FilterError::InvalidRefuseSeconds(-1f64)));
+    assert!(FiltersBuilder::new()
+                .refuse_seconds(f64::NAN)
+                .build()
+                .is_err());
+}
+
+#[test]
+fn filter_policy_backs_off_per_agent_while_work_is_pending() {
+    let now = Instant::now();
+    let mut policy = FilterPolicy::new(Duration::from_secs(5),
+                                       Duration::from_secs(60));
+    let s1 = slave_id("s1");
+    let mut refusals = vec![];
+    for _ in 0..6 {
+        refusals.push(policy.decline(&s1, true, now).get_refuse_seconds());
+    }
+    assert_eq!(refusals, vec![5f64, 10f64, 20f64, 40f64, 60f64, 60f64]);
+    assert_eq!(policy.refusal(&slave_id("s2")), Duration::from_secs(5));
+    assert!(!policy.needs_revive(now));
+
+    policy.used(&s1);
+    assert_eq!(policy.decline(&s1, true, now).get_refuse_seconds(), 5f64);
+}
+
+#[test]
+fn filter_policy_refuses_long_when_idle_until_revived() {
+    let now = Instant::now();
+    let mut policy = FilterPolicy::default();
+    let s1 = slave_id("s1");
+    policy.decline(&s1, true, now);
+    assert_eq!(policy.decline(&s1, false, now).get_refuse_seconds(),
+               300f64);
+    assert!(policy.needs_revive(now + Duration::from_secs(299)));
+    assert!(!policy.needs_revive(now + Duration::from_secs(300)));
+
+    policy.revived();
+    assert!(!policy.needs_revive(now));
+    assert_eq!(policy.refusal(&s1), Duration::from_secs(5));
+}
@@ -0,0 +1,113 @@
+use mesos::health_check::{self, HealthCheckError};
+use mesos::proto::*;
+use mesos::{HealthCheckBuilder, HealthMonitor, TaskQueue, TaskSpec,
+            UnhealthyPolicy, util};
+
+use fixtures::{offer, status};
+
+#[test]
+fn health_check_builder_builds_http_and_command_checks() {
+    let http = HealthCheckBuilder::http(8080)
+                   .path("/health")
+                   .status(200)
+                   .status(204)
+                   .interval_seconds(5f64)
+                   .consecutive_failures(2)
+                   .grace_period_seconds(30f64)
+                   .build()
+                   .unwrap();
+    assert_eq!(http.get_http().get_port(), 8080);
+    assert_eq!(http.get_http().get_path(), "/health");
+    assert_eq!(http.get_http().get_statuses(), &[200, 204]);
+    assert_eq!(http.get_interval_seconds(), 5f64);
+    assert_eq!(http.get_consecutive_failures(), 2);
+    // untouched settings keep Mesos' defaults
+    assert_eq!(http.get_timeout_seconds(), 20f64);
+
+    let command = HealthCheckBuilder::shell("pg_isready").build().unwrap();
+    assert_eq!(command.get_command().get_value(), "pg_isready");
+    assert!(!command.has_http());
+}
+
+#[test]
+fn health_check_builder_rejects_checks_that_cannot_pass() {
+    assert_eq!(HealthCheckBuilder::http(0).build().unwrap_err(),
+               HealthCheckError::MissingPort);
+    assert_eq!(HealthCheckBuilder::http(80).path("health").build(),
+               Err(HealthCheckError::RelativePath("health".to_string())));
+    assert_eq!(HealthCheckBuilder::http(80).status(42).build(),
+               Err(HealthCheckError::InvalidStatus(42)));
+    assert_eq!(HealthCheckBuilder::shell("").build(),
+               Err(HealthCheckError::EmptyCommand));
+    assert_eq!(HealthCheckBuilder::shell("true")
+                   .delay_seconds(-1f64)
+                   .build(),
+               Err(HealthCheckError::InvalidSeconds("delay_seconds",
+                                                    -1f64)));
+    assert_eq!(HealthCheckBuilder::shell("true")
+                   .consecutive_failures(0)
+                   .build(),
+               Err(HealthCheckError::Zero("consecutive_failures")));
+}
+
+#[test]
+fn health_changes_are_detected_and_policies_applied() {
+    let running = status("a", TaskState::TASK_RUNNING);
+    assert_eq!(health_check::health_change(None, &running), None);
+    let mut healthy = running.clone();
+    healthy.set_healthy(true);
+    assert_eq!(health_check::health_change(None, &healthy), Some(true));
+    assert_eq!(health_check::health_change(Some(true), &healthy), None);
+    let mut unhealthy = running.clone();
+    unhealthy.set_healthy(false);
+    assert_eq!(health_check::health_change(Some(true), &unhealthy),
+               Some(false));
+
+    let mut monitor = HealthMonitor::default();
+    assert!(!monitor.kills());
+    monitor.set_policy(UnhealthyPolicy::Kill);
+    assert!(monitor.kills());
+    assert!(!monitor.replaces());
+    monitor.set_policy(UnhealthyPolicy::Replace);
+    assert!(monitor.kills());
+    assert!(monitor.replaces());
+}
+
+#[test]
+fn task_queue_attaches_checks_and_returns_specs_for_replacement() {
+    let mut command = CommandInfo::new();
+    command.set_value("nginx".to_string());
+    let mut spec = TaskSpec::new("web",
+                                 command,
+                                 vec![util::scalar("cpus", "*", 1f64)]);
+    spec.health_check = Some(HealthCheckBuilder::http(80).build().unwrap());
+
+    let mut queue = TaskQueue::new();
+    queue.push(spec);
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 1f64)]);
+    let launches = queue.match_offers(vec![&a]);
+    let task = &launches[0].tasks[0];
+    assert_eq!(task.get_health_check().get_http().get_port(), 80);
+
+    let spec = queue.forget(task.get_task_id()).unwrap();
+    assert_eq!(spec.name, "web");
+    assert!(queue.forget(task.get_task_id()).is_none());
+
+    // a task marked for replacement comes back once, with a fresh id
+    queue.push(spec);
+    let launches = queue.match_offers(vec![&a]);
+    let task_id = launches[0].tasks[0].get_task_id();
+    assert!(queue.replace(task_id));
+    assert!(!queue.replace(&util::task_id("elsewhere")));
+    let spec = queue.terminated(task_id).unwrap();
+    assert_eq!(spec.name, "web");
+    assert!(spec.task_id.is_none());
+    assert!(queue.terminated(task_id).is_none());
+
+    // other tasks are just forgotten
+    queue.push(spec);
+    let launches = queue.match_offers(vec![&a]);
+    let task_id = launches[0].tasks[0].get_task_id();
+    assert!(queue.terminated(task_id).is_none());
+    assert!(queue.placements().is_empty());
+}
@@ -0,0 +1,93 @@
+use std::time::{Duration, Instant};
+
+use protobuf::{self, Message};
+
+use mesos::kill_tracker::{KillProgress, KillTracker};
+use mesos::proto::*;
+use mesos::{TaskRegistry, util};
+
+use fixtures::{slave_id, status};
+
+#[test]
+fn kill_tracker_follows_kills_and_retries_overdue_ones() {
+    let start = Instant::now();
+    let mut kills = KillTracker::new(Duration::from_secs(10));
+    kills.start(&util::task_id("a"),
+                Some(slave_id("s1")),
+                Some(Duration::from_secs(5)),
+                start);
+    kills.start(&util::task_id("b"), None, None, start);
+    assert_eq!(kills.len(), 2);
+
+    assert!(kills.poll(start + Duration::from_secs(9)).is_empty());
+    let retries = kills.poll(start + Duration::from_secs(10));
+    assert_eq!(retries.len(), 1);
+    assert_eq!(retries[0].task_id.get_value(), "b");
+    assert_eq!(retries[0].attempts, 2);
+
+    assert_eq!(kills.observe(&status("a", TaskState::TASK_KILLING)),
+               Some(KillProgress::Killing));
+    assert_eq!(kills.observe(&status("a", TaskState::TASK_KILLING)), None);
+    assert!(kills.get(&util::task_id("a")).unwrap().killing);
+    let retries = kills.poll(start + Duration::from_secs(15));
+    assert_eq!(retries.len(), 1);
+    assert_eq!(retries[0].slave_id, Some(slave_id("s1")));
+
+    assert_eq!(kills.observe(&status("a", TaskState::TASK_KILLED)),
+               Some(KillProgress::Terminated(TaskState::TASK_KILLED)));
+    assert_eq!(kills.observe(&status("b", TaskState::TASK_FINISHED)),
+               Some(KillProgress::Terminated(TaskState::TASK_FINISHED)));
+    assert_eq!(kills.observe(&status("c", TaskState::TASK_KILLED)), None);
+    assert!(kills.is_empty());
+}
+
+#[test]
+fn kill_calls_carry_the_kill_policy() {
+    let kill_policy = util::kill_policy(Duration::from_millis(1500));
+    assert_eq!(kill_policy.get_grace_period().get_nanoseconds(),
+               1_500_000_000);
+
+    let mut kill = scheduler::Call_Kill::new();
+    kill.set_task_id(util::task_id("a"));
+    kill.set_kill_policy(kill_policy);
+    let bytes = kill.write_to_bytes().unwrap();
+    let parsed: scheduler::Call_Kill = protobuf::parse_from_bytes(&bytes)
+                                           .unwrap();
+    assert_eq!(parsed, kill);
+    assert!(format!("{:?}", parsed).contains("kill_policy"));
+}
+
+#[test]
+fn kill_tracker_gives_the_grace_period_again_before_forcing() {
+    let mut task_info = util::task_info("a",
+                                        &util::task_id("a"),
+                                        &slave_id("s1"),
+                                        &CommandInfo::new(),
+                                        vec![]);
+    task_info.set_kill_policy(util::kill_policy(Duration::from_secs(60)));
+    let mut registry = TaskRegistry::new();
+    registry.register(&task_info);
+    let grace_period = registry.get(&util::task_id("a"))
+                               .unwrap()
+                               .grace_period;
+    assert_eq!(grace_period, Some(Duration::from_secs(60)));
+
+    let start = Instant::now();
+    let at = |secs| start + Duration::from_secs(secs);
+    let mut kills = KillTracker::new(Duration::from_secs(30));
+    kills.start(&util::task_id("a"), None, grace_period, start);
+
+    assert!(kills.poll(at(89)).is_empty());
+    let retries = kills.poll(at(90));
+    assert!(!retries[0].is_forced());
+    assert_eq!(retries[0].kill_policy(),
+               Some(util::kill_policy(Duration::from_secs(60))));
+
+    assert!(kills.poll(at(179)).is_empty());
+    let retries = kills.poll(at(180));
+    assert!(retries[0].is_forced());
+    assert_eq!(retries[0].kill_policy(),
+               Some(util::kill_policy(Duration::from_secs(0))));
+    assert!(kills.poll(at(209)).is_empty());
+    assert_eq!(kills.poll(at(210)).len(), 1);
+}
@@ -0,0 +1,177 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use protobuf::{self, Message};
+
+use mesos::maintenance::{self, DrainStep, Maintenance, Window};
+use mesos::proto::*;
+use mesos::{TaskRegistry, util};
+
+use fixtures::{offer, offer_id, slave_id, status};
+
+fn inverse_offer(id: &str, slave: &str, start_secs: u64) -> InverseOffer {
+    let start = UNIX_EPOCH + Duration::from_secs(start_secs);
+    let unavailability = util::unavailability(start,
+                                              Some(Duration::from_secs(3600)));
+
+    let mut inverse_offer = InverseOffer::new();
+    inverse_offer.set_id(offer_id(id));
+    inverse_offer.set_framework_id(util::framework_id("framework"));
+    inverse_offer.set_slave_id(slave_id(slave));
+    inverse_offer.set_unavailability(unavailability);
+    inverse_offer
+}
+
+fn registry() -> TaskRegistry {
+    let mut registry = TaskRegistry::new();
+    for &(id, slave) in &[("a", "s1"), ("b", "s1"), ("c", "s2")] {
+        registry.register(&util::task_info(id,
+                                           &util::task_id(id),
+                                           &slave_id(slave),
+                                           &CommandInfo::new(),
+                                           vec![]));
+    }
+    registry.update(&status("b", TaskState::TASK_FINISHED));
+    registry
+}
+
+#[test]
+fn maintenance_drains_agents_before_accepting() {
+    let mut registry = registry();
+    let mut maintenance = Maintenance::new(Duration::from_secs(600));
+    assert!(maintenance.drain(&inverse_offer("i1", "s1", 10_000)));
+    assert!(!maintenance.is_draining(&slave_id("s1")));
+
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    assert!(maintenance.poll(&registry, at(9_000)).is_empty());
+    assert_eq!(maintenance.poll(&registry, at(9_400)),
+               vec![DrainStep::Kill(util::task_id("a"), slave_id("s1"))]);
+    assert!(maintenance.is_draining(&slave_id("s1")));
+    assert!(maintenance.poll(&registry, at(9_401)).is_empty());
+
+    maintenance.terminated(&util::task_id("c"));
+    assert_eq!(maintenance.get(&slave_id("s1")).unwrap().tasks,
+               vec![util::task_id("a")]);
+    registry.update(&status("a", TaskState::TASK_KILLED));
+    maintenance.terminated(&util::task_id("a"));
+    assert_eq!(maintenance.poll(&registry, at(9_500)),
+               vec![DrainStep::Accept(offer_id("i1"))]);
+    assert!(maintenance.poll(&registry, at(9_600)).is_empty());
+
+    // inverse offers are made again until maintenance starts
+    maintenance.drain(&inverse_offer("i2", "s1", 10_000));
+    assert_eq!(maintenance.poll(&registry, at(9_700)),
+               vec![DrainStep::Accept(offer_id("i2"))]);
+
+    assert!(maintenance.poll(&registry, at(13_600)).is_empty());
+    assert!(maintenance.drains().is_empty());
+}
+
+#[test]
+fn maintenance_kills_tasks_launched_on_draining_agents() {
+    let mut registry = registry();
+    let mut maintenance = Maintenance::new(Duration::from_secs(600));
+    maintenance.drain(&inverse_offer("i1", "s1", 10_000));
+
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    assert_eq!(maintenance.poll(&registry, at(9_400)),
+               vec![DrainStep::Kill(util::task_id("a"), slave_id("s1"))]);
+
+    registry.register(&util::task_info("d",
+                                       &util::task_id("d"),
+                                       &slave_id("s1"),
+                                       &CommandInfo::new(),
+                                       vec![]));
+    registry.update(&status("a", TaskState::TASK_KILLED));
+    maintenance.terminated(&util::task_id("a"));
+    assert_eq!(maintenance.poll(&registry, at(9_500)),
+               vec![DrainStep::Kill(util::task_id("d"), slave_id("s1"))]);
+
+    registry.update(&status("d", TaskState::TASK_KILLED));
+    maintenance.terminated(&util::task_id("d"));
+    assert_eq!(maintenance.poll(&registry, at(9_600)),
+               vec![DrainStep::Accept(offer_id("i1"))]);
+}
+
+#[test]
+fn maintenance_forgets_rescinded_and_agentless_inverse_offers() {
+    let mut maintenance = Maintenance::default();
+    let mut everything = inverse_offer("i1", "s1", 0);
+    everything.clear_slave_id();
+    assert!(!maintenance.drain(&everything));
+
+    maintenance.drain(&inverse_offer("i2", "s2", 0));
+    assert!(maintenance.rescind(&offer_id("i1")).is_none());
+    assert!(maintenance.rescind(&offer_id("i2")).is_some());
+    assert!(maintenance.get(&slave_id("s2")).is_none());
+}
+
+#[test]
+fn inverse_offer_calls_roundtrip() {
+    let mut accept = scheduler::Call_AcceptInverseOffers::new();
+    accept.mut_inverse_offer_ids().push(offer_id("i1"));
+    let mut call = scheduler::Call::new();
+    call.set_field_type(scheduler::Call_Type::ACCEPT_INVERSE_OFFERS);
+    call.set_accept_inverse_offers(accept);
+
+    let mut decline = scheduler::Call_DeclineInverseOffers::new();
+    decline.mut_inverse_offer_ids().push(offer_id("i2"));
+    decline.mut_filters().set_refuse_seconds(60f64);
+    call.set_decline_inverse_offers(decline);
+
+    let bytes = call.write_to_bytes().unwrap();
+    let parsed: scheduler::Call = protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, call);
+    assert_eq!(parsed.get_field_type(),
+               scheduler::Call_Type::ACCEPT_INVERSE_OFFERS);
+    assert!(format!("{:?}", parsed).contains("decline_inverse_offers"));
+}
+
+#[test]
+fn time_and_duration_infos_convert_to_std_time() {
+    let duration = Duration::new(90, 5);
+    assert_eq!(util::duration_info(duration).get_nanoseconds(),
+               90_000_000_005);
+    assert_eq!(util::duration(&util::duration_info(duration)), duration);
+    let mut negative = DurationInfo::new();
+    negative.set_nanoseconds(-1);
+    assert_eq!(util::duration(&negative), Duration::from_secs(0));
+
+    let time = UNIX_EPOCH + Duration::new(1_500_000_000, 250);
+    let time_info = util::time_info(time);
+    assert_eq!(time_info.get_nanoseconds(), 1_500_000_000_000_000_250);
+    assert_eq!(util::system_time(&time_info), time);
+    let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+    assert_eq!(util::time_info(before_epoch).get_nanoseconds(),
+               -1_000_000_000);
+    assert_eq!(util::system_time(&util::time_info(before_epoch)),
+               before_epoch);
+}
+
+#[test]
+fn offers_are_refused_to_work_running_into_maintenance() {
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    let hundred = Some(Duration::from_secs(100));
+    let window = Window::new(&util::unavailability(at(1000), hundred));
+    assert_eq!(window.end, Some(at(1100)));
+    assert!(!window.overlaps(at(0), Some(Duration::from_secs(1000))));
+    assert!(window.overlaps(at(0), Some(Duration::from_secs(1001))));
+    assert!(window.overlaps(at(1050), Some(Duration::from_secs(1))));
+    assert!(window.overlaps(at(0), None));
+    assert!(!window.overlaps(at(1100), None));
+    let forever = Window::new(&util::unavailability(at(1000), None));
+    assert!(forever.overlaps(at(5000), Some(Duration::from_secs(1))));
+
+    let now = SystemTime::now();
+    let free = offer("a", "s1", vec![]);
+    let mut maintained = offer("b", "s2", vec![]);
+    let start = now + Duration::from_secs(3600);
+    maintained.set_unavailability(util::unavailability(start, None));
+    let hour = Some(Duration::from_secs(3600));
+    let day = Some(Duration::from_secs(86400));
+    assert!(maintenance::fits_before_maintenance(&maintained, hour, now));
+    assert!(!maintenance::fits_before_maintenance(&maintained, day, now));
+    assert_eq!(maintenance::available_offers(vec![&free, &maintained],
+                                             None,
+                                             now),
+               vec![&free]);
+}
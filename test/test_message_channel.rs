@@ -0,0 +1,78 @@
+use std::time::{Duration, Instant};
+
+use mesos::message_channel::{Envelope, Kind, MAGIC, MessageChannel,
+                             MessageError, Reply, VERSION};
+use mesos::proto::*;
+use mesos::util;
+
+use fixtures::slave_id;
+
+fn executor_id(id: &str) -> ExecutorID {
+    let mut executor_id = ExecutorID::new();
+    executor_id.set_value(id.to_string());
+    executor_id
+}
+
+#[test]
+fn envelopes_roundtrip_typed_messages() {
+    let task_id = util::task_id("a");
+    let envelope = Envelope::response(0x0102030405060708, &task_id).unwrap();
+    let data = envelope.to_bytes();
+    assert_eq!(&data[..11], &[MAGIC, VERSION, 2, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let parsed = Envelope::from_bytes(&data).unwrap();
+    assert_eq!(parsed, envelope);
+    assert_eq!(parsed.decode::<TaskID>().unwrap(), task_id);
+
+    assert_eq!(Envelope::from_bytes(&[0, 1]), Err(MessageError::Truncated));
+    assert_eq!(Envelope::from_bytes(&[7; 11]),
+               Err(MessageError::NotAnEnvelope));
+    let mut future = data.clone();
+    future[1] = VERSION + 1;
+    assert_eq!(Envelope::from_bytes(&future),
+               Err(MessageError::UnsupportedVersion(VERSION + 1)));
+    let mut unknown = data.clone();
+    unknown[2] = 7;
+    assert_eq!(Envelope::from_bytes(&unknown),
+               Err(MessageError::UnknownKind(7)));
+    // a TaskID is missing its required value
+    assert!(Envelope::one_way(&TaskID::new()).is_err());
+}
+
+#[test]
+fn message_channel_routes_responses_to_their_reply() {
+    let start = Instant::now();
+    let mut channel = MessageChannel::new(Duration::from_secs(10));
+    let (s1, e1) = (slave_id("s1"), executor_id("e1"));
+    let (request, reply): (Envelope, Reply<TaskStatus>) =
+        channel.request(&s1, &e1, &util::task_id("a"), start).unwrap();
+    assert_eq!(request.kind, Kind::Request);
+    assert_eq!(request.correlation_id, reply.correlation_id());
+    assert!(reply.try_get().is_none());
+
+    // only responses to pending requests are consumed
+    let one_way = Envelope::one_way(&util::task_id("b")).unwrap();
+    assert!(!channel.receive(&s1, &e1, &one_way.to_bytes()));
+    assert!(!channel.receive(&s1, &e1, b"raw bytes"));
+
+    let mut status = TaskStatus::new();
+    status.set_task_id(util::task_id("a"));
+    status.set_state(TaskState::TASK_RUNNING);
+    let response = Envelope::response(request.correlation_id, &status)
+                       .unwrap();
+    // and only when they come from the executor asked
+    let data = response.to_bytes();
+    assert!(!channel.receive(&s1, &executor_id("e2"), &data));
+    assert!(!channel.receive(&slave_id("s2"), &e1, &data));
+    assert!(channel.receive(&s1, &e1, &data));
+    assert!(!channel.receive(&s1, &e1, &data));
+    assert_eq!(reply.wait(Duration::from_secs(1)).unwrap(), status);
+
+    let (_, unanswered): (Envelope, Reply<TaskStatus>) =
+        channel.request(&s1, &e1, &util::task_id("c"), start).unwrap();
+    assert_eq!(unanswered.wait(Duration::from_millis(1)).unwrap_err(),
+               MessageError::Timeout);
+    assert_eq!(channel.expire(start + Duration::from_secs(10)), 1);
+    assert_eq!(unanswered.wait(Duration::from_millis(1)).unwrap_err(),
+               MessageError::NoReply);
+}
@@ -0,0 +1,84 @@
+use mesos::container::ContainerError;
+use mesos::network;
+use mesos::proto::*;
+use mesos::{MesosBuilder, NetworkBuilder, PortAllocator, util};
+
+use fixtures::{offer, status};
+
+fn reported(name: &str, ips: &[&str]) -> NetworkInfo {
+    let mut network_info = NetworkInfo::new();
+    network_info.set_name(name.to_string());
+    for ip in ips {
+        let mut ip_address = NetworkInfo_IPAddress::new();
+        ip_address.set_ip_address(ip.to_string());
+        network_info.mut_ip_addresses().push(ip_address);
+    }
+    network_info
+}
+
+#[test]
+fn network_builder_attaches_to_named_network() {
+    let a = offer("a",
+                  "s1",
+                  vec![util::ranges("ports", "*", &[(31000, 31001)])]);
+    let ports = PortAllocator::new(&[&a]).allocate(2).unwrap();
+
+    let network_info = NetworkBuilder::new("calico")
+                           .ip(NetworkInfo_Protocol::IPv4)
+                           .group("web")
+                           .label("subnet", "10.1.0.0/16")
+                           .ports(&ports, &[80], "tcp")
+                           .build()
+                           .unwrap();
+    assert_eq!(network_info.get_name(), "calico");
+    assert_eq!(network_info.get_ip_addresses()[0].get_protocol(),
+               NetworkInfo_Protocol::IPv4);
+    assert_eq!(network_info.get_groups(), &["web".to_string()]);
+    assert_eq!(network_info.get_labels().get_labels()[0].get_value(),
+               "10.1.0.0/16");
+    let mappings: Vec<(u32, u32)> = network_info.get_port_mappings()
+                                                .iter()
+                                                .map(|m| {
+                                                    (m.get_host_port(),
+                                                     m.get_container_port())
+                                                })
+                                                .collect();
+    assert_eq!(mappings, vec![(31000, 80), (31001, 31001)]);
+
+    let container = MesosBuilder::new()
+                        .network(network_info)
+                        .build()
+                        .unwrap();
+    assert_eq!(container.get_network_infos()[0].get_name(), "calico");
+}
+
+#[test]
+fn network_builder_rejects_invalid_networks() {
+    assert_eq!(NetworkBuilder::new("").build().unwrap_err(),
+               ContainerError::MissingNetworkName);
+    assert_eq!(NetworkBuilder::new("calico")
+                   .port_mapping(31000, 80, "tcp")
+                   .port_mapping(31000, 443, "tcp")
+                   .build()
+                   .unwrap_err(),
+               ContainerError::DuplicateHostPort(31000));
+}
+
+#[test]
+fn assigned_ips_are_read_from_container_status() {
+    let mut legacy = reported("", &[]);
+    legacy.set_ip_address("10.0.0.5".to_string());
+    let mut container_status = ContainerStatus::new();
+    container_status.mut_network_infos()
+                    .push(reported("calico", &["192.168.1.7", "fd00::7"]));
+    container_status.mut_network_infos().push(legacy);
+
+    let mut running = status("a", TaskState::TASK_RUNNING);
+    running.set_container_status(container_status);
+    assert_eq!(network::assigned_ips(&running),
+               vec![("calico".to_string(), "192.168.1.7".to_string()),
+                    ("calico".to_string(), "fd00::7".to_string()),
+                    ("".to_string(), "10.0.0.5".to_string())]);
+    assert!(network::assigned_ips(&status("a", TaskState::TASK_RUNNING))
+                .is_empty());
+}
@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use mesos::{OfferPool, util};
+
+use fixtures::{offer, offer_id, slave_id};
+
+#[test]
+fn offer_pool_holds_rescinds_and_expires() {
+    let mut pool = OfferPool::new(Duration::from_secs(10), 30f64);
+    let now = Instant::now();
+
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 2f64)]);
+    let b = offer("b", "s1", vec![util::scalar("mem", "*", 512f64)]);
+    let c = offer("c", "s2", vec![util::scalar("cpus", "*", 4f64)]);
+    pool.add(vec![&a, &b], now);
+    pool.add(vec![&c], now + Duration::from_secs(5));
+    assert_eq!(pool.len(), 3);
+
+    assert!(pool.rescind(&offer_id("b")).is_some());
+    assert!(!pool.contains(&offer_id("b")));
+
+    let expired = pool.expire(now + Duration::from_secs(10));
+    assert_eq!(expired, vec![offer_id("a")]);
+    assert_eq!(pool.filters().get_refuse_seconds(), 30f64);
+
+    let taken = pool.take_slave(&slave_id("s2"));
+    assert_eq!(taken.len(), 1);
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn offer_pool_takes_matching_offers() {
+    let mut pool = OfferPool::default();
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 2f64)]);
+    let b = offer("b", "s2", vec![util::scalar("cpus", "*", 8f64)]);
+    pool.add(vec![&a, &b], Instant::now());
+
+    let big = pool.take_matching(|o| {
+        util::get_scalar_resource_sum("cpus", vec![o]) >= 4f64
+    });
+    assert_eq!(big.len(), 1);
+    assert_eq!(big[0].get_id(), &offer_id("b"));
+    assert_eq!(pool.offers()[0].get_id(), &offer_id("a"));
+}
+
+#[test]
+fn offer_pool_holds_offers_for_thirty_seconds_by_default() {
+    let mut pool = OfferPool::default();
+    assert_eq!(pool.hold(), Some(Duration::from_secs(30)));
+    let now = Instant::now();
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 2f64)]);
+    pool.add(vec![&a], now);
+    assert!(pool.expire(now + Duration::from_secs(29)).is_empty());
+    assert_eq!(pool.expire(now + Duration::from_secs(30)),
+               vec![offer_id("a")]);
+
+    // offers can still be held until used
+    pool.set_hold(None);
+    let b = offer("b", "s1", vec![util::scalar("cpus", "*", 2f64)]);
+    pool.add(vec![&b], now);
+    assert!(pool.expire(now + Duration::from_secs(3600)).is_empty());
+    assert!(pool.contains(&offer_id("b")));
+}
@@ -0,0 +1,49 @@
+use mesos::persistent_volume::VolumeError;
+use mesos::proto::*;
+use mesos::{PersistentVolume, Resources, util};
+
+use fixtures::offer;
+
+#[test]
+fn persistent_volume_lifecycle() {
+    let volume = PersistentVolume::new("pg-data", "data").principal("ops");
+
+    assert_eq!(volume.create(&util::scalar("disk", "*", 1024f64)),
+               Err(VolumeError::Unreserved));
+    assert_eq!(volume.create(&util::scalar("mem", "db", 1024f64)),
+               Err(VolumeError::NotDisk("mem".to_string())));
+
+    let create = volume.create_operation(&util::scalar("disk", "db", 1024f64))
+                       .unwrap();
+    assert_eq!(create.get_field_type(), Offer_Operation_Type::CREATE);
+    let created = create.get_create().get_volumes()[0].clone();
+    assert_eq!(created.get_disk().get_persistence().get_id(), "pg-data");
+    assert_eq!(created.get_disk().get_volume().get_container_path(), "data");
+    assert_eq!(volume.create(&created),
+               Err(VolumeError::AlreadyPersistent("pg-data".to_string())));
+
+    // the volume comes back in a later offer next to plain reserved disk
+    let later = offer("a",
+                      "s1",
+                      vec![created.clone(),
+                           util::scalar("disk", "db", 512f64)]);
+    let (found_in, found) = volume.find(&[&later]).unwrap();
+    assert_eq!(found_in.get_id().get_value(), "a");
+    assert_eq!(found, &created);
+
+    // plain disk requests never eat into the volume, and the volume is
+    // handed out whole
+    let offered = Resources::from_vec(later.get_resources().to_vec());
+    let some_disk = vec![util::scalar("disk", "*", 256f64)];
+    let plain = offered.find(&Resources::from_vec(some_disk)).unwrap();
+    assert!(plain.iter().all(|r| !r.get_disk().has_persistence()));
+    let too_much_disk = vec![util::scalar("disk", "*", 1024f64)];
+    assert!(offered.find(&Resources::from_vec(too_much_disk)).is_none());
+    let attached = offered.find(&Resources::from_vec(vec![found.clone()]))
+                          .unwrap();
+    assert_eq!(attached.into_vec(), vec![created.clone()]);
+
+    let destroy = volume.destroy_operation(&[&later]).unwrap();
+    assert_eq!(destroy.get_field_type(), Offer_Operation_Type::DESTROY);
+    assert_eq!(destroy.get_destroy().get_volumes().to_vec(), vec![created]);
+}
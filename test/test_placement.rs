@@ -0,0 +1,88 @@
+use mesos::placement::{BestFit, FirstFit, SpreadByAttribute};
+use mesos::proto::*;
+use mesos::{TaskQueue, TaskSpec, util};
+
+use fixtures::{offer, text_attribute};
+
+fn spec(name: &str, cpus: f64) -> TaskSpec {
+    TaskSpec::new(name,
+                  CommandInfo::new(),
+                  vec![util::scalar("cpus", "*", cpus)])
+}
+
+fn agent(slave: &str, cpus: f64, zone: &str) -> Offer {
+    let mut offer = offer(slave, slave, vec![util::scalar("cpus", "*", cpus)]);
+    offer.mut_attributes().push(text_attribute("zone", zone));
+    offer
+}
+
+fn placements(queue: &mut TaskQueue, offers: Vec<&Offer>) -> Vec<String> {
+    let mut placed: Vec<(String, String)> =
+        queue.match_offers(offers)
+             .iter()
+             .flat_map(|l| l.tasks.iter())
+             .map(|t| {
+                 (t.get_name().to_string(),
+                  t.get_slave_id().get_value().to_string())
+             })
+             .collect();
+    placed.sort();
+    placed.into_iter().map(|(_, slave)| slave).collect()
+}
+
+#[test]
+fn first_fit_uses_agents_in_offer_order() {
+    let (a, b) = (agent("a", 4f64, "z1"), agent("b", 2f64, "z2"));
+    let mut queue = TaskQueue::with_strategy(Box::new(FirstFit));
+    queue.push(spec("t1", 1f64));
+    queue.push(spec("t2", 1f64));
+    assert_eq!(placements(&mut queue, vec![&a, &b]), vec!["a", "a"]);
+}
+
+#[test]
+fn best_fit_minimises_leftover() {
+    let (a, b) = (agent("a", 4f64, "z1"), agent("b", 2f64, "z2"));
+    let mut queue = TaskQueue::with_strategy(Box::new(BestFit));
+    queue.push(spec("t1", 2f64));
+    queue.push(spec("t2", 3f64));
+    queue.push(spec("t3", 1f64));
+    // t1 fills b exactly, t2 then only fits on a, and t3 takes the
+    // remaining cpu on a
+    assert_eq!(placements(&mut queue, vec![&a, &b]), vec!["b", "a", "a"]);
+}
+
+#[test]
+fn spread_balances_attribute_values() {
+    let a = agent("a", 8f64, "z1");
+    let b = agent("b", 8f64, "z1");
+    let c = agent("c", 8f64, "z2");
+    let mut queue =
+        TaskQueue::with_strategy(Box::new(SpreadByAttribute::new("zone")));
+    for i in 0..4 {
+        queue.push(spec(&format!("t{}", i), 1f64));
+    }
+    assert_eq!(placements(&mut queue, vec![&a, &b, &c]),
+               vec!["a", "c", "a", "c"]);
+}
+
+#[test]
+fn spread_forgets_tasks_that_are_gone() {
+    let a = agent("a", 8f64, "z1");
+    let c = agent("c", 8f64, "z2");
+    let mut queue =
+        TaskQueue::with_strategy(Box::new(SpreadByAttribute::new("zone")));
+    queue.push(spec("t0", 1f64));
+    queue.push(spec("t1", 1f64));
+    let launches = queue.match_offers(vec![&a, &c]);
+    let on_a = launches.iter()
+                       .flat_map(|l| l.tasks.iter())
+                       .find(|t| t.get_slave_id().get_value() == "a")
+                       .unwrap()
+                       .get_task_id()
+                       .clone();
+
+    // with z1's task gone, z1 is the emptier zone again
+    queue.forget(&on_a);
+    queue.push(spec("t2", 1f64));
+    assert_eq!(placements(&mut queue, vec![&c, &a]), vec!["a"]);
+}
@@ -0,0 +1,66 @@
+use mesos::proto::*;
+use mesos::{PortAllocator, TaskQueue, TaskSpec, util};
+
+use fixtures::offer;
+
+fn ports_offer() -> Offer {
+    offer("a",
+          "s1",
+          vec![util::scalar("cpus", "*", 4f64),
+               util::ranges("ports", "*", &[(31000, 31001), (31005, 31009)]),
+               util::ranges("ports", "web", &[(31002, 31002)])])
+}
+
+#[test]
+fn port_allocator_hands_out_each_port_once() {
+    let offer = ports_offer();
+    let mut allocator = PortAllocator::new(&[&offer]);
+    assert_eq!(allocator.available(), vec![(31000, 31002), (31005, 31009)]);
+    assert_eq!(allocator.count(), 8);
+
+    let first = allocator.allocate(3).unwrap();
+    assert_eq!(first.ports, vec![31000, 31001, 31002]);
+    // the reserved port keeps its role
+    assert_eq!(first.resources.len(), 2);
+
+    let contiguous = allocator.allocate_contiguous(4).unwrap();
+    assert_eq!(contiguous.ports, vec![31005, 31006, 31007, 31008]);
+
+    assert!(allocator.allocate_specific(&[31001]).is_none());
+    assert!(allocator.allocate_specific(&[31009]).is_some());
+    assert!(allocator.allocate(1).is_none());
+}
+
+#[test]
+fn port_allocation_feeds_discovery_and_port_mappings() {
+    let offer = ports_offer();
+    let allocation = PortAllocator::new(&[&offer]).allocate(2).unwrap();
+
+    let ports = allocation.discovery_ports(&["http"], "tcp");
+    assert_eq!(ports.get_ports()[0].get_name(), "http");
+    assert_eq!(ports.get_ports()[1].get_number(), 31001);
+
+    let mappings = allocation.port_mappings(&[80], "tcp");
+    assert_eq!(mappings[0].get_container_port(), 80);
+    assert_eq!(mappings[1].get_container_port(), 31001);
+}
+
+#[test]
+fn task_queue_allocates_ports() {
+    let offer = ports_offer();
+    let mut spec = TaskSpec::new("web",
+                                 CommandInfo::new(),
+                                 vec![util::scalar("cpus", "*", 1f64)]);
+    spec.ports = 2;
+
+    let mut queue = TaskQueue::new();
+    queue.push(spec.clone());
+    queue.push(spec);
+    let launches = queue.match_offers(vec![&offer]);
+    let tasks = &launches[0].tasks;
+    assert_eq!(tasks.len(), 2);
+
+    let vars = tasks[1].get_command().get_environment().get_variables();
+    let port0 = vars.iter().find(|v| v.get_name() == "PORT0").unwrap();
+    assert_eq!(port0.get_value(), "31002");
+}
@@ -0,0 +1,100 @@
+use std::time::{Duration, Instant};
+
+use mesos::proto::*;
+use mesos::reconciler::{self, Phase, Step};
+use mesos::{Reconciler, TaskRegistry, util};
+
+fn status(id: &str, state: TaskState) -> TaskStatus {
+    let mut status = TaskStatus::new();
+    status.set_task_id(util::task_id(id));
+    status.set_state(state);
+    status
+}
+
+#[test]
+fn reconciler_retries_then_reconciles_implicitly() {
+    let mut registry = TaskRegistry::new();
+    registry.update(&status("a", TaskState::TASK_RUNNING));
+    registry.update(&status("b", TaskState::TASK_STARTING));
+    registry.update(&status("c", TaskState::TASK_FINISHED));
+
+    let mut reconciler = Reconciler::new(Duration::from_secs(1),
+                                         Duration::from_secs(3));
+    let start = Instant::now();
+    reconciler.start(&registry, start);
+
+    match reconciler.poll(start) {
+        Some(Step::Explicit(tasks)) => assert_eq!(tasks.len(), 2),
+        other => panic!("expected explicit reconciliation, got {:?}", other),
+    }
+    assert_eq!(reconciler.poll(start), None);
+
+    reconciler.observe(&status("a", TaskState::TASK_RUNNING));
+
+    // only the unanswered task is retried, after the backoff
+    let retry = start + Duration::from_secs(1);
+    match reconciler.poll(retry) {
+        Some(Step::Explicit(tasks)) => {
+            assert_eq!(tasks.len(), 1);
+            assert_eq!(tasks[0].get_task_id().get_value(), "b");
+        }
+        other => panic!("expected explicit reconciliation, got {:?}", other),
+    }
+    assert_eq!(reconciler.poll(retry + Duration::from_secs(1)), None);
+
+    reconciler.observe(&status("b", TaskState::TASK_LOST));
+    assert_eq!(reconciler.poll(retry), Some(Step::Implicit));
+    assert_eq!(reconciler.phase(), Phase::Implicit);
+
+    let lost = reconciler.complete();
+    assert_eq!(lost, vec![util::task_id("b")]);
+    assert!(reconciler.is_complete());
+    assert_eq!(reconciler.poll(retry + Duration::from_secs(60)), None);
+}
+
+#[test]
+fn reconciler_caps_large_backoffs() {
+    let mut registry = TaskRegistry::new();
+    registry.update(&status("a", TaskState::TASK_RUNNING));
+
+    let mut reconciler = Reconciler::new(Duration::from_secs(u64::MAX / 4),
+                                         Duration::from_secs(60));
+    let start = Instant::now();
+    reconciler.start(&registry, start);
+    for attempt in 0..3 {
+        let at = start + Duration::from_secs(60 * attempt);
+        assert!(reconciler.poll(at).is_some());
+    }
+    assert_eq!(reconciler.attempts(), 3);
+}
+
+#[test]
+fn reconciler_checks_suspect_losses_until_confirmed() {
+    let mut reconciler = Reconciler::new(Duration::from_secs(1),
+                                         Duration::from_secs(3));
+    let start = Instant::now();
+    let lost = status("a", TaskState::TASK_LOST);
+    assert!(!reconciler::confirms_loss(&lost));
+    reconciler.suspect(&lost, start);
+    reconciler.suspect(&status("b", TaskState::TASK_LOST), start);
+
+    let suspects = reconciler.poll_suspects(start);
+    assert_eq!(suspects.len(), 2);
+    assert!(reconciler.poll_suspects(start).is_empty());
+
+    // an unconfirmed loss keeps the task suspect, a confirmed one ends it
+    reconciler.observe(&lost);
+    let mut confirmed = status("b", TaskState::TASK_LOST);
+    confirmed.set_reason(TaskStatus_Reason::REASON_RECONCILIATION);
+    assert!(reconciler::confirms_loss(&confirmed));
+    reconciler.observe(&confirmed);
+
+    let retry = start + Duration::from_secs(1);
+    let suspects = reconciler.poll_suspects(retry);
+    assert_eq!(suspects.len(), 1);
+    assert_eq!(suspects[0].get_task_id().get_value(), "a");
+
+    // as does the task coming back
+    reconciler.observe(&status("a", TaskState::TASK_RUNNING));
+    assert!(reconciler.suspects().is_empty());
+}
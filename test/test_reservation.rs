@@ -0,0 +1,50 @@
+use mesos::proto::*;
+use mesos::{PersistentVolume, Reservation, util};
+
+use fixtures::offer;
+
+#[test]
+fn reservation_builds_operations_and_recognises_its_resources() {
+    let reservation = Reservation::new("db", "ops").label("service", "pg");
+
+    let reserve = reservation.reserve_operation(&[util::scalar("disk",
+                                                               "*",
+                                                               512f64)]);
+    assert_eq!(reserve.get_field_type(), Offer_Operation_Type::RESERVE);
+    let reserved = reserve.get_reserve().get_resources()[0].clone();
+    assert_eq!(reserved.get_role(), "db");
+    assert!(reservation.matches(&reserved));
+
+    let other_service = Reservation::new("db", "ops").label("service", "kv");
+    let foreign = other_service.reserved(&util::scalar("cpus", "db", 1f64));
+    let statically_reserved = util::scalar("mem", "db", 256f64);
+    assert!(!reservation.matches(&foreign));
+    assert!(!reservation.matches(&statically_reserved));
+
+    let ours = offer("a", "s1", vec![reserved, foreign]);
+    let theirs = offer("b", "s2", vec![statically_reserved]);
+    assert_eq!(reservation.offers(&[&ours, &theirs]).len(), 1);
+
+    let unreserve = reservation.unreserve_operation(&[&ours, &theirs]);
+    assert_eq!(unreserve.get_field_type(), Offer_Operation_Type::UNRESERVE);
+    let released = unreserve.get_unreserve().get_resources();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].get_name(), "disk");
+}
+
+#[test]
+fn reservation_leaves_persistent_volumes_out_of_unreserve() {
+    let reservation = Reservation::new("db", "ops");
+    let disk = reservation.reserved(&util::scalar("disk", "*", 512f64));
+    let volume = PersistentVolume::new("pg-data", "data")
+                     .create(&reservation.reserved(&util::scalar("disk",
+                                                                 "*",
+                                                                 1024f64)))
+                     .unwrap();
+    let a = offer("a", "s1", vec![disk, volume]);
+
+    let unreserve = reservation.unreserve_operation(&[&a]);
+    let released = unreserve.get_unreserve().get_resources();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].get_scalar().get_value(), 512f64);
+}
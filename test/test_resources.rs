@@ -0,0 +1,243 @@
+use mesos::resources::DiskSource;
+use mesos::util::{disk, ranges, set};
+use mesos::{Resources, util};
+
+// A small deterministic generator so the property tests are repeatable
+// without pulling in a property testing crate.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+const ROLES: [&'static str; 3] = ["*", "web", "db"];
+
+// Builds a random resource set.  Ranges and set items are drawn from
+// the `slot` half of the space so two sets built with different slots
+// never overlap, as `Resources` arithmetic requires to be reversible.
+fn arbitrary(rng: &mut XorShift, slot: u64) -> Resources {
+    let mut resources = Resources::new();
+    for _ in 0..rng.below(6) {
+        let role = ROLES[rng.below(3) as usize];
+        let resource = match rng.below(4) {
+            0 => util::scalar("cpus", role, rng.below(16000) as f64 / 1000f64),
+            1 => util::scalar("mem", role, (rng.below(4096) + 1) as f64),
+            2 => {
+                let begin = (rng.below(50) * 2 + slot) * 100;
+                let end = begin + rng.below(100);
+                ranges("ports", role, &[(begin, end)])
+            }
+            _ => {
+                let item = format!("gpu{}-{}", slot, rng.below(8));
+                set("gpus", role, &[&item])
+            }
+        };
+        resources.add_resource(&resource);
+    }
+    resources
+}
+
+#[test]
+fn adding_then_subtracting_is_identity() {
+    let mut rng = XorShift(0x2545F4914F6CDD1D);
+    for _ in 0..500 {
+        let a = arbitrary(&mut rng, 0);
+        let b = arbitrary(&mut rng, 1);
+        let sum = a.clone() + &b;
+        assert!(sum.contains(&a));
+        assert!(sum.contains(&b));
+        assert_eq!(sum - &b, a);
+    }
+}
+
+#[test]
+fn overlapping_ranges_and_sets_are_held_once() {
+    let a = Resources::from_vec(vec![ranges("ports", "*", &[(1000, 1010)]),
+                                     set("gpus", "*", &["gpu0", "gpu1"])]);
+    let b = Resources::from_vec(vec![ranges("ports", "*", &[(1005, 1020)]),
+                                     set("gpus", "*", &["gpu1"])]);
+    let sum = a.clone() + &b;
+    assert_eq!(sum,
+               Resources::from_vec(vec![ranges("ports", "*", &[(1000, 1020)]),
+                                        set("gpus", "*", &["gpu0", "gpu1"])]));
+    // the overlap is gone along with `b`
+    assert_eq!(sum - &b,
+               Resources::from_vec(vec![ranges("ports", "*", &[(1000, 1004)]),
+                                        set("gpus", "*", &["gpu0"])]));
+}
+
+#[test]
+fn checked_sub_refuses_what_is_not_there() {
+    let have = Resources::from_vec(vec![util::scalar("cpus", "*", 2f64),
+                                        ranges("ports", "*", &[(1000, 1010)])]);
+    let cpus = Resources::from_vec(vec![util::scalar("cpus", "*", 1f64)]);
+    assert_eq!(have.checked_sub(&cpus).unwrap().scalar("cpus"), 1f64);
+
+    let too_many = Resources::from_vec(vec![util::scalar("cpus", "*", 3f64)]);
+    assert!(have.checked_sub(&too_many).is_none());
+    assert_eq!(have.clone() - &too_many, have.filter_by_name("ports"));
+
+    let reserved = Resources::from_vec(vec![util::scalar("cpus", "web", 1f64)]);
+    assert!(have.checked_sub(&reserved).is_none());
+    let ports = vec![ranges("ports", "*", &[(1010, 1011)])];
+    assert!(have.checked_sub(&Resources::from_vec(ports)).is_none());
+}
+
+#[test]
+fn scalars_use_fixed_point() {
+    let mut a = Resources::new();
+    for _ in 0..10 {
+        a.add_resource(&util::scalar("cpus", "*", 0.1));
+    }
+    assert_eq!(a.scalar("cpus"), 1f64);
+
+    let b = Resources::from_vec(vec![util::scalar("cpus", "*", 0.3333)]);
+    assert_eq!(b.scalar("cpus"), 0.333);
+
+    // subtraction never goes below empty
+    assert!((b - a).is_empty());
+}
+
+#[test]
+fn ranges_merge_and_split() {
+    let a = Resources::from_vec(vec![ranges("ports", "*", &[(1, 5)]),
+                                     ranges("ports", "*", &[(6, 10)])]);
+    assert_eq!(a, Resources::from_vec(vec![ranges("ports", "*", &[(1, 10)])]));
+
+    let left = a - Resources::from_vec(vec![ranges("ports", "*", &[(4, 6)])]);
+    assert_eq!(left.as_slice()[0].get_ranges().get_range().len(), 2);
+    assert!(left.contains(&Resources::from_vec(vec![ranges("ports",
+                                                           "*",
+                                                           &[(7, 10)])])));
+    assert!(!left.contains(&Resources::from_vec(vec![ranges("ports",
+                                                            "*",
+                                                            &[(5, 5)])])));
+}
+
+#[test]
+fn resources_are_keyed_by_role_and_reservation() {
+    let mut reserved = util::scalar("cpus", "web", 2f64);
+    reserved.mut_reservation().set_principal("ops".to_string());
+
+    let all = Resources::from_vec(vec![util::scalar("cpus", "*", 1f64),
+                                       util::scalar("cpus", "web", 1f64),
+                                       reserved.clone()]);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.scalar("cpus"), 4f64);
+    assert_eq!(all.filter_by_role("web").len(), 2);
+    assert!(!all.contains(&Resources::from_vec(vec![util::scalar("cpus",
+                                                                 "*",
+                                                                 2f64)])));
+
+    let flat = all.flatten("*");
+    assert_eq!(flat.len(), 1);
+    assert_eq!(flat.scalar("cpus"), 4f64);
+}
+
+#[test]
+fn find_keeps_the_offered_roles() {
+    let offered = Resources::from_vec(vec![util::scalar("cpus", "web", 1f64),
+                                           util::scalar("cpus", "*", 4f64),
+                                           set("gpus", "*", &["a", "b"])]);
+    let wanted = Resources::from_vec(vec![util::scalar("cpus", "*", 2f64),
+                                          set("gpus", "*", &["b"])]);
+    let found = offered.find(&wanted).unwrap();
+    assert_eq!(found.filter_by_role("web").scalar("cpus"), 1f64);
+    assert_eq!(found.filter_by_role("*").scalar("cpus"), 1f64);
+    assert!(offered.contains(&found));
+
+    let too_much = Resources::from_vec(vec![util::scalar("cpus", "*", 6f64)]);
+    assert!(offered.find(&too_much).is_none());
+}
+
+#[test]
+fn mount_disks_are_never_split() {
+    let data = DiskSource::Mount("/mnt/data".to_string());
+    let offered = Resources::from_vec(vec![
+        disk("*", 100f64, &DiskSource::Root),
+        disk("*", 500f64, &data),
+        disk("*", 200f64, &DiskSource::Path("/var/scratch".to_string())),
+    ]);
+
+    assert_eq!(offered.disk_capacity(),
+               vec![(DiskSource::Root, 100f64),
+                    (DiskSource::Path("/var/scratch".to_string()), 200f64),
+                    (data.clone(), 500f64)]);
+
+    // shareable disk is used up before a whole mount is taken
+    let small = Resources::from(vec![disk("*", 250f64, &DiskSource::Root)]);
+    let found = offered.find(&small).unwrap();
+    assert_eq!(found.scalar("disk"), 250f64);
+    assert!(found.iter().all(|r| !DiskSource::of(r).is_mount()));
+
+    // more than that takes a whole mount, without the shareable disk
+    let large = Resources::from(vec![disk("*", 350f64, &DiskSource::Root)]);
+    let found = offered.find(&large).unwrap().into_vec();
+    assert_eq!(found, vec![disk("*", 500f64, &data)]);
+
+    // asking for a source only matches disk from that source
+    let on_data = Resources::from(vec![disk("*", 10f64, &data)]);
+    let found = offered.find(&on_data).unwrap().into_vec();
+    assert_eq!(found, vec![disk("*", 500f64, &data)]);
+    let elsewhere = DiskSource::Mount("/mnt/other".to_string());
+    let missing = Resources::from(vec![disk("*", 10f64, &elsewhere)]);
+    assert!(offered.find(&missing).is_none());
+}
+
+#[test]
+fn disk_requests_take_the_smallest_mount_that_fits() {
+    let mount = |root: &str| DiskSource::Mount(root.to_string());
+    let offered = Resources::from_vec(vec![
+        disk("*", 100f64, &DiskSource::Root),
+        disk("*", 200f64, &mount("/mnt/a")),
+        disk("*", 200f64, &mount("/mnt/b")),
+        disk("*", 600f64, &mount("/mnt/c")),
+        disk("*", 400f64, &mount("/mnt/d")),
+    ]);
+
+    let wanted = Resources::from(vec![disk("*", 350f64, &DiskSource::Root)]);
+    let found = offered.find(&wanted).unwrap().into_vec();
+    assert_eq!(found, vec![disk("*", 400f64, &mount("/mnt/d"))]);
+
+    // mounts too small on their own are never combined
+    let small = Resources::from_vec(vec![
+        disk("*", 200f64, &mount("/mnt/a")),
+        disk("*", 200f64, &mount("/mnt/b")),
+    ]);
+    assert!(small.find(&wanted).is_none());
+}
+
+#[test]
+fn reserved_resources_are_preferred_and_roles_honoured() {
+    let offered = Resources::from_vec(vec![
+        util::scalar("cpus", "*", 4f64),
+        util::scalar("cpus", "analytics", 2f64),
+        util::scalar("mem", "*", 1024f64),
+    ]);
+
+    let breakdown = offered.by_role();
+    assert_eq!(breakdown.len(), 2);
+    assert_eq!(breakdown[0].0, "*");
+    assert_eq!(breakdown[0].1.scalar("cpus"), 4f64);
+    assert_eq!(breakdown[1].0, "analytics");
+    assert_eq!(breakdown[1].1.scalar("cpus"), 2f64);
+
+    let any = Resources::from(vec![util::scalar("cpus", "*", 3f64)]);
+    let found = offered.find(&any).unwrap();
+    assert_eq!(found.filter_by_role("analytics").scalar("cpus"), 2f64);
+    assert_eq!(found.filter_by_role("*").scalar("cpus"), 1f64);
+
+    let reserved = Resources::from(vec![util::scalar("cpus",
+                                                     "analytics",
+                                                     3f64)]);
+    assert!(offered.find(&reserved).is_none());
+}
@@ -0,0 +1,77 @@
+use std::time::Instant;
+
+use mesos::proto::*;
+use mesos::task_builder::{TaskError, validate_unique};
+use mesos::{SchedulerClient, TaskBuilder, TaskRegistry, util};
+
+use fixtures::{offer, offer_id, slave_id};
+
+fn builder(id: &str) -> TaskBuilder {
+    let mut command = CommandInfo::new();
+    command.set_value("sleep 10".to_string());
+    TaskBuilder::new("sleeper")
+        .id(id)
+        .slave_id(&slave_id("s1"))
+        .resource(util::scalar("cpus", "*", 0.5f64))
+        .command(command)
+}
+
+#[test]
+fn task_builder_fills_in_task_info() {
+    let task = builder("sleeper.1")
+                   .label("tier", "batch")
+                   .data(b"payload".to_vec())
+                   .build()
+                   .unwrap();
+    assert_eq!(task.get_task_id().get_value(), "sleeper.1");
+    assert_eq!(task.get_slave_id().get_value(), "s1");
+    assert_eq!(task.get_command().get_value(), "sleep 10");
+    assert_eq!(task.get_labels().get_labels()[0].get_key(), "tier");
+    assert_eq!(task.get_data(), b"payload");
+    assert!(!task.has_executor());
+}
+
+#[test]
+fn task_builder_rejects_what_the_master_would() {
+    assert_eq!(builder("a/b").build().unwrap_err().to_string(),
+               "invalid task id \"a/b\": ids cannot contain slashes");
+    assert_eq!(builder("a").executor(ExecutorInfo::new()).build(),
+               Err(TaskError::CommandAndExecutor));
+    assert_eq!(TaskBuilder::new("x")
+                   .id("x")
+                   .slave_id(&slave_id("s1"))
+                   .resource(util::scalar("cpus", "*", 1f64))
+                   .build(),
+               Err(TaskError::NothingToRun));
+    assert_eq!(builder("a").resource(util::scalar("ports", "*", 1f64)).build(),
+               Err(TaskError::InvalidResource("ports".to_string(),
+                                              "expected RANGES, got SCALAR"
+                                                  .to_string())));
+    assert_eq!(builder("a").resource(util::scalar("mem", "*", 0f64)).build(),
+               Err(TaskError::InvalidResource("mem".to_string(),
+                                              "0 is not a positive amount"
+                                                  .to_string())));
+
+    let a = builder("a").build().unwrap();
+    let mut registry = TaskRegistry::new();
+    assert_eq!(validate_unique(&[a.clone(), a.clone()], &registry),
+               Err(TaskError::DuplicateTaskId("a".to_string())));
+    registry.register(&a);
+    assert!(validate_unique(&[a.clone()], &registry).is_err());
+    assert!(validate_unique(&[builder("b").build().unwrap()], &registry)
+                .is_ok());
+}
+
+#[test]
+fn launching_a_duplicate_task_id_fails_before_posting() {
+    // nothing listens there, so anything posted would fail to connect
+    let client = SchedulerClient::new("http://127.0.0.1:1".to_string(), None);
+    let a = builder("a").build().unwrap();
+    client.registry.lock().unwrap().register(&a);
+    let o = offer("o", "s1", vec![util::scalar("cpus", "*", 1f64)]);
+    client.offer_pool.lock().unwrap().add(vec![&o], Instant::now());
+
+    let err = client.launch(vec![offer_id("o")], vec![a], None).unwrap_err();
+    assert_eq!(err.to_string(), "task id \"a\" is already in use");
+    assert!(client.offer_pool.lock().unwrap().contains(&offer_id("o")));
+}
@@ -0,0 +1,306 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use mesos::proto::*;
+use mesos::container::DockerNetwork;
+use mesos::{DockerBuilder, SchedulerClient, TaskQueue, TaskSpec, util};
+
+use fixtures::{offer, offer_id, slave_id};
+
+fn spec(name: &str, cpus: f64, mem: f64) -> TaskSpec {
+    let mut command = CommandInfo::new();
+    command.set_value("sleep 10".to_string());
+    TaskSpec::new(name,
+                  command,
+                  vec![util::scalar("cpus", "*", cpus),
+                       util::scalar("mem", "*", mem)])
+}
+
+#[test]
+fn task_queue_carves_tasks_out_of_offers() {
+    let a = offer("a",
+                  "s1",
+                  vec![util::scalar("cpus", "*", 1f64),
+                       util::scalar("cpus", "web", 1f64),
+                       util::scalar("mem", "*", 256f64)]);
+    let b = offer("b",
+                  "s2",
+                  vec![util::scalar("cpus", "*", 4f64),
+                       util::scalar("mem", "*", 1024f64)]);
+
+    let mut queue = TaskQueue::new();
+    assert!(!queue.push(spec("web", 2f64, 128f64)));
+    queue.push(spec("db", 2f64, 512f64));
+    queue.push(spec("huge", 16f64, 128f64));
+
+    let launches = queue.match_offers(vec![&a, &b]);
+    assert_eq!(launches.len(), 2);
+
+    let web = &launches[0].tasks[0];
+    assert_eq!(launches[0].offer_ids, vec![offer_id("a")]);
+    assert_eq!(web.get_slave_id().get_value(), "s1");
+    let roles: Vec<&str> = web.get_resources()
+                              .iter()
+                              .filter(|r| r.get_name() == "cpus")
+                              .map(|r| r.get_role())
+                              .collect();
+    // the reserved cpu is used up first
+    assert_eq!(roles, vec!["web", "*"]);
+
+    assert_eq!(launches[1].tasks[0].get_name(), "db");
+    assert_eq!(queue.len(), 1);
+    assert!(!queue.should_suppress());
+}
+
+#[test]
+fn task_queue_honours_constraints_and_suppresses_when_drained() {
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 4f64),
+                                  util::scalar("mem", "*", 4096f64)]);
+    let b = offer("b", "s2", vec![util::scalar("cpus", "*", 4f64),
+                                  util::scalar("mem", "*", 4096f64)]);
+
+    let mut pinned = spec("pinned", 1f64, 128f64);
+    pinned.constraints
+          .push("hostname:CLUSTER:s2.example.com".parse().unwrap());
+
+    let mut queue = TaskQueue::new();
+    queue.push(pinned);
+    let launches = queue.match_offers(vec![&a, &b]);
+    assert_eq!(launches.len(), 1);
+    assert_eq!(launches[0].offer_ids, vec![offer_id("b")]);
+
+    assert!(queue.should_suppress());
+    assert!(!queue.should_suppress());
+    assert!(queue.is_suppressed());
+    assert!(queue.push(spec("more", 1f64, 128f64)));
+}
+
+#[test]
+fn task_queue_keeps_regular_tasks_off_revocable_resources() {
+    let mut slack = util::scalar("cpus", "*", 4f64);
+    slack.set_revocable(Resource_RevocableInfo::new());
+    let a = offer("a",
+                  "s1",
+                  vec![slack, util::scalar("cpus", "*", 1f64),
+                       util::scalar("mem", "*", 4096f64)]);
+
+    let (revocable, regular) = util::split_revocable(&[&a]);
+    assert_eq!(revocable[0].get_resources().len(), 1);
+    assert_eq!(regular[0].get_resources().len(), 2);
+
+    let mut batch = spec("batch", 2f64, 128f64);
+    batch.revocable = true;
+
+    let mut queue = TaskQueue::new();
+    queue.push(spec("service", 2f64, 128f64));
+    queue.push(batch);
+    let launches = queue.match_offers(vec![&a]);
+    assert_eq!(queue.len(), 1);
+    let task = &launches[0].tasks[0];
+    assert_eq!(task.get_name(), "batch");
+    assert!(task.get_resources()
+                .iter()
+                .filter(|r| r.get_name() == "cpus")
+                .all(|r| r.has_revocable()));
+}
+
+#[test]
+fn task_queue_never_mixes_revocable_and_regular_resources() {
+    let mut slack = util::scalar("cpus", "*", 1f64);
+    slack.set_revocable(Resource_RevocableInfo::new());
+    let a = offer("a",
+                  "s1",
+                  vec![slack, util::scalar("cpus", "*", 4f64),
+                       util::scalar("mem", "*", 4096f64)]);
+
+    let mut batch = spec("batch", 2f64, 128f64);
+    batch.revocable = true;
+    let mut small = spec("small", 1f64, 128f64);
+    small.revocable = true;
+
+    let mut queue = TaskQueue::new();
+    queue.push(batch);
+    queue.push(small);
+    let launches = queue.match_offers(vec![&a]);
+    let tasks = &launches[0].tasks;
+    assert_eq!(tasks.len(), 2);
+
+    // too big for the revocable cpu, so it gets regular ones only
+    let cpus: Vec<&Resource> = tasks[0].get_resources()
+                                       .iter()
+                                       .filter(|r| r.get_name() == "cpus")
+                                       .collect();
+    assert_eq!(cpus.len(), 1);
+    assert!(!cpus[0].has_revocable());
+    assert_eq!(cpus[0].get_scalar().get_value(), 2f64);
+
+    // the revocable cpu still goes to a task it covers
+    assert!(tasks[1].get_resources()
+                    .iter()
+                    .filter(|r| r.get_name() == "cpus")
+                    .all(|r| r.has_revocable()));
+}
+
+#[test]
+fn task_queue_spreads_unique_tasks() {
+    let a = offer("a", "s1", vec![util::scalar("cpus", "*", 4f64),
+                                  util::scalar("mem", "*", 4096f64)]);
+    let b = offer("b", "s2", vec![util::scalar("cpus", "*", 4f64),
+                                  util::scalar("mem", "*", 4096f64)]);
+
+    let mut queue = TaskQueue::new();
+    for _ in 0..3 {
+        let mut replica = spec("replica", 1f64, 128f64);
+        replica.constraints.push("hostname:UNIQUE".parse().unwrap());
+        queue.push(replica);
+    }
+    let launches = queue.match_offers(vec![&a, &b]);
+    assert_eq!(launches.len(), 2);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.placements().len(), 2);
+
+    // once a replica is gone its agent is available again
+    let gone = launches[0].tasks[0].get_task_id().clone();
+    queue.forget(&gone);
+    assert_eq!(queue.match_offers(vec![&a]).len(), 1);
+}
+
+#[test]
+fn task_queue_keeps_long_running_tasks_off_agents_due_for_maintenance() {
+    let mut maintained = offer("a",
+                               "s1",
+                               vec![util::scalar("cpus", "*", 4f64),
+                                    util::scalar("mem", "*", 1024f64)]);
+    let start = SystemTime::now() + Duration::from_secs(3600);
+    maintained.set_unavailability(util::unavailability(start, None));
+
+    let mut queue = TaskQueue::new();
+    let mut batch = spec("batch", 1f64, 128f64);
+    batch.expected_runtime = Some(Duration::from_secs(60));
+    queue.push(batch);
+    queue.push(spec("service", 1f64, 128f64));
+
+    let launches = queue.match_offers(vec![&maintained]);
+    assert_eq!(launches.len(), 1);
+    assert_eq!(launches[0].tasks.len(), 1);
+    assert_eq!(launches[0].tasks[0].get_name(), "batch");
+    assert_eq!(queue.pending()[0].name, "service");
+}
+
+#[test]
+fn task_queue_gets_tasks_back_when_their_launch_fails() {
+    // nothing listens there, so every call fails
+    let client = SchedulerClient::new("http://127.0.0.1:1".to_string(), None);
+    let a = offer("a",
+                  "s1",
+                  vec![util::scalar("cpus", "*", 2f64),
+                       util::scalar("mem", "*", 1024f64)]);
+    let b = offer("b",
+                  "s2",
+                  vec![util::scalar("cpus", "*", 2f64),
+                       util::scalar("mem", "*", 1024f64)]);
+    let c = offer("c", "s3", vec![util::scalar("mem", "*", 1024f64)]);
+    client.offer_pool
+          .lock()
+          .unwrap()
+          .add(vec![&a, &b, &c], Instant::now());
+    for _ in 0..2 {
+        let mut replica = spec("replica", 1f64, 128f64);
+        replica.constraints.push("hostname:UNIQUE".parse().unwrap());
+        client.task_queue.lock().unwrap().push(replica);
+    }
+
+    assert!(client.launch_queued(vec![&a, &b, &c]).is_err());
+    // the offers of the failed launches are declined, the unused one is
+    // still held
+    assert_eq!(client.offer_pool.lock().unwrap().len(), 1);
+    assert!(client.offer_pool.lock().unwrap().contains(&offer_id("c")));
+    assert!(client.registry.lock().unwrap().is_empty());
+
+    let mut queue = client.task_queue.lock().unwrap();
+    assert_eq!(queue.len(), 2);
+    assert!(queue.placements().is_empty());
+    assert_eq!(queue.match_offers(vec![&a, &b]).len(), 2);
+}
+
+#[test]
+fn task_queue_drops_tasks_reusing_a_live_task_id() {
+    let client = SchedulerClient::new("http://127.0.0.1:1".to_string(), None);
+    let a = offer("a",
+                  "s1",
+                  vec![util::scalar("cpus", "*", 2f64),
+                       util::scalar("mem", "*", 1024f64)]);
+    client.offer_pool.lock().unwrap().add(vec![&a], Instant::now());
+    client.registry
+          .lock()
+          .unwrap()
+          .register(&util::task_info("web",
+                                     &util::task_id("web.1"),
+                                     &slave_id("s2"),
+                                     &CommandInfo::new(),
+                                     vec![]));
+    let with_id = |id: &str| {
+        let mut spec = spec("web", 1f64, 128f64);
+        spec.task_id = Some(util::task_id(id));
+        spec
+    };
+
+    // a spec reusing a running task's id is dropped without placing it
+    client.task_queue.lock().unwrap().push(with_id("web.1"));
+    let _ = client.launch_queued(vec![&a]);
+    assert!(client.task_queue.lock().unwrap().is_empty());
+    assert!(client.offer_pool.lock().unwrap().contains(&offer_id("a")));
+
+    // as are specs sharing an id with one launched alongside them,
+    // whose offers are declined rather than posted
+    client.task_queue.lock().unwrap().push(with_id("web.2"));
+    client.task_queue.lock().unwrap().push(with_id("web.2"));
+    let err = client.launch_queued(vec![&a]).unwrap_err();
+    assert_eq!(err.to_string(), "task id \"web.2\" is already in use");
+    let queue = client.task_queue.lock().unwrap();
+    assert!(queue.is_empty());
+    assert!(queue.placements().is_empty());
+    assert!(!client.offer_pool.lock().unwrap().contains(&offer_id("a")));
+}
+
+#[test]
+fn task_queue_maps_and_announces_ports_of_bridged_containers() {
+    let a = offer("a",
+                  "s1",
+                  vec![util::scalar("cpus", "*", 1f64),
+                       util::scalar("mem", "*", 256f64),
+                       util::ranges("ports", "*", &[(31000, 31001)])]);
+
+    let mut web = spec("web", 1f64, 128f64);
+    web.ports = 2;
+    web.container_ports = vec![80];
+    web.port_names = vec!["http".to_string(), "admin".to_string()];
+    web.container = Some(DockerBuilder::new("nginx:1.11")
+                             .network(DockerNetwork::Bridge)
+                             .build()
+                             .unwrap());
+
+    let mut queue = TaskQueue::new();
+    queue.push(web);
+    let launches = queue.match_offers(vec![&a]);
+    let task = &launches[0].tasks[0];
+
+    let mappings: Vec<(u32, u32)> = task.get_container()
+                                        .get_docker()
+                                        .get_port_mappings()
+                                        .iter()
+                                        .map(|m| {
+                                            (m.get_host_port(),
+                                             m.get_container_port())
+                                        })
+                                        .collect();
+    // ports without a container port are mapped to themselves
+    assert_eq!(mappings, vec![(31000, 80), (31001, 31001)]);
+
+    let ports: Vec<(u32, &str)> = task.get_discovery()
+                                      .get_ports()
+                                      .get_ports()
+                                      .iter()
+                                      .map(|p| (p.get_number(), p.get_name()))
+                                      .collect();
+    assert_eq!(ports, vec![(31000, "http"), (31001, "admin")]);
+}
@@ -0,0 +1,139 @@
+use mesos::proto::*;
+use mesos::task_registry::Transition;
+use mesos::{TaskRegistry, util};
+
+fn task_info(id: &str, slave: &str) -> TaskInfo {
+    let mut slave_id = SlaveID::new();
+    slave_id.set_value(slave.to_string());
+    util::task_info(id,
+                    &util::task_id(id),
+                    &slave_id,
+                    &CommandInfo::new(),
+                    vec![util::scalar("cpus", "*", 1f64)])
+}
+
+fn status(id: &str, state: TaskState) -> TaskStatus {
+    let mut status = TaskStatus::new();
+    status.set_task_id(util::task_id(id));
+    status.set_state(state);
+    status
+}
+
+#[test]
+fn registry_follows_task_lifecycle() {
+    let mut registry = TaskRegistry::new();
+    registry.register(&task_info("a", "s1"));
+    registry.register(&task_info("b", "s2"));
+    assert_eq!(registry.pending().len(), 2);
+
+    assert_eq!(registry.update(&status("a", TaskState::TASK_RUNNING)),
+               Transition::Changed(TaskState::TASK_STAGING));
+    assert_eq!(registry.update(&status("a", TaskState::TASK_RUNNING)),
+               Transition::Duplicate);
+
+    let mut s1 = SlaveID::new();
+    s1.set_value("s1".to_string());
+    assert_eq!(registry.running_on(&s1).len(), 1);
+    assert_eq!(registry.pending().len(), 1);
+
+    let mut lost = status("b", TaskState::TASK_LOST);
+    lost.set_reason(TaskStatus_Reason::REASON_SLAVE_REMOVED);
+    registry.update(&lost);
+    let b = registry.get(&util::task_id("b")).unwrap();
+    assert!(b.is_terminal());
+    assert_eq!(b.reason, Some(TaskStatus_Reason::REASON_SLAVE_REMOVED));
+
+    registry.update(&status("a", TaskState::TASK_FINISHED));
+
+    // terminal states are final
+    assert_eq!(registry.update(&status("a", TaskState::TASK_RUNNING)),
+               Transition::Invalid(TaskState::TASK_FINISHED));
+    assert_eq!(registry.terminal().len(), 2);
+    assert_eq!(registry.remove_terminal().len(), 2);
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn registry_revives_tasks_reported_lost_by_a_partition() {
+    let mut registry = TaskRegistry::new();
+    registry.register(&task_info("a", "s1"));
+    registry.update(&status("a", TaskState::TASK_RUNNING));
+    assert_eq!(registry.update(&status("a", TaskState::TASK_LOST)),
+               Transition::Changed(TaskState::TASK_RUNNING));
+
+    // the agent re-registered with the task still running
+    assert_eq!(registry.update(&status("a", TaskState::TASK_RUNNING)),
+               Transition::Changed(TaskState::TASK_LOST));
+    assert_eq!(registry.running().len(), 1);
+    assert_eq!(registry.update(&status("a", TaskState::TASK_LOST)),
+               Transition::Changed(TaskState::TASK_RUNNING));
+
+    // or with the state it ended in while cut off
+    let mut failed = status("a", TaskState::TASK_FAILED);
+    failed.set_message("exited with 1".to_string());
+    assert_eq!(registry.update(&failed),
+               Transition::Changed(TaskState::TASK_LOST));
+    let task = registry.get(&util::task_id("a")).unwrap();
+    assert_eq!(task.message, Some("exited with 1".to_string()));
+    assert_eq!(registry.update(&status("a", TaskState::TASK_KILLED)),
+               Transition::Invalid(TaskState::TASK_FAILED));
+}
+
+#[test]
+fn registry_discovers_unknown_tasks() {
+    let mut registry = TaskRegistry::new();
+    assert_eq!(registry.update(&status("c", TaskState::TASK_RUNNING)),
+               Transition::Discovered);
+    assert_eq!(registry.update(&status("c", TaskState::TASK_STARTING)),
+               Transition::Invalid(TaskState::TASK_RUNNING));
+    assert_eq!(registry.running().len(), 1);
+}
+
+#[test]
+fn registry_recognises_revoked_tasks() {
+    let mut revocable = task_info("r", "s1");
+    revocable.mut_resources()[0].set_revocable(Resource_RevocableInfo::new());
+    let mut registry = TaskRegistry::new();
+    registry.register(&revocable);
+    assert!(registry.get(&util::task_id("r")).unwrap().revocable);
+
+    let mut preempted = status("r", TaskState::TASK_KILLED);
+    preempted.set_reason(TaskStatus_Reason::REASON_CONTAINER_PREEMPTED);
+    registry.update(&preempted);
+    assert_eq!(registry.revoked().len(), 1);
+}
+
+#[test]
+fn registry_records_container_ips_when_running() {
+    let mut registry = TaskRegistry::new();
+    registry.register(&task_info("a", "s1"));
+
+    let mut network_info = NetworkInfo::new();
+    network_info.set_name("calico".to_string());
+    let mut ip_address = NetworkInfo_IPAddress::new();
+    ip_address.set_ip_address("192.168.1.7".to_string());
+    network_info.mut_ip_addresses().push(ip_address);
+    let mut running = status("a", TaskState::TASK_RUNNING);
+    running.mut_container_status().mut_network_infos().push(network_info);
+    registry.update(&running);
+
+    registry.update(&status("a", TaskState::TASK_KILLING));
+    let task = registry.get(&util::task_id("a")).unwrap();
+    assert_eq!(task.ips_on("calico"), vec!["192.168.1.7"]);
+    assert!(task.ips_on("other").is_empty());
+}
+
+#[test]
+fn registry_keeps_the_last_reported_health() {
+    let mut registry = TaskRegistry::new();
+    registry.register(&task_info("a", "s1"));
+    let mut running = status("a", TaskState::TASK_RUNNING);
+    registry.update(&running);
+    assert_eq!(registry.get(&util::task_id("a")).unwrap().healthy, None);
+
+    running.set_healthy(false);
+    registry.update(&running);
+    registry.update(&status("a", TaskState::TASK_KILLING));
+    assert_eq!(registry.get(&util::task_id("a")).unwrap().healthy,
+               Some(false));
+}